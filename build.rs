@@ -0,0 +1,29 @@
+use std::process::Command;
+
+fn main() {
+    // sqlx::migrate! embeds the migrations, so a new file has to trigger a rebuild
+    println!("cargo:rerun-if-changed=migrations");
+
+    println!("cargo:rerun-if-env-changed=BUILD_DATE");
+    let build_date = std::env::var("BUILD_DATE")
+        .unwrap_or_else(|_| chrono::Utc::now().format("%Y-%m-%d").to_string());
+    println!("cargo:rustc-env=BUILD_DATE={}", build_date);
+
+    // Docker builds have no .git, so they pass the commit in instead
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+    let git_commit = std::env::var("GIT_COMMIT")
+        .ok()
+        .or_else(|| {
+            Command::new("git")
+                .args(["rev-parse", "--short", "HEAD"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|commit| commit.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIT_COMMIT={}", git_commit);
+}
@@ -0,0 +1,33 @@
+-- User-defined log schemas
+-- Statements are idempotent so databases created by the former docker/db/init.sql migrate cleanly
+
+CREATE EXTENSION IF NOT EXISTS "uuid-ossp";
+
+CREATE TABLE IF NOT EXISTS schemas (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL,
+    version VARCHAR(50) NOT NULL,
+    description TEXT,
+    schema_definition JSONB NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+    UNIQUE(name, version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_schemas_name ON schemas(name);
+CREATE INDEX IF NOT EXISTS idx_schemas_name_version ON schemas(name, version);
+
+-- Keep updated_at current on every update
+CREATE OR REPLACE FUNCTION update_updated_at_column()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = NOW();
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+DROP TRIGGER IF EXISTS update_schemas_updated_at ON schemas;
+CREATE TRIGGER update_schemas_updated_at
+    BEFORE UPDATE ON schemas
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
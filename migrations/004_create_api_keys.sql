@@ -0,0 +1,10 @@
+-- X-API-Key authentication (keys are stored as SHA-256 hex digests)
+
+CREATE TABLE IF NOT EXISTS api_keys (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    key_hash TEXT NOT NULL UNIQUE,
+    description TEXT,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    permissions TEXT[] NOT NULL DEFAULT '{read}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,4 @@
+-- Full-text search over schema names and descriptions (GET /schemas/search)
+
+CREATE INDEX IF NOT EXISTS idx_schemas_search ON schemas
+    USING GIN (to_tsvector('english', name || ' ' || COALESCE(description, '')));
@@ -0,0 +1,5 @@
+-- Service or client that submitted each log
+
+ALTER TABLE logs ADD COLUMN IF NOT EXISTS source VARCHAR(255);
+
+CREATE INDEX IF NOT EXISTS idx_logs_source ON logs(source);
@@ -0,0 +1,15 @@
+-- Previous state of a schema, written in the same transaction as each update or delete
+-- No foreign key: history outlives the schemas it describes
+
+CREATE TABLE IF NOT EXISTS schema_history (
+    id BIGSERIAL PRIMARY KEY,
+    schema_id UUID NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    version VARCHAR(50) NOT NULL,
+    description TEXT,
+    schema_definition JSONB NOT NULL,
+    change_type TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_schema_history_schema ON schema_history(schema_id, changed_at DESC);
@@ -0,0 +1,4 @@
+-- Deprecated schemas still accept logs; clients are warned to move to a newer version
+
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS deprecated BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS deprecated_at TIMESTAMPTZ;
@@ -0,0 +1,6 @@
+-- Identifier shared by logs of one request or workflow across services and schemas
+-- Not unique: every log of a correlated flow carries the same value
+
+ALTER TABLE logs ADD COLUMN IF NOT EXISTS correlation_id VARCHAR(255);
+
+CREATE INDEX IF NOT EXISTS idx_logs_correlation_id ON logs(correlation_id, created_at);
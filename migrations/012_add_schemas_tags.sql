@@ -0,0 +1,3 @@
+-- Free-form labels (e.g. production, team-billing) for organising schemas
+
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS tags TEXT[] NOT NULL DEFAULT '{}';
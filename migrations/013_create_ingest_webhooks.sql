@@ -0,0 +1,9 @@
+-- Tokens letting external systems push logs for a schema without knowing its id
+
+CREATE TABLE IF NOT EXISTS ingest_webhooks (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    token TEXT NOT NULL UNIQUE,
+    schema_id UUID NOT NULL REFERENCES schemas(id) ON DELETE CASCADE,
+    transform JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,6 @@
+-- Numeric severity of a log, 0 (TRACE) through 5 (FATAL)
+-- Lets listings filter by level without parsing log_data
+
+ALTER TABLE logs ADD COLUMN IF NOT EXISTS severity SMALLINT;
+
+CREATE INDEX IF NOT EXISTS idx_logs_severity ON logs(severity);
@@ -0,0 +1,8 @@
+-- no-transaction
+-- GIN index for log_data containment filters (log_data @> $filter)
+-- jsonb_path_ops supports @> and @? but not key-existence checks (?, ?|, ?&),
+-- which still use idx_logs_data_gin
+-- Built concurrently so large tables stay writable; that cannot run in a
+-- transaction, so this migration holds this one statement only
+
+CREATE INDEX CONCURRENTLY IF NOT EXISTS idx_logs_log_data_gin ON logs USING GIN (log_data jsonb_path_ops);
@@ -0,0 +1,3 @@
+-- Serves a schema's logs newest first without sorting, for paginated listings
+
+CREATE INDEX IF NOT EXISTS idx_logs_schema_id_created_at ON logs (schema_id, created_at DESC);
@@ -0,0 +1,3 @@
+-- How strictly logs are checked against the schema: 'strict' or 'lenient'
+
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS validation_mode TEXT NOT NULL DEFAULT 'strict';
@@ -0,0 +1,6 @@
+-- Monotonic sequence number for keyset pagination; `id` remains the identifier.
+-- Adding a BIGSERIAL column numbers the existing rows.
+
+ALTER TABLE schemas ADD COLUMN IF NOT EXISTS seq BIGSERIAL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_schemas_seq ON schemas (seq);
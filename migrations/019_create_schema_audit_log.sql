@@ -0,0 +1,12 @@
+-- Successful schema mutations as seen at the HTTP layer, written asynchronously
+
+CREATE TABLE IF NOT EXISTS schema_audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    operation TEXT NOT NULL,
+    schema_id UUID,
+    actor TEXT,
+    request_id TEXT,
+    timestamp TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_schema_audit_log_schema ON schema_audit_log(schema_id, timestamp DESC);
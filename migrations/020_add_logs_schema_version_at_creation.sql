@@ -0,0 +1,3 @@
+-- Version of the schema a log was validated against; NULL for logs created before it was recorded
+
+ALTER TABLE logs ADD COLUMN IF NOT EXISTS schema_version_at_creation TEXT;
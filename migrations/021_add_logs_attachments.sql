@@ -0,0 +1,4 @@
+-- References to artifacts stored elsewhere, as a JSON array of {url, name, content_type};
+-- NULL for logs without attachments
+
+ALTER TABLE logs ADD COLUMN IF NOT EXISTS attachments JSONB;
@@ -0,0 +1,219 @@
+use futures_util::{stream::BoxStream, StreamExt};
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{client::IntoClientRequest, Message},
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::{CreateLogRequest, CreateSchemaRequest, LogResponse, PaginatedResponse},
+    error::{AppError, AppResult},
+    middleware::auth::API_KEY_HEADER,
+    ErrorResponse, LogEvent, SchemaResponse,
+};
+
+/// Typed HTTP client for the log server API.
+///
+/// Error responses come back as the [`AppError`] variant matching their status code,
+/// carrying the server's error message.
+#[derive(Debug, Clone)]
+pub struct LogServerClient {
+    base_url: String,
+    client: reqwest::Client,
+    api_key: Option<String>,
+}
+
+impl LogServerClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            api_key: None,
+        }
+    }
+
+    /// Sends `key` in the `X-API-Key` header of every request.
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub async fn create_schema(&self, request: &CreateSchemaRequest) -> AppResult<SchemaResponse> {
+        self.send_json(
+            self.request(reqwest::Method::POST, "/schemas")
+                .json(request),
+        )
+        .await
+    }
+
+    pub async fn get_schema(&self, id: Uuid) -> AppResult<SchemaResponse> {
+        self.send_json(self.request(reqwest::Method::GET, &format!("/schemas/{}", id)))
+            .await
+    }
+
+    /// Lists schemas, optionally filtered by exact name and/or version.
+    pub async fn list_schemas(
+        &self,
+        name: Option<&str>,
+        version: Option<&str>,
+    ) -> AppResult<PaginatedResponse<SchemaResponse>> {
+        let query: Vec<(&str, &str)> = [("name", name), ("version", version)]
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect();
+
+        self.send_json(self.request(reqwest::Method::GET, "/schemas").query(&query))
+            .await
+    }
+
+    /// Deletes a schema; with `force`, its logs are deleted too.
+    pub async fn delete_schema(&self, id: Uuid, force: bool) -> AppResult<()> {
+        let request = self
+            .request(reqwest::Method::DELETE, &format!("/schemas/{}", id))
+            .query(&[("force", force)]);
+        self.send(request).await.map(|_| ())
+    }
+
+    pub async fn create_log(&self, request: &CreateLogRequest) -> AppResult<LogResponse> {
+        self.send_json(self.request(reqwest::Method::POST, "/logs").json(request))
+            .await
+    }
+
+    pub async fn get_log(&self, id: i32) -> AppResult<LogResponse> {
+        self.send_json(self.request(reqwest::Method::GET, &format!("/logs/{}", id)))
+            .await
+    }
+
+    /// Lists a schema version's logs, newest first; without a version, the schema's
+    /// highest version is used.
+    pub async fn list_logs(
+        &self,
+        schema_name: &str,
+        schema_version: Option<&str>,
+    ) -> AppResult<PaginatedResponse<LogResponse>> {
+        let path = match schema_version {
+            Some(version) => format!("/logs/schema/{}/{}", schema_name, version),
+            None => format!("/logs/schema/{}", schema_name),
+        };
+        self.send_json(self.request(reqwest::Method::GET, &path))
+            .await
+    }
+
+    pub async fn delete_log(&self, id: i32) -> AppResult<()> {
+        self.send(self.request(reqwest::Method::DELETE, &format!("/logs/{}", id)))
+            .await
+            .map(|_| ())
+    }
+
+    /// Opens `/ws/logs` and yields each broadcast event, optionally only for one schema.
+    ///
+    /// The stream ends when the server closes the connection.
+    pub async fn subscribe_ws(
+        &self,
+        schema_id: Option<Uuid>,
+    ) -> AppResult<BoxStream<'static, AppResult<LogEvent>>> {
+        let mut url = format!("{}/ws/logs", self.base_url.replacen("http", "ws", 1));
+        if let Some(schema_id) = schema_id {
+            url.push_str(&format!("?schema_id={}", schema_id));
+        }
+
+        let mut request = url
+            .into_client_request()
+            .map_err(|e| AppError::BadRequest(e.to_string()))?;
+        if let Some(key) = &self.api_key {
+            let value = key
+                .parse()
+                .map_err(|_| AppError::BadRequest("Invalid API key".to_string()))?;
+            request.headers_mut().insert(API_KEY_HEADER, value);
+        }
+
+        let (socket, _) = connect_async(request)
+            .await
+            .map_err(|e| AppError::InternalError(format!("WebSocket connection failed: {}", e)))?;
+
+        let events = socket.filter_map(|message| async move {
+            match message {
+                Ok(Message::Text(text)) => Some(
+                    serde_json::from_str::<LogEvent>(&text)
+                        .map_err(|e| AppError::InternalError(e.to_string())),
+                ),
+                Ok(_) => None,
+                Err(e) => Some(Err(AppError::InternalError(e.to_string()))),
+            }
+        });
+
+        Ok(events.boxed())
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path));
+        match &self.api_key {
+            Some(key) => request.header(API_KEY_HEADER, key),
+            None => request,
+        }
+    }
+
+    async fn send(&self, request: RequestBuilder) -> AppResult<Response> {
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AppError::InternalError(format!("Request failed: {}", e)))?;
+
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(error_from_response(status, &body))
+    }
+
+    async fn send_json<T: DeserializeOwned>(&self, request: RequestBuilder) -> AppResult<T> {
+        self.send(request)
+            .await?
+            .json()
+            .await
+            .map_err(|e| AppError::InternalError(format!("Invalid response body: {}", e)))
+    }
+}
+
+/// Maps an error response back to the [`AppError`] the server would raise for it.
+fn error_from_response(status: StatusCode, body: &str) -> AppError {
+    let (message, field_errors, errors) = match serde_json::from_str::<ErrorResponse>(body) {
+        Ok(error) => (
+            error.message,
+            error.field_errors,
+            error.validation_errors.unwrap_or_default(),
+        ),
+        Err(_) if body.is_empty() => (status.to_string(), None, Vec::new()),
+        Err(_) => (body.to_string(), None, Vec::new()),
+    };
+
+    match status {
+        StatusCode::BAD_REQUEST => match field_errors {
+            Some(field_errors) => AppError::FieldValidationError {
+                message,
+                field_errors,
+                errors,
+            },
+            None => AppError::BadRequest(message),
+        },
+        StatusCode::UNAUTHORIZED => AppError::Unauthorized(message),
+        StatusCode::FORBIDDEN => AppError::Forbidden(message),
+        StatusCode::NOT_FOUND => AppError::NotFound(message),
+        StatusCode::REQUEST_TIMEOUT => AppError::Timeout(message),
+        StatusCode::CONFLICT => AppError::Conflict(message),
+        StatusCode::PAYLOAD_TOO_LARGE => AppError::PayloadTooLarge(message),
+        StatusCode::UNPROCESSABLE_ENTITY => AppError::SchemaValidationError(message),
+        StatusCode::TOO_MANY_REQUESTS => AppError::RateLimitExceeded(message),
+        _ => AppError::InternalError(message),
+    }
+}
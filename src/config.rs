@@ -0,0 +1,235 @@
+use anyhow::{anyhow, Context};
+use axum::http::HeaderValue;
+use std::{
+    env,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    middleware::{auth, body_limit, compression, rate_limit, timeout},
+    repositories::slow_query,
+    services::{
+        log_service, schema_cache,
+        schema_service::{self, VersionFormat},
+    },
+    telemetry,
+};
+
+pub const DATABASE_URL_ENV: &str = "DATABASE_URL";
+pub const PORT_ENV: &str = "PORT";
+pub const BIND_ADDR_ENV: &str = "BIND_ADDR";
+pub const BROADCAST_CAPACITY_ENV: &str = "BROADCAST_CAPACITY";
+pub const MAX_WS_CONNECTIONS_ENV: &str = "MAX_WS_CONNECTIONS";
+pub const CORS_ALLOWED_ORIGINS_ENV: &str = "CORS_ALLOWED_ORIGINS";
+pub const LOG_FORMAT_ENV: &str = "LOG_FORMAT";
+
+pub const DEFAULT_PORT: u16 = 8080;
+pub const DEFAULT_BIND_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+/// Privileged ports are refused so the server never needs to run as root.
+pub const MIN_PORT: u16 = 1024;
+
+/// Events buffered per WebSocket subscriber before slow ones start missing events.
+pub const DEFAULT_BROADCAST_CAPACITY: usize = 100;
+
+pub const DEFAULT_MAX_WS_CONNECTIONS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+/// Server configuration, read once from the environment at startup.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub database_url: String,
+    pub port: u16,
+    pub bind_addr: IpAddr,
+    pub broadcast_capacity: usize,
+    /// Concurrent WebSocket subscribers; `0` means unlimited
+    pub max_ws_connections: usize,
+    pub max_request_body_bytes: usize,
+    /// Origins allowed by CORS; empty allows any origin
+    pub cors_allowed_origins: Vec<HeaderValue>,
+    pub log_format: LogFormat,
+    pub request_timeout_ms: u64,
+    pub rate_limit_per_minute: u32,
+    pub rate_limit_burst: u32,
+    pub log_ingest_rate_limit_per_minute: u32,
+    pub ingest_webhook_rate_limit_per_minute: u32,
+    /// Repository queries slower than this are logged as warnings
+    pub slow_query_threshold_ms: u64,
+    pub require_api_key: bool,
+    pub disable_compression: bool,
+    pub max_log_data_bytes: usize,
+    pub enrich_logs: bool,
+    pub server_version: Option<String>,
+    pub otel_exporter_otlp_endpoint: Option<String>,
+    pub version_format: VersionFormat,
+    /// Schemas kept in the lookup cache; `0` disables it
+    pub schema_cache_size: usize,
+}
+
+impl AppConfig {
+    /// Reads every setting, applying defaults for unset variables. Set but invalid
+    /// values are an error so misconfiguration is caught at startup.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let database_url = env::var(DATABASE_URL_ENV)
+            .with_context(|| format!("{} environment variable is not set", DATABASE_URL_ENV))?;
+
+        let port = parse_env(PORT_ENV, DEFAULT_PORT)?;
+        if port < MIN_PORT {
+            return Err(anyhow!(
+                "{} must be between {} and 65535",
+                PORT_ENV,
+                MIN_PORT
+            ));
+        }
+
+        let broadcast_capacity = parse_env(BROADCAST_CAPACITY_ENV, DEFAULT_BROADCAST_CAPACITY)?;
+        if broadcast_capacity == 0 {
+            return Err(anyhow!("{} must be greater than 0", BROADCAST_CAPACITY_ENV));
+        }
+
+        let log_format = match optional_env(LOG_FORMAT_ENV) {
+            None => LogFormat::Text,
+            Some(value) if value.eq_ignore_ascii_case("text") => LogFormat::Text,
+            Some(value) if value.eq_ignore_ascii_case("json") => LogFormat::Json,
+            Some(value) => {
+                return Err(anyhow!(
+                    "Invalid {} value '{}': expected 'text' or 'json'",
+                    LOG_FORMAT_ENV,
+                    value
+                ))
+            }
+        };
+
+        Ok(Self {
+            database_url,
+            port,
+            bind_addr: parse_env(BIND_ADDR_ENV, DEFAULT_BIND_ADDR)?,
+            broadcast_capacity,
+            max_ws_connections: parse_env(MAX_WS_CONNECTIONS_ENV, DEFAULT_MAX_WS_CONNECTIONS)?,
+            max_request_body_bytes: parse_env(
+                body_limit::MAX_REQUEST_BODY_BYTES_ENV,
+                body_limit::DEFAULT_MAX_REQUEST_BODY_BYTES,
+            )?,
+            cors_allowed_origins: parse_origins()?,
+            log_format,
+            request_timeout_ms: parse_env(
+                timeout::REQUEST_TIMEOUT_MS_ENV,
+                timeout::DEFAULT_REQUEST_TIMEOUT_MS,
+            )?,
+            rate_limit_per_minute: parse_env(
+                rate_limit::RATE_LIMIT_PER_MINUTE_ENV,
+                rate_limit::DEFAULT_RATE_LIMIT_PER_MINUTE,
+            )?,
+            rate_limit_burst: parse_env(
+                rate_limit::RATE_LIMIT_BURST_ENV,
+                rate_limit::DEFAULT_RATE_LIMIT_BURST,
+            )?,
+            log_ingest_rate_limit_per_minute: parse_env(
+                rate_limit::LOG_INGEST_RATE_LIMIT_PER_MINUTE_ENV,
+                rate_limit::DEFAULT_LOG_INGEST_RATE_LIMIT_PER_MINUTE,
+            )?,
+            ingest_webhook_rate_limit_per_minute: parse_env(
+                rate_limit::INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE_ENV,
+                rate_limit::DEFAULT_INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE,
+            )?,
+            slow_query_threshold_ms: parse_env(
+                slow_query::SLOW_QUERY_THRESHOLD_MS_ENV,
+                slow_query::DEFAULT_SLOW_QUERY_THRESHOLD_MS,
+            )?,
+            require_api_key: flag_env(auth::REQUIRE_API_KEY_ENV),
+            disable_compression: flag_env(compression::DISABLE_COMPRESSION_ENV),
+            max_log_data_bytes: parse_env(
+                log_service::MAX_LOG_DATA_BYTES_ENV,
+                log_service::DEFAULT_MAX_LOG_DATA_BYTES,
+            )?,
+            enrich_logs: flag_env(log_service::ENRICH_LOGS_ENV),
+            server_version: optional_env(log_service::SERVER_VERSION_ENV),
+            otel_exporter_otlp_endpoint: optional_env(telemetry::OTEL_EXPORTER_OTLP_ENDPOINT_ENV),
+            version_format: parse_env(
+                schema_service::VERSION_FORMAT_ENV,
+                VersionFormat::default(),
+            )?,
+            schema_cache_size: parse_env(
+                schema_cache::SCHEMA_CACHE_SIZE_ENV,
+                schema_cache::DEFAULT_SCHEMA_CACHE_SIZE,
+            )?,
+        })
+    }
+
+    /// Address the server listens on.
+    pub fn socket_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.bind_addr, self.port)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    pub fn slow_query_threshold(&self) -> Duration {
+        Duration::from_millis(self.slow_query_threshold_ms)
+    }
+
+    /// Long-running routes never get less time than the global timeout.
+    pub fn long_request_timeout(&self) -> Duration {
+        Duration::from_millis(timeout::LONG_REQUEST_TIMEOUT_MS).max(self.request_timeout())
+    }
+
+    /// Per-route limits never drop below the global one.
+    pub fn schema_max_request_body_bytes(&self) -> usize {
+        body_limit::SCHEMA_MAX_REQUEST_BODY_BYTES.max(self.max_request_body_bytes)
+    }
+
+    pub fn log_batch_max_request_body_bytes(&self) -> usize {
+        body_limit::LOG_BATCH_MAX_REQUEST_BODY_BYTES.max(self.max_request_body_bytes)
+    }
+}
+
+/// Unset and blank variables are treated the same.
+fn optional_env(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.trim().is_empty())
+}
+
+/// Flags are enabled by `1`, `true` or `yes`.
+fn flag_env(name: &str) -> bool {
+    optional_env(name)
+        .map(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false)
+}
+
+fn parse_env<T>(name: &str, default: T) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    match optional_env(name) {
+        Some(value) => value
+            .trim()
+            .parse()
+            .map_err(|e| anyhow!("Invalid {} value '{}': {}", name, value, e)),
+        None => Ok(default),
+    }
+}
+
+/// Comma-separated origins such as `https://app.example.com,http://localhost:3000`.
+fn parse_origins() -> anyhow::Result<Vec<HeaderValue>> {
+    let Some(value) = optional_env(CORS_ALLOWED_ORIGINS_ENV) else {
+        return Ok(Vec::new());
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .map(|origin| {
+            HeaderValue::from_str(origin)
+                .map_err(|_| anyhow!("Invalid {} origin '{}'", CORS_ALLOWED_ORIGINS_ENV, origin))
+        })
+        .collect()
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::services::{migration_service::AppliedMigration, GlobalLogStats};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MigrationInfo {
+    pub version: i64,
+    pub description: String,
+}
+
+impl From<AppliedMigration> for MigrationInfo {
+    fn from(migration: AppliedMigration) -> Self {
+        MigrationInfo {
+            version: migration.version,
+            description: migration.description,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MigrationResponse {
+    /// Migrations applied by this request; empty when the database was up to date
+    pub applied: Vec<MigrationInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GlobalLogStatsResponse {
+    pub total_logs: i64,
+    pub total_schemas: i64,
+    pub logs_last_hour: i64,
+    pub logs_last_24h: i64,
+    /// Creation time of the oldest log; absent when there are no logs
+    pub oldest_log: Option<String>,
+    /// Creation time of the newest log; absent when there are no logs
+    pub newest_log: Option<String>,
+}
+
+impl From<GlobalLogStats> for GlobalLogStatsResponse {
+    fn from(stats: GlobalLogStats) -> Self {
+        GlobalLogStatsResponse {
+            total_logs: stats.total_logs,
+            total_schemas: stats.total_schemas,
+            logs_last_hour: stats.logs_last_hour,
+            logs_last_24h: stats.logs_last_24h,
+            oldest_log: stats.oldest_log.map(|at| at.to_rfc3339()),
+            newest_log: stats.newest_log.map(|at| at.to_rfc3339()),
+        }
+    }
+}
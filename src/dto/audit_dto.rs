@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::AuditLog;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GetAuditLogQuery {
+    /// Entity type (`schema` or `log`)
+    pub entity_type: Option<String>,
+    /// Entity ID
+    pub entity_id: Option<String>,
+    /// Maximum number of entries (default 50, max 500)
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogListResponse {
+    pub audit_logs: Vec<AuditLog>,
+}
@@ -1,13 +1,39 @@
 use std::collections::HashMap;
 
+use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use utoipa::{IntoParams, ToSchema};
 
-#[derive(Debug, Serialize, Deserialize)]
+pub const DEFAULT_PAGE_LIMIT: i64 = 100;
+pub const MAX_PAGE_LIMIT: i64 = 1000;
+
+/// Response header carrying the number of items matching a listing, across all pages.
+pub const TOTAL_COUNT_HEADER: &str = "X-Total-Count";
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub field_errors: Option<HashMap<String, Vec<String>>>,
+    /// Kind of resource that was missing or conflicting, e.g. `schema` or `log`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    /// Every schema violation of the log data, located by JSON Pointer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_errors: Option<Vec<ValidationError>>,
+}
+
+/// One way the log data violates its schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ValidationError {
+    /// JSON Pointer to the offending value; `""` is the log data itself, which is where
+    /// missing `required` properties are reported
+    pub path: String,
+    pub message: String,
+    /// JSON Pointer to the schema keyword that failed, e.g. `/properties/status/type`
+    pub schema_path: String,
 }
 
 impl ErrorResponse {
@@ -16,6 +42,8 @@ impl ErrorResponse {
             error: error.into(),
             message: message.into(),
             field_errors: None,
+            resource_type: None,
+            validation_errors: None,
         }
     }
 
@@ -28,6 +56,112 @@ impl ErrorResponse {
             error: error.into(),
             message: message.into(),
             field_errors: Some(field_errors),
+            resource_type: None,
+            validation_errors: None,
+        }
+    }
+
+    pub fn with_validation_errors(mut self, validation_errors: Vec<ValidationError>) -> Self {
+        self.validation_errors = Some(validation_errors);
+        self
+    }
+
+    pub fn with_resource_type(mut self, resource_type: impl Into<String>) -> Self {
+        self.resource_type = Some(resource_type.into());
+        self
+    }
+
+    /// This error as an RFC 7807 problem detail for a response with `status` to a
+    /// request for `path`. The usual fields are kept alongside `type`, `title`,
+    /// `status`, `detail` and `instance` as extension members.
+    pub fn as_problem_detail(&self, status: u16, path: &str) -> Value {
+        let title = StatusCode::from_u16(status)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or("Error");
+
+        let mut problem = json!({
+            "type": "about:blank",
+            "title": title,
+            "status": status,
+            "detail": self.message,
+        });
+        if !path.is_empty() {
+            problem["instance"] = json!(path);
+        }
+
+        if let (Some(problem), Ok(Value::Object(fields))) =
+            (problem.as_object_mut(), serde_json::to_value(self))
+        {
+            for (key, value) in fields {
+                problem.entry(key).or_insert(value);
+            }
+        }
+
+        problem
+    }
+}
+
+/// `limit`/`offset` query parameters shared by the listing endpoints.
+#[derive(Debug, Clone, Copy, Default, Deserialize, IntoParams)]
+pub struct PaginationQuery {
+    /// Maximum number of items (default 100, max 1000)
+    pub limit: Option<i64>,
+    /// Number of items to skip (default 0)
+    pub offset: Option<i64>,
+}
+
+impl PaginationQuery {
+    /// Whether the client asked for pagination; `total` and `has_more` are only
+    /// computed in that case.
+    pub fn is_requested(&self) -> bool {
+        self.limit.is_some() || self.offset.is_some()
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_PAGE_LIMIT)
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=MAX_PAGE_LIMIT).contains(&self.limit()) {
+            return Err(format!("limit must be between 1 and {}", MAX_PAGE_LIMIT));
+        }
+
+        if self.offset() < 0 {
+            return Err("offset cannot be negative".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Envelope for listing endpoints. `total` and `has_more` are omitted unless the
+/// request carried `limit` or `offset`; the total is always sent in `X-Total-Count`.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    pub limit: i64,
+    pub offset: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+}
+
+impl<T: Serialize> PaginatedResponse<T> {
+    pub fn new(items: Vec<T>, pagination: &PaginationQuery, total: Option<i64>) -> Self {
+        let offset = pagination.offset();
+
+        Self {
+            has_more: total.map(|total| offset + (items.len() as i64) < total),
+            items,
+            total,
+            limit: pagination.limit(),
+            offset,
         }
     }
 }
@@ -1,55 +1,268 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::Log;
+use crate::{
+    repositories::log_repository::TimeBucketedCount, AppError, AttachmentRef, Log, Schema,
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateLogRequest {
     pub schema_id: Uuid,
     pub log_data: Value,
+    /// Service that submitted the log; `POST /logs` falls back to the `User-Agent` product
+    pub source: Option<String>,
+    /// Shared by related logs, e.g. of one request across services
+    pub correlation_id: Option<String>,
+    /// 0 (TRACE) through 5 (FATAL)
+    pub severity: Option<i16>,
+    /// Up to 10 references to artifacts stored elsewhere; URLs must be `https://`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<AttachmentRef>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LogResponse {
     pub id: i32,
     pub schema_id: Uuid,
+    /// Only present when `include_schema=true` was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    /// Only present when `include_schema=true` was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
     pub log_data: Value,
+    pub source: Option<String>,
+    pub correlation_id: Option<String>,
+    pub severity: Option<i16>,
+    /// Schema version the log was validated against, when recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version_at_creation: Option<String>,
+    /// Only present when the log has attachments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<AttachmentRef>>,
     pub created_at: String,
 }
 
+impl LogResponse {
+    pub fn with_schema(mut self, schema: &Schema) -> Self {
+        self.schema_name = Some(schema.name.clone());
+        self.schema_version = Some(schema.version.clone());
+        self
+    }
+}
+
 impl From<Log> for LogResponse {
     fn from(log: Log) -> Self {
         LogResponse {
             id: log.id,
             schema_id: log.schema_id,
+            schema_name: None,
+            schema_version: None,
             log_data: log.log_data,
+            source: log.source,
+            correlation_id: log.correlation_id,
+            severity: log.severity,
+            schema_version_at_creation: log.schema_version_at_creation,
+            attachments: log.attachments.map(|attachments| attachments.0),
             created_at: log.created_at.to_rfc3339(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LogAttachmentsResponse {
+    pub log_id: i32,
+    pub attachments: Vec<AttachmentRef>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkCreateLogRequest {
+    pub logs: Vec<CreateLogRequest>,
+    /// Validate every entry first and write nothing if any is invalid (default false)
+    pub fail_fast: Option<bool>,
+}
+
+/// Options for `POST /logs/batch` when the body is NDJSON and cannot carry them itself.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BulkCreateLogQuery {
+    /// Validate every entry first and write nothing if any is invalid (default false)
+    pub fail_fast: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkCreateLogResponse {
+    pub created: Vec<LogResponse>,
+    pub failed: Vec<BulkCreateFailure>,
+    pub total_submitted: usize,
+    pub total_created: usize,
+    pub total_failed: usize,
+}
+
+/// A rejected batch entry; `index` is its position in the submitted `logs` array.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkCreateFailure {
+    pub index: usize,
+    pub schema_id: Uuid,
+    pub error_code: String,
+    pub error_message: String,
+}
+
+/// Fills in the error fields only; set `index` and `schema_id` with struct update syntax.
+impl From<AppError> for BulkCreateFailure {
+    fn from(error: AppError) -> Self {
+        let error_code = match &error {
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::ValidationError(_)
+            | AppError::SchemaValidationError(_)
+            | AppError::FieldValidationError { .. } => "VALIDATION_FAILED",
+            AppError::BadRequest(_) => "INVALID_INPUT",
+            AppError::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            _ => "INTERNAL_SERVER_ERROR",
+        };
+
+        BulkCreateFailure {
+            index: 0,
+            schema_id: Uuid::nil(),
+            error_code: error_code.to_string(),
+            error_message: error.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GetLogQuery {
+    /// Add `schema_name` and `schema_version` to the log
+    pub include_schema: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteLogsBeforeQuery {
+    /// RFC 3339 cutoff; logs created strictly before it are deleted
+    pub timestamp: String,
+    /// Schema version (default: the highest)
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteLogsByFilterQuery {
+    /// RFC 3339 instant; only logs created strictly before it are deleted
+    pub before: Option<String>,
+    /// RFC 3339 instant; only logs created strictly after it are deleted
+    pub after: Option<String>,
+    /// Only logs submitted by this source are deleted
+    pub source: Option<String>,
+    /// Schema version (default: the highest)
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteSchemaLogsQuery {
+    /// RFC 3339 instant; only logs created strictly before it are deleted
+    pub before: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeleteLogsResponse {
+    pub deleted_count: i64,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GroupedByTimeQuery {
+    /// `minute`, `hour` (default), `day` or `week`
+    pub bucket: Option<String>,
+    /// RFC 3339 start of the range (default 24 hours before `to`)
+    pub from: Option<String>,
+    /// RFC 3339 end of the range (default now)
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AcrossVersionsQuery {
+    /// Maximum number of logs (default 100, max 1000)
+    pub limit: Option<i64>,
+    /// Number of logs to skip (default 0)
+    pub offset: Option<i64>,
+    /// RFC 3339; only logs created at or after this time
+    pub from: Option<String>,
+    /// RFC 3339; only logs created at or before this time
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AcrossVersionsResponse {
+    pub logs: Vec<LogResponse>,
+    /// Versions of the schema whose logs were searched, lowest first
+    pub schema_versions_queried: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeBucketCount {
+    /// Start of the bucket
+    pub time: String,
+    pub count: i64,
+}
+
+impl From<TimeBucketedCount> for TimeBucketCount {
+    fn from(bucket: TimeBucketedCount) -> Self {
+        TimeBucketCount {
+            time: bucket.bucket.to_rfc3339(),
+            count: bucket.count,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupedByTimeResponse {
+    /// Buckets in chronological order; buckets without logs are omitted
+    pub buckets: Vec<TimeBucketCount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "event_type", rename_all = "lowercase")]
 pub enum LogEvent {
     Created {
         id: i32,
         schema_id: Uuid,
+        schema_name: String,
+        schema_version: String,
         log_data: Value,
+        /// Only present when the log has a source
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+        /// Only present when the log has a severity
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        severity: Option<i16>,
+        /// Only present when the log has attachments
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        attachments: Option<Vec<AttachmentRef>>,
         created_at: String,
     },
     Deleted {
         id: i32,
         schema_id: Uuid,
     },
+    /// Events collected over a short window, sent to `?batch_mode=true` connections
+    Batch {
+        #[schema(no_recursion)]
+        events: Vec<LogEvent>,
+        /// When the batch was sent
+        batch_at: String,
+    },
 }
 
 impl LogEvent {
-    pub fn created_from(log: Log) -> Self {
+    pub fn created_from(log: Log, schema_name: String, schema_version: String) -> Self {
         LogEvent::Created {
             id: log.id,
             schema_id: log.schema_id,
+            schema_name,
+            schema_version,
             log_data: log.log_data,
+            source: log.source,
+            severity: log.severity,
+            attachments: log.attachments.map(|attachments| attachments.0),
             created_at: log.created_at.to_rfc3339(),
         }
     }
@@ -61,10 +274,12 @@ impl LogEvent {
         }
     }
 
-    pub fn schema_id(&self) -> Uuid {
+    /// `None` for a batch, whose events may belong to different schemas.
+    pub fn schema_id(&self) -> Option<Uuid> {
         match self {
-            LogEvent::Created { schema_id, .. } => *schema_id,
-            LogEvent::Deleted { schema_id, .. } => *schema_id,
+            LogEvent::Created { schema_id, .. } => Some(*schema_id),
+            LogEvent::Deleted { schema_id, .. } => Some(*schema_id),
+            LogEvent::Batch { .. } => None,
         }
     }
 }
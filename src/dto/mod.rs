@@ -1,25 +1,79 @@
+pub mod admin_dto;
+pub mod audit_dto;
 pub mod common;
 pub mod log_dto;
 pub mod schema_dto;
+pub mod webhook_dto;
 
-pub use common::ErrorResponse;
+pub use admin_dto::{GlobalLogStatsResponse, MigrationInfo, MigrationResponse};
+pub use audit_dto::{AuditLogListResponse, GetAuditLogQuery};
+pub use common::{
+    ErrorResponse, PaginatedResponse, PaginationQuery, ValidationError, TOTAL_COUNT_HEADER,
+};
 
 pub use schema_dto::{
+    // Responses
+    BulkCreateSchemaFailure,
+    BulkCreateSchemaResponse,
     // Requests
     CreateSchemaRequest,
     DeleteSchemaQuery,
+    ExportSchemaQuery,
+    FieldInfo,
     // Queries
+    GetSchemaQuery,
     GetSchemasQuery,
-    // Responses
+    PatchSchemaDefinitionRequest,
+    PatchTagsRequest,
+    SchemaDraft,
+    SchemaFieldsResponse,
     SchemaResponse,
+    SchemaVersionSort,
+    SchemaVersionsQuery,
+    SchemaVersionsResponse,
+    SearchSchemasQuery,
+    UpdateSchemaQuery,
     UpdateSchemaRequest,
+    ValidateExampleQuery,
+    ValidateExampleResponse,
+    ValidationReport,
+    ValidationReportError,
 };
 
 pub use log_dto::{
+    // Responses
+    AcrossVersionsQuery,
+    AcrossVersionsResponse,
+    BulkCreateFailure,
+    BulkCreateLogQuery,
     // Requests
+    BulkCreateLogRequest,
+    BulkCreateLogResponse,
     CreateLogRequest,
+    // Queries
+    DeleteLogsBeforeQuery,
+    DeleteLogsByFilterQuery,
+    DeleteLogsResponse,
+    DeleteSchemaLogsQuery,
+    GetLogQuery,
+    GroupedByTimeQuery,
+    GroupedByTimeResponse,
+    LogAttachmentsResponse,
     // WebSocket Events
     LogEvent,
-    // Responses
     LogResponse,
+    TimeBucketCount,
+};
+
+pub use webhook_dto::{
+    // Requests
+    CreateIngestWebhookRequest,
+    CreateWebhookRequest,
+    // Responses
+    IngestWebhookListResponse,
+    IngestWebhookResponse,
+    UpdateIngestWebhookRequest,
+    UpdateWebhookRequest,
+    WebhookListResponse,
+    WebhookResponse,
 };
@@ -1,66 +1,344 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::{repositories::schema_repository::SchemaQueryParams, Schema};
+use crate::{
+    dto::PaginationQuery, models::ValidationMode,
+    repositories::schema_repository::SchemaQueryParams, Schema,
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateSchemaRequest {
     pub name: String,
     pub version: String,
     pub description: Option<String>,
     pub schema_definition: Value,
+    /// Example payloads, stored under the definition's `examples` keyword
+    pub examples: Option<Vec<Value>>,
+    /// Defaults to `strict`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_mode: Option<ValidationMode>,
+    /// Inline remote `$ref`s before storing the definition (default false)
+    #[serde(default)]
+    pub resolve_refs: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateSchemaRequest {
     pub name: String,
     pub version: String,
     pub description: Option<String>,
     pub schema_definition: Value,
+    /// Keeps the current mode when omitted
+    #[serde(default)]
+    pub validation_mode: Option<ValidationMode>,
+    /// The schema's `updated_at` as last read; the update is rejected with 409 if the
+    /// schema has changed since
+    #[serde(default)]
+    pub expected_updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PatchSchemaDefinitionRequest {
+    pub schema_definition: Value,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PatchTagsRequest {
+    /// Tags to append if not already present
+    #[serde(default)]
+    pub add: Vec<String>,
+    /// Tags to remove
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SchemaResponse {
     pub id: Uuid,
+    /// Creation order, for `after_seq` pagination
+    pub seq: i64,
     pub name: String,
     pub version: String,
     pub description: Option<String>,
     pub schema_definition: Value,
+    pub deprecated: bool,
+    /// When the schema was deprecated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_at: Option<String>,
+    pub tags: Vec<String>,
+    pub validation_mode: ValidationMode,
     pub created_at: String,
     pub updated_at: String,
+    /// Number of logs using the schema; only present when `include_stats=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_count: Option<i64>,
+}
+
+impl SchemaResponse {
+    pub fn with_log_count(mut self, log_count: i64) -> Self {
+        self.log_count = Some(log_count);
+        self
+    }
 }
 
 impl From<Schema> for SchemaResponse {
     fn from(schema: Schema) -> Self {
         SchemaResponse {
             id: schema.id,
+            seq: schema.seq,
             name: schema.name,
             version: schema.version,
             description: schema.description,
             schema_definition: schema.schema_definition,
+            deprecated: schema.deprecated,
+            deprecated_at: schema.deprecated_at.map(|at| at.to_rfc3339()),
+            tags: schema.tags,
+            validation_mode: schema.validation_mode,
             created_at: schema.created_at.to_rfc3339(),
             updated_at: schema.updated_at.to_rfc3339(),
+            log_count: None,
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkCreateSchemaResponse {
+    pub created: Vec<SchemaResponse>,
+    pub failed: Vec<BulkCreateSchemaFailure>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkCreateSchemaFailure {
+    pub name: String,
+    pub error: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct GetSchemasQuery {
+    /// Exact schema name
     pub name: Option<String>,
+    /// Exact schema version
     pub version: Option<String>,
+    /// Case-insensitive substring of the schema name, matched anywhere in it; results are
+    /// ordered by name. Cannot be combined with `name`, `version`, `after_seq`,
+    /// `include_stats` or `stream`
+    pub name_contains: Option<String>,
+    /// Maximum number of schemas (default 100, max 1000)
+    pub limit: Option<i64>,
+    /// Number of schemas to skip (default 0)
+    pub offset: Option<i64>,
+    /// Keyset pagination: only schemas with a greater `seq`, in `seq` order. Cannot be
+    /// combined with the other filters, `offset`, `include_stats` or `stream`
+    pub after_seq: Option<i64>,
+    /// Include `log_count` for each schema
+    pub include_stats: Option<bool>,
+    /// Stream every matching schema as a plain JSON array, one schema per line;
+    /// `limit` only applies when given
+    pub stream: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SearchSchemasQuery {
+    /// Search terms matched against schema names and descriptions
+    pub q: Option<String>,
+    /// Maximum number of schemas (default 100, max 1000)
+    pub limit: Option<i64>,
+    /// Number of schemas to skip (default 0)
+    pub offset: Option<i64>,
+}
+
+impl SearchSchemasQuery {
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GetSchemaQuery {
+    /// Include `log_count`; the response then carries no `ETag`
+    pub include_stats: Option<bool>,
+}
+
+impl GetSchemasQuery {
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
 }
 
 impl From<GetSchemasQuery> for SchemaQueryParams {
     fn from(query: GetSchemasQuery) -> Self {
+        let pagination = query.pagination();
+
         SchemaQueryParams {
             name: query.name,
             version: query.version,
+            limit: Some(pagination.limit()),
+            offset: Some(pagination.offset()),
+            after_seq: query.after_seq,
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct UpdateSchemaQuery {
+    /// Reject the update if up to 100 recent logs would fail the new definition
+    pub strict_compatibility: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct DeleteSchemaQuery {
+    /// Also delete all logs referencing the schema
     pub force: Option<bool>,
 }
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ValidateExampleQuery {
+    /// Index into the definition's `examples` (default 0)
+    pub index: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ValidateExampleResponse {
+    pub schema_id: Uuid,
+    pub index: usize,
+    pub valid: bool,
+    /// Validation errors grouped by field; only present when the example is invalid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_errors: Option<HashMap<String, Vec<String>>>,
+}
+
+/// Outcome of `POST /schemas/validate`; nothing is written either way.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ValidationReport {
+    /// Whether every submitted schema could be created
+    pub valid: bool,
+    pub errors: Vec<ValidationReportError>,
+}
+
+impl ValidationReport {
+    pub fn new(errors: Vec<ValidationReportError>) -> Self {
+        Self {
+            valid: errors.is_empty(),
+            errors,
+        }
+    }
+}
+
+/// A rejected schema; `index` is its position in the submitted array.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ValidationReportError {
+    pub index: usize,
+    pub name: String,
+    pub version: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldInfo {
+    /// Property name; nested properties use dot notation (`address.city`)
+    pub name: String,
+    /// JSON Schema `type`; union types are joined with `|`
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SchemaFieldsResponse {
+    pub schema_id: Uuid,
+    pub fields: Vec<FieldInfo>,
+}
+
+/// JSON Schema dialect declared by `GET /schemas/{id}/export.json`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaDraft {
+    Draft4,
+    Draft6,
+    Draft7,
+    Draft201909,
+    #[default]
+    Draft202012,
+}
+
+impl SchemaDraft {
+    pub fn uri(&self) -> &'static str {
+        match self {
+            SchemaDraft::Draft4 => "http://json-schema.org/draft-04/schema#",
+            SchemaDraft::Draft6 => "http://json-schema.org/draft-06/schema#",
+            SchemaDraft::Draft7 => "http://json-schema.org/draft-07/schema#",
+            SchemaDraft::Draft201909 => "https://json-schema.org/draft/2019-09/schema",
+            SchemaDraft::Draft202012 => "https://json-schema.org/draft/2020-12/schema",
+        }
+    }
+}
+
+impl std::str::FromStr for SchemaDraft {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "4" => Ok(SchemaDraft::Draft4),
+            "6" => Ok(SchemaDraft::Draft6),
+            "7" => Ok(SchemaDraft::Draft7),
+            "2019-09" => Ok(SchemaDraft::Draft201909),
+            "2020-12" => Ok(SchemaDraft::Draft202012),
+            _ => Err(format!(
+                "draft '{}' is not one of 4, 6, 7, 2019-09, 2020-12",
+                value
+            )),
+        }
+    }
+}
+
+/// Order of `GET /schemas/{name}/versions`, both highest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaVersionSort {
+    #[default]
+    CreatedAt,
+    Version,
+}
+
+impl std::str::FromStr for SchemaVersionSort {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "created_at" => Ok(SchemaVersionSort::CreatedAt),
+            "version" => Ok(SchemaVersionSort::Version),
+            _ => Err(format!(
+                "sort_by '{}' is not one of created_at, version",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SchemaVersionsQuery {
+    /// `created_at` (default, newest first) or `version` (highest semver first, then
+    /// other versions in descending lexicographic order)
+    pub sort_by: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SchemaVersionsResponse {
+    pub schema_name: String,
+    pub versions: Vec<SchemaResponse>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportSchemaQuery {
+    /// `4`, `6`, `7`, `2019-09` or `2020-12`; overrides a `$schema` already in the definition
+    pub draft: Option<String>,
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::models::{IngestWebhook, Webhook};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    /// Subscribed events: `log.created`, `log.deleted`, `schema.created`, `schema.updated`, `schema.deleted`
+    pub events: Vec<String>,
+    /// Key for the `X-Hub-Signature-256` HMAC
+    pub secret: String,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWebhookRequest {
+    pub url: String,
+    pub events: Vec<String>,
+    /// Omit to keep the current secret
+    pub secret: Option<String>,
+    pub active: Option<bool>,
+}
+
+/// Webhook as returned by the API; the secret is never echoed back.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: String,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(webhook: Webhook) -> Self {
+        WebhookResponse {
+            id: webhook.id,
+            url: webhook.url,
+            events: webhook.events,
+            active: webhook.active,
+            created_at: webhook.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookListResponse {
+    pub webhooks: Vec<WebhookResponse>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateIngestWebhookRequest {
+    /// Schema every ingested log is validated against
+    pub schema_id: Uuid,
+    /// Object mapping log fields to JSON Pointers into the request body, e.g.
+    /// `{"message": "/msg", "level": "/severity"}`; omit to store the body as is
+    pub transform: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateIngestWebhookRequest {
+    pub schema_id: Uuid,
+    pub transform: Option<Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IngestWebhookResponse {
+    pub id: Uuid,
+    pub token: String,
+    pub schema_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform: Option<Value>,
+    /// Path that external systems POST logs to
+    pub ingest_url: String,
+    pub created_at: String,
+}
+
+impl From<IngestWebhook> for IngestWebhookResponse {
+    fn from(webhook: IngestWebhook) -> Self {
+        IngestWebhookResponse {
+            ingest_url: format!("/webhooks/ingest/{}", webhook.token),
+            id: webhook.id,
+            token: webhook.token,
+            schema_id: webhook.schema_id,
+            transform: webhook.transform,
+            created_at: webhook.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IngestWebhookListResponse {
+    pub webhooks: Vec<IngestWebhookResponse>,
+}
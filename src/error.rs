@@ -1,11 +1,14 @@
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
 use serde_json::json;
+use std::collections::HashMap;
 use std::fmt;
 
+use crate::dto::{ErrorResponse, ValidationError};
+
 #[derive(Debug)]
 pub enum AppError {
     // Resource not found (404)
@@ -28,6 +31,41 @@ pub enum AppError {
 
     // Schema validation failed (422)
     SchemaValidationError(String),
+
+    // Log data failed validation against its schema, grouped by field (400)
+    FieldValidationError {
+        message: String,
+        field_errors: HashMap<String, Vec<String>>,
+        /// The same errors, ungrouped and located by JSON Pointer
+        errors: Vec<ValidationError>,
+    },
+
+    // Request payload exceeds a size limit (413)
+    PayloadTooLarge(String),
+
+    // Too many requests (429)
+    RateLimitExceeded(String),
+
+    // Request or query took too long (408)
+    Timeout(String),
+
+    // Missing or invalid credentials (401)
+    Unauthorized(String),
+
+    // Authenticated but not allowed (403)
+    Forbidden(String),
+}
+
+impl AppError {
+    pub fn unauthorized(msg: impl Into<String>) -> Self {
+        AppError::Unauthorized(msg.into())
+    }
+
+    /// `NotFound` with a message naming what was looked up, e.g.
+    /// `Schema with id '6f1c…' not found`.
+    pub fn not_found_resource(resource: &str, field: &str, value: &str) -> Self {
+        AppError::NotFound(format!("{} with {} '{}' not found", resource, field, value))
+    }
 }
 
 impl fmt::Display for AppError {
@@ -40,6 +78,14 @@ impl fmt::Display for AppError {
             AppError::InternalError(msg) => write!(f, "Internal error: {}", msg),
             AppError::BadRequest(msg) => write!(f, "Bad request: {}", msg),
             AppError::SchemaValidationError(msg) => write!(f, "Schema validation error: {}", msg),
+            AppError::FieldValidationError { message, .. } => {
+                write!(f, "Schema validation failed: {}", message)
+            }
+            AppError::PayloadTooLarge(msg) => write!(f, "Payload too large: {}", msg),
+            AppError::RateLimitExceeded(msg) => write!(f, "Rate limit exceeded: {}", msg),
+            AppError::Timeout(msg) => write!(f, "Timeout: {}", msg),
+            AppError::Unauthorized(msg) => write!(f, "Unauthorized: {}", msg),
+            AppError::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
         }
     }
 }
@@ -74,6 +120,63 @@ impl IntoResponse for AppError {
                 "SchemaValidationError",
                 msg,
             ),
+            AppError::FieldValidationError {
+                message,
+                field_errors,
+                errors,
+            } => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(
+                        ErrorResponse::with_field_errors(
+                            "VALIDATION_FAILED",
+                            format!("Schema validation failed: {}", message),
+                            field_errors,
+                        )
+                        .with_validation_errors(errors),
+                    ),
+                )
+                    .into_response();
+            }
+            AppError::PayloadTooLarge(msg) => {
+                return (
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    Json(ErrorResponse::new("PAYLOAD_TOO_LARGE", msg)),
+                )
+                    .into_response();
+            }
+            AppError::RateLimitExceeded(msg) => {
+                tracing::info!("Rate limit exceeded: {}", msg);
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, "60")],
+                    Json(ErrorResponse::new("RATE_LIMIT_EXCEEDED", msg)),
+                )
+                    .into_response();
+            }
+            AppError::Timeout(msg) => {
+                tracing::warn!("Timeout: {}", msg);
+                return (
+                    StatusCode::REQUEST_TIMEOUT,
+                    Json(ErrorResponse::new("TIMEOUT", msg)),
+                )
+                    .into_response();
+            }
+            AppError::Unauthorized(msg) => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    [(header::WWW_AUTHENTICATE, "Bearer")],
+                    Json(ErrorResponse::new("UNAUTHORIZED", msg)),
+                )
+                    .into_response();
+            }
+            AppError::Forbidden(msg) => {
+                return (
+                    StatusCode::FORBIDDEN,
+                    Json(ErrorResponse::new("FORBIDDEN", msg)),
+                )
+                    .into_response();
+            }
         };
 
         let body = Json(json!({
@@ -93,6 +196,18 @@ impl From<anyhow::Error> for AppError {
     }
 }
 
+impl From<std::num::ParseIntError> for AppError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        AppError::BadRequest(format!("Invalid integer: {}", err))
+    }
+}
+
+impl From<uuid::Error> for AppError {
+    fn from(_: uuid::Error) -> Self {
+        AppError::BadRequest("Invalid UUID format".to_string())
+    }
+}
+
 impl From<sqlx::Error> for AppError {
     fn from(err: sqlx::Error) -> Self {
         match err {
@@ -111,6 +226,10 @@ impl From<sqlx::Error> for AppError {
                             "Referenced resource does not exist".to_string(),
                         );
                     }
+                    if code == "57014" {
+                        // PostgreSQL query_canceled (statement_timeout)
+                        return AppError::Timeout("Database query timed out".to_string());
+                    }
                 }
                 AppError::DatabaseError(db_err.to_string())
             }
@@ -0,0 +1,63 @@
+use axum::{extract::State, http::StatusCode, Json};
+
+use crate::{
+    dto::{ErrorResponse, GlobalLogStatsResponse, MigrationResponse},
+    AppState,
+};
+
+/// ## POST /admin/migrate
+/// Apply any pending database migrations.
+#[utoipa::path(
+    post,
+    path = "/admin/migrate",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Migrations applied", body = MigrationResponse),
+        (status = 500, description = "A migration failed", body = ErrorResponse),
+    )
+)]
+pub async fn run_migrations(
+    State(state): State<AppState>,
+) -> Result<Json<MigrationResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.migration_service.run_pending().await {
+        Ok(applied) => {
+            for migration in &applied {
+                tracing::info!(
+                    "Applied migration {} ({})",
+                    migration.version,
+                    migration.description
+                );
+            }
+
+            Ok(Json(MigrationResponse {
+                applied: applied.into_iter().map(Into::into).collect(),
+            }))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("MIGRATION_FAILED", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /admin/stats
+/// Get server-wide log and schema counts.
+#[utoipa::path(
+    get,
+    path = "/admin/stats",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Server-wide statistics", body = GlobalLogStatsResponse),
+    )
+)]
+pub async fn get_admin_stats(
+    State(state): State<AppState>,
+) -> Result<Json<GlobalLogStatsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.log_service.get_log_stats_global().await {
+        Ok(stats) => Ok(Json(GlobalLogStatsResponse::from(stats))),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
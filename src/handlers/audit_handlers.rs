@@ -0,0 +1,87 @@
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, Query, State},
+    http::{request::Parts, StatusCode},
+    Json,
+};
+use std::{convert::Infallible, net::SocketAddr};
+
+use crate::{
+    dto::{AuditLogListResponse, ErrorResponse, GetAuditLogQuery},
+    models::{ApiKey, AuditContext},
+    AppState,
+};
+
+const DEFAULT_AUDIT_LIMIT: i64 = 50;
+const MAX_AUDIT_LIMIT: i64 = 500;
+
+/// Builds the audit context from what earlier middleware left on the request:
+/// the request id, the authenticated API key (if auth is enabled) and the peer address.
+impl<S> FromRequestParts<S> for AuditContext
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(AuditContext {
+            actor: parts
+                .extensions
+                .get::<ApiKey>()
+                .map(|api_key| api_key.id.to_string()),
+            request_id: parts.extensions.get::<String>().cloned(),
+            ip_address: parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string()),
+        })
+    }
+}
+
+/// ## GET /audit
+/// List audit entries, newest first.
+///
+/// Query parameters:
+/// - entity_type: Filter by entity type (`schema` or `log`)
+/// - entity_id: Filter by entity id
+/// - limit: Maximum number of entries (default 50, max 500)
+#[utoipa::path(
+    get,
+    path = "/audit",
+    tag = "audit",
+    params(GetAuditLogQuery),
+    responses(
+        (status = 200, description = "Audit entries, newest first", body = AuditLogListResponse),
+        (status = 400, description = "Invalid limit", body = ErrorResponse),
+    )
+)]
+pub async fn get_audit_log(
+    State(state): State<AppState>,
+    Query(params): Query<GetAuditLogQuery>,
+) -> Result<Json<AuditLogListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let limit = params.limit.unwrap_or(DEFAULT_AUDIT_LIMIT);
+    if !(1..=MAX_AUDIT_LIMIT).contains(&limit) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "INVALID_INPUT",
+                format!("Limit must be between 1 and {}", MAX_AUDIT_LIMIT),
+            )),
+        ));
+    }
+
+    match state
+        .audit_service
+        .get_entries(
+            params.entity_type.as_deref(),
+            params.entity_id.as_deref(),
+            limit,
+        )
+        .await
+    {
+        Ok(audit_logs) => Ok(Json(AuditLogListResponse { audit_logs })),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("FETCH_FAILED", e.to_string())),
+        )),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::{http::StatusCode, Json};
+
+use crate::dto::ErrorResponse;
+
+/// Response for requests that match no route.
+pub async fn handle_404() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse::new(
+            "NOT_FOUND",
+            "The requested endpoint does not exist",
+        )),
+    )
+}
+
+/// Response for requests to an existing route with a method it does not support.
+pub async fn handle_405() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::METHOD_NOT_ALLOWED,
+        Json(ErrorResponse::new(
+            "METHOD_NOT_ALLOWED",
+            "The requested endpoint does not support this method",
+        )),
+    )
+}
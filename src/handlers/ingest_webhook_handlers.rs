@@ -0,0 +1,277 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        CreateIngestWebhookRequest, ErrorResponse, IngestWebhookListResponse,
+        IngestWebhookResponse, LogResponse, UpdateIngestWebhookRequest,
+    },
+    error::AppError,
+    handlers::log_handlers::{announce_log, create_log_error, source_from_user_agent},
+    models::AuditContext,
+    services::LogMetadata,
+    AppState,
+};
+
+/// ## GET /admin/webhooks
+/// List all ingest webhooks.
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks",
+    tag = "admin",
+    responses(
+        (status = 200, description = "All ingest webhooks", body = IngestWebhookListResponse),
+    )
+)]
+pub async fn get_ingest_webhooks(
+    State(state): State<AppState>,
+) -> Result<Json<IngestWebhookListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.ingest_webhook_service.get_ingest_webhooks().await {
+        Ok(webhooks) => Ok(Json(IngestWebhookListResponse {
+            webhooks: webhooks
+                .into_iter()
+                .map(IngestWebhookResponse::from)
+                .collect(),
+        })),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /admin/webhooks/{id}
+/// Get one ingest webhook.
+#[utoipa::path(
+    get,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    params(("id" = Uuid, Path, description = "Ingest webhook ID")),
+    responses(
+        (status = 200, description = "Ingest webhook found", body = IngestWebhookResponse),
+        (status = 404, description = "Ingest webhook not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_ingest_webhook_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<IngestWebhookResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.ingest_webhook_service.get_ingest_webhook(id).await {
+        Ok(Some(webhook)) => Ok(Json(IngestWebhookResponse::from(webhook))),
+        Ok(None) => Err(ingest_webhook_not_found(id)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## POST /admin/webhooks
+/// Register an ingest webhook; logs are posted to its `ingest_url`.
+#[utoipa::path(
+    post,
+    path = "/admin/webhooks",
+    tag = "admin",
+    request_body = CreateIngestWebhookRequest,
+    responses(
+        (status = 201, description = "Ingest webhook created", body = IngestWebhookResponse,
+            headers(("Location" = String, description = "URL of the new ingest webhook"))),
+        (status = 400, description = "Invalid transform", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn create_ingest_webhook(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateIngestWebhookRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .ingest_webhook_service
+        .create_ingest_webhook(payload.schema_id, payload.transform)
+        .await
+    {
+        Ok(webhook) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::LOCATION,
+                format!("/admin/webhooks/{}", webhook.id).parse().unwrap(),
+            );
+
+            Ok((
+                StatusCode::CREATED,
+                headers,
+                Json(IngestWebhookResponse::from(webhook)),
+            ))
+        }
+        Err(e) => Err(ingest_webhook_error(e)),
+    }
+}
+
+/// ## PUT /admin/webhooks/{id}
+/// Replace an ingest webhook's schema and transform. The token is kept.
+#[utoipa::path(
+    put,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    params(("id" = Uuid, Path, description = "Ingest webhook ID")),
+    request_body = UpdateIngestWebhookRequest,
+    responses(
+        (status = 200, description = "Ingest webhook updated", body = IngestWebhookResponse),
+        (status = 400, description = "Invalid transform", body = ErrorResponse),
+        (status = 404, description = "Ingest webhook or schema not found", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn update_ingest_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateIngestWebhookRequest>,
+) -> Result<Json<IngestWebhookResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .ingest_webhook_service
+        .update_ingest_webhook(id, payload.schema_id, payload.transform)
+        .await
+    {
+        Ok(Some(webhook)) => Ok(Json(IngestWebhookResponse::from(webhook))),
+        Ok(None) => Err(ingest_webhook_not_found(id)),
+        Err(e) => Err(ingest_webhook_error(e)),
+    }
+}
+
+/// ## DELETE /admin/webhooks/{id}
+/// Delete an ingest webhook, revoking its token.
+#[utoipa::path(
+    delete,
+    path = "/admin/webhooks/{id}",
+    tag = "admin",
+    params(("id" = Uuid, Path, description = "Ingest webhook ID")),
+    responses(
+        (status = 204, description = "Ingest webhook deleted"),
+        (status = 404, description = "Ingest webhook not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_ingest_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    match state.ingest_webhook_service.delete_ingest_webhook(id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(ingest_webhook_not_found(id)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("DELETION_FAILED", e.to_string())),
+        )),
+    }
+}
+
+/// ## POST /webhooks/ingest/{token}
+/// Create a log from an arbitrary JSON payload, reshaped by the webhook's transform.
+///
+/// The token authenticates the request, so no API key is needed. Requests are rate
+/// limited per token by `INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE`.
+#[utoipa::path(
+    post,
+    path = "/webhooks/ingest/{token}",
+    tag = "webhooks",
+    params(("token" = String, Path, description = "Ingest webhook token")),
+    request_body = Value,
+    responses(
+        (status = 201, description = "Log created", body = LogResponse),
+        (status = 400, description = "Payload is not an object or failed schema validation", body = ErrorResponse),
+        (status = 404, description = "Unknown token", body = ErrorResponse),
+        (status = 413, description = "`log_data` exceeds `MAX_LOG_DATA_BYTES`", body = ErrorResponse),
+        (status = 429, description = "Rate limit for this token exceeded"),
+    )
+)]
+pub async fn ingest_webhook_log(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    headers: HeaderMap,
+    audit_ctx: AuditContext,
+    Json(payload): Json<Value>,
+) -> Result<(StatusCode, Json<LogResponse>), (StatusCode, Json<ErrorResponse>)> {
+    let (schema_id, log_data) = match state
+        .ingest_webhook_service
+        .prepare_log(&token, payload)
+        .await
+    {
+        Ok(Some(prepared)) => prepared,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(
+                    ErrorResponse::new("NOT_FOUND", "No ingest webhook has this token")
+                        .with_resource_type("webhook"),
+                ),
+            ))
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+            ))
+        }
+    };
+
+    if !log_data.is_object() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "INVALID_INPUT",
+                "Log data must be a JSON object",
+            )),
+        ));
+    }
+
+    let metadata = LogMetadata {
+        source: source_from_user_agent(&headers),
+        correlation_id: None,
+        severity: None,
+        attachments: None,
+    };
+
+    match state
+        .log_service
+        .validate_and_create(schema_id, log_data, metadata, &audit_ctx)
+        .await
+    {
+        Ok((log, schema)) => Ok((StatusCode::CREATED, Json(announce_log(&state, log, schema)))),
+        Err(e) => Err(create_log_error(e)),
+    }
+}
+
+fn ingest_webhook_error(e: AppError) -> (StatusCode, Json<ErrorResponse>) {
+    match e {
+        AppError::ValidationError(message) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ),
+        AppError::NotFound(message) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+        ),
+        e => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        ),
+    }
+}
+
+fn ingest_webhook_not_found(id: Uuid) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(
+            ErrorResponse::new(
+                "NOT_FOUND",
+                format!("Ingest webhook with id '{}' not found", id),
+            )
+            .with_resource_type("webhook"),
+        ),
+    )
+}
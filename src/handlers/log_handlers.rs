@@ -1,34 +1,129 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    body::Bytes,
+    extract::{FromRequest, Path, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::{DateTime, Duration, Utc};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use uuid::Uuid;
 
 use crate::{
-    dto::{CreateLogRequest, ErrorResponse, LogEvent, LogResponse},
+    dto::{
+        AcrossVersionsQuery, AcrossVersionsResponse, BulkCreateFailure, BulkCreateLogQuery,
+        BulkCreateLogRequest, BulkCreateLogResponse, CreateLogRequest, DeleteLogsBeforeQuery,
+        DeleteLogsByFilterQuery, DeleteLogsResponse, DeleteSchemaLogsQuery, ErrorResponse,
+        GetLogQuery, GroupedByTimeQuery, GroupedByTimeResponse, LogAttachmentsResponse, LogEvent,
+        LogResponse, PaginatedResponse, PaginationQuery, TOTAL_COUNT_HEADER,
+    },
+    error::AppError,
+    models::{
+        webhook_model::{EVENT_LOG_CREATED, EVENT_LOG_DELETED},
+        AuditContext, Log, Schema,
+    },
+    repositories::log_repository::{
+        LogDeleteFilter, LogSort, SeverityRange, SortOrder, TimeBucket,
+    },
+    services::{LogMetadata, LogService},
+    utils::{
+        hash_idempotency_key,
+        idempotency::{IDEMPOTENCY_KEY_HEADER, MAX_IDEMPOTENCY_KEY_LENGTH},
+    },
     AppState,
 };
 
+/// ## GET /logs/schema/{schema_name}
+/// List the logs of a schema's highest version.
+#[utoipa::path(
+    get,
+    path = "/logs/schema/{schema_name}",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name; its highest version is used"),
+        PaginationQuery,
+        ("include_schema" = Option<bool>, Query, description = "Add `schema_name` and `schema_version` to each log"),
+    ),
+    responses(
+        (status = 200, description = "Logs for the schema", body = PaginatedResponse<LogResponse>,
+            headers(("X-Total-Count" = i64, description = "Number of matching logs across all pages"))),
+        (status = 400, description = "Invalid schema name", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_logs_default(
     State(state): State<AppState>,
     Path(schema_name): Path<String>,
     Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<Value>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    // Report bad pagination before a missing schema, as the versioned route does
+    if let Err(message) = pagination_from_params(&mut params.clone()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ));
+    }
+
+    let schema_version = match state.schema_service.get_latest_by_name(&schema_name).await {
+        Ok(Some(schema)) => schema.version,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(
+                    ErrorResponse::new(
+                        "NOT_FOUND",
+                        format!("Schema with name '{}' not found", schema_name),
+                    )
+                    .with_resource_type("schema"),
+                ),
+            ));
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+            ));
+        }
+    };
+
     get_logs(
         State(state),
-        Path((schema_name, "1.0.0".to_string())),
+        Path((schema_name, schema_version)),
         Query(params),
     )
     .await
 }
 
+/// ## GET /logs/schema/{schema_name}/{schema_version}
+/// List a schema version's logs, filtered by `log_data` fields, newest first.
+#[utoipa::path(
+    get,
+    path = "/logs/schema/{schema_name}/{schema_version}",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        ("schema_version" = String, Path, description = "Schema version"),
+        PaginationQuery,
+        ("include_schema" = Option<bool>, Query, description = "Add `schema_name` and `schema_version` to each log"),
+        ("source" = Option<String>, Query, description = "Only logs submitted by this source"),
+        ("sort_field" = Option<String>, Query, description = "Sort by this top-level `log_data` field (as text) instead of `created_at`"),
+        ("sort_order" = Option<String>, Query, description = "`asc` (default with `sort_field`) or `desc` (default otherwise)"),
+        ("min_severity" = Option<i16>, Query, description = "Only logs with at least this severity (0-5)"),
+        ("max_severity" = Option<i16>, Query, description = "Only logs with at most this severity (0-5)"),
+    ),
+    responses(
+        (status = 200, description = "Logs for the schema; any query parameter other than `limit`, `offset`, `include_schema`, `source`, `sort_field`, `sort_order`, `min_severity` and `max_severity` filters on a `log_data` field", body = PaginatedResponse<LogResponse>,
+            headers(("X-Total-Count" = i64, description = "Number of matching logs across all pages"))),
+        (status = 400, description = "Invalid schema name, version, sort or severity", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_logs(
     State(state): State<AppState>,
     Path((schema_name, schema_version)): Path<(String, String)>,
-    Query(params): Query<HashMap<String, String>>,
-) -> Result<Json<Value>, (StatusCode, Json<ErrorResponse>)> {
+    Query(mut params): Query<HashMap<String, String>>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
     if schema_name.trim().is_empty() || schema_version.trim().is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -39,6 +134,25 @@ pub async fn get_logs(
         ));
     }
 
+    let include_schema = params
+        .remove("include_schema")
+        .is_some_and(|value| value == "true");
+    let source = params.remove("source");
+
+    let query = pagination_from_params(&mut params).and_then(|pagination| {
+        let sort = sort_from_params(&mut params)?;
+        Ok((pagination, sort, severity_from_params(&mut params)?))
+    });
+    let (pagination, sort, severity) = match query {
+        Ok(query) => query,
+        Err(message) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("INVALID_INPUT", message)),
+            ));
+        }
+    };
+
     let filters: Option<Value> = if params.is_empty() {
         None
     } else {
@@ -52,13 +166,48 @@ pub async fn get_logs(
 
     match state
         .log_service
-        .get_logs_by_schema_name_and_id(&schema_name, &schema_version, filters)
+        .get_logs_by_schema_name_and_id(
+            &schema_name,
+            &schema_version,
+            filters,
+            source.as_deref(),
+            &severity,
+            &sort,
+            &pagination,
+        )
         .await
     {
-        Ok(logs) => {
-            let log_responses: Vec<LogResponse> = logs.into_iter().map(LogResponse::from).collect();
+        Ok((logs, total)) => {
+            let schemas = if include_schema {
+                match state.log_service.schemas_for_logs(&logs).await {
+                    Ok(schemas) => schemas,
+                    Err(e) => {
+                        return Err((
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+                        ))
+                    }
+                }
+            } else {
+                HashMap::new()
+            };
+
+            let logs = logs
+                .into_iter()
+                .map(|log| match schemas.get(&log.schema_id) {
+                    Some(schema) => LogResponse::from(log).with_schema(schema),
+                    None => LogResponse::from(log),
+                })
+                .collect();
 
-            Ok(Json(json!({ "logs": log_responses })))
+            Ok((
+                [(TOTAL_COUNT_HEADER, total.to_string())],
+                Json(PaginatedResponse::new(
+                    logs,
+                    &pagination,
+                    pagination.is_requested().then_some(total),
+                )),
+            ))
         }
         Err(e) => {
             let status_code = if e.to_string().contains("not found") {
@@ -69,24 +218,347 @@ pub async fn get_logs(
 
             Err((
                 status_code,
-                Json(ErrorResponse::new("NOT_FOUND", e.to_string())),
+                Json(ErrorResponse::new("NOT_FOUND", e.to_string()).with_resource_type("schema")),
             ))
         }
     }
 }
 
+/// ## GET /logs/schema/{schema_name}/{schema_version}/grouped-by-time
+/// Count a schema version's logs per minute, hour, day or week, for trend charts.
+#[utoipa::path(
+    get,
+    path = "/logs/schema/{schema_name}/{schema_version}/grouped-by-time",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        ("schema_version" = String, Path, description = "Schema version"),
+        GroupedByTimeQuery,
+    ),
+    responses(
+        (status = 200, description = "Log counts per time bucket", body = GroupedByTimeResponse),
+        (status = 400, description = "Invalid bucket or time range", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_logs_grouped_by_time(
+    State(state): State<AppState>,
+    Path((schema_name, schema_version)): Path<(String, String)>,
+    Query(params): Query<GroupedByTimeQuery>,
+) -> Result<Json<GroupedByTimeResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        )
+    };
+
+    let bucket = match params.bucket.as_deref() {
+        Some(bucket) => bucket.parse::<TimeBucket>().map_err(bad_request)?,
+        None => TimeBucket::Hour,
+    };
+
+    let to = match params.to.as_deref() {
+        Some(to) => parse_timestamp("to", to).map_err(bad_request)?,
+        None => Utc::now(),
+    };
+    let from = match params.from.as_deref() {
+        Some(from) => parse_timestamp("from", from).map_err(bad_request)?,
+        None => to - Duration::hours(24),
+    };
+
+    if from > to {
+        return Err(bad_request("from must not be after to".to_string()));
+    }
+
+    match state
+        .log_service
+        .get_logs_grouped_by_time(&schema_name, &schema_version, bucket, from, to)
+        .await
+    {
+        Ok(buckets) => Ok(Json(GroupedByTimeResponse {
+            buckets: buckets.into_iter().map(Into::into).collect(),
+        })),
+        Err(AppError::NotFound(message)) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /logs/correlation/{correlation_id}
+/// List the logs sharing a correlation ID, from any schema, oldest first.
+#[utoipa::path(
+    get,
+    path = "/logs/correlation/{correlation_id}",
+    tag = "logs",
+    params(
+        ("correlation_id" = String, Path, description = "Correlation ID"),
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, description = "Logs with this correlation ID, each with its `schema_name` and `schema_version`", body = PaginatedResponse<LogResponse>,
+            headers(("X-Total-Count" = i64, description = "Number of matching logs across all pages"))),
+        (status = 400, description = "Invalid pagination", body = ErrorResponse),
+    )
+)]
+pub async fn get_logs_by_correlation_id(
+    State(state): State<AppState>,
+    Path(correlation_id): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    if let Err(message) = pagination.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ));
+    }
+
+    let internal_error = |e: AppError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )
+    };
+
+    let (logs, total) = state
+        .log_service
+        .get_logs_by_correlation_id(&correlation_id, &pagination)
+        .await
+        .map_err(internal_error)?;
+    let schemas = state
+        .log_service
+        .schemas_for_logs(&logs)
+        .await
+        .map_err(internal_error)?;
+
+    let logs = logs
+        .into_iter()
+        .map(|log| match schemas.get(&log.schema_id) {
+            Some(schema) => LogResponse::from(log).with_schema(schema),
+            None => LogResponse::from(log),
+        })
+        .collect();
+
+    Ok((
+        [(TOTAL_COUNT_HEADER, total.to_string())],
+        Json(PaginatedResponse::new(
+            logs,
+            &pagination,
+            pagination.is_requested().then_some(total),
+        )),
+    ))
+}
+
+/// ## GET /logs/schema/{schema_name}/across-versions
+/// List the logs of every version of a schema together, newest first.
+#[utoipa::path(
+    get,
+    path = "/logs/schema/{schema_name}/across-versions",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        AcrossVersionsQuery,
+    ),
+    responses(
+        (status = 200, description = "Logs of all versions of the schema", body = AcrossVersionsResponse,
+            headers(("X-Total-Count" = i64, description = "Number of matching logs across all pages"))),
+        (status = 400, description = "Invalid pagination or time range", body = ErrorResponse),
+        (status = 404, description = "No schema with this name", body = ErrorResponse),
+    )
+)]
+pub async fn get_logs_across_versions(
+    State(state): State<AppState>,
+    Path(schema_name): Path<String>,
+    Query(params): Query<AcrossVersionsQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        )
+    };
+
+    let pagination = PaginationQuery {
+        limit: params.limit,
+        offset: params.offset,
+    };
+    pagination.validate().map_err(bad_request)?;
+
+    let from = params
+        .from
+        .as_deref()
+        .map(|from| parse_timestamp("from", from))
+        .transpose()
+        .map_err(bad_request)?;
+    let to = params
+        .to
+        .as_deref()
+        .map(|to| parse_timestamp("to", to))
+        .transpose()
+        .map_err(bad_request)?;
+
+    if matches!((from, to), (Some(from), Some(to)) if from > to) {
+        return Err(bad_request("from must not be after to".to_string()));
+    }
+
+    match state
+        .log_service
+        .get_logs_across_versions(&schema_name, from, to, &pagination)
+        .await
+    {
+        Ok((logs, schema_versions_queried, total)) => Ok((
+            [(TOTAL_COUNT_HEADER, total.to_string())],
+            Json(AcrossVersionsResponse {
+                logs: logs.into_iter().map(LogResponse::from).collect(),
+                schema_versions_queried,
+            }),
+        )),
+        Err(AppError::NotFound(message)) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+fn parse_timestamp(name: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| format!("{} '{}' is not a valid RFC 3339 date-time", name, value))
+}
+
+/// Removes `limit` and `offset` from the query so the rest can be used as filters.
+fn pagination_from_params(params: &mut HashMap<String, String>) -> Result<PaginationQuery, String> {
+    let mut parse = |key: &str| {
+        params
+            .remove(key)
+            .map(|value| {
+                value
+                    .parse::<i64>()
+                    .map_err(|_| format!("{} must be an integer", key))
+            })
+            .transpose()
+    };
+
+    let pagination = PaginationQuery {
+        limit: parse("limit")?,
+        offset: parse("offset")?,
+    };
+    pagination.validate()?;
+
+    Ok(pagination)
+}
+
+fn sort_from_params(params: &mut HashMap<String, String>) -> Result<LogSort, String> {
+    let order = params
+        .remove("sort_order")
+        .map(|order| order.parse::<SortOrder>())
+        .transpose()?;
+
+    LogSort::new(params.remove("sort_field"), order)
+}
+
+fn severity_from_params(params: &mut HashMap<String, String>) -> Result<SeverityRange, String> {
+    let mut bound = |name: &str| {
+        params
+            .remove(name)
+            .map(|value| {
+                value
+                    .parse::<i16>()
+                    .map_err(|_| format!("{} must be an integer", name))
+            })
+            .transpose()
+    };
+
+    SeverityRange::new(bound("min_severity")?, bound("max_severity")?)
+}
+
+/// ## GET /logs/{id}
+/// Get one log.
+#[utoipa::path(
+    get,
+    path = "/logs/{id}",
+    tag = "logs",
+    params(
+        ("id" = i32, Path, description = "Log ID"),
+        GetLogQuery,
+    ),
+    responses(
+        (status = 200, description = "Log found", body = LogResponse),
+        (status = 400, description = "Invalid log ID"),
+        (status = 404, description = "Log not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_log_by_id(
     State(state): State<AppState>,
     Path(id): Path<i32>,
+    Query(query): Query<GetLogQuery>,
 ) -> Result<Json<LogResponse>, (StatusCode, Json<ErrorResponse>)> {
     match state.log_service.get_log_by_id(id).await {
+        Ok(Some(log)) if query.include_schema.unwrap_or(false) => {
+            match state.schema_service.get_schema_by_id(log.schema_id).await {
+                Ok(Some(schema)) => Ok(Json(LogResponse::from(log).with_schema(&schema))),
+                Ok(None) => Ok(Json(LogResponse::from(log))),
+                Err(e) => Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("FETCH_FAILED", e.to_string())),
+                )),
+            }
+        }
         Ok(Some(log)) => Ok(Json(LogResponse::from(log))),
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!("Log with id '{}' not found", id),
-            )),
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Log with id '{}' not found", id))
+                    .with_resource_type("log"),
+            ),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("FETCH_FAILED", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /logs/{id}/attachments
+/// Get the attachments of one log; empty when it has none.
+#[utoipa::path(
+    get,
+    path = "/logs/{id}/attachments",
+    tag = "logs",
+    params(("id" = i32, Path, description = "Log ID")),
+    responses(
+        (status = 200, description = "The log's attachments", body = LogAttachmentsResponse),
+        (status = 400, description = "Invalid log ID"),
+        (status = 404, description = "Log not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_log_attachments(
+    State(state): State<AppState>,
+    Path(id): Path<i32>,
+) -> Result<Json<LogAttachmentsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.log_service.get_log_by_id(id).await {
+        Ok(Some(log)) => Ok(Json(LogAttachmentsResponse {
+            log_id: log.id,
+            attachments: log
+                .attachments
+                .map(|attachments| attachments.0)
+                .unwrap_or_default(),
+        })),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Log with id '{}' not found", id))
+                    .with_resource_type("log"),
+            ),
         )),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -95,10 +567,36 @@ pub async fn get_log_by_id(
     }
 }
 
+/// ## POST /logs
+/// Create a new log entry validated against its schema.
+///
+/// When an `Idempotency-Key` header is sent, retries with the same key (for the same
+/// schema, within 24 hours) return the originally created log with 200 instead of
+/// creating a duplicate.
+#[utoipa::path(
+    post,
+    path = "/logs",
+    tag = "logs",
+    request_body = CreateLogRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Deduplicates retries for 24 hours"),
+    ),
+    responses(
+        (status = 201, description = "Log created", body = LogResponse,
+            headers(("Location" = String, description = "URL of the new log"))),
+        (status = 200, description = "Log previously created with the same Idempotency-Key", body = LogResponse),
+        (status = 400, description = "Invalid input or log data failed schema validation", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 413, description = "`log_data` exceeds `MAX_LOG_DATA_BYTES`", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
 pub async fn create_log(
     State(state): State<AppState>,
+    headers: HeaderMap,
+    audit_ctx: AuditContext,
     Json(payload): Json<CreateLogRequest>,
-) -> Result<(StatusCode, Json<LogResponse>), (StatusCode, Json<ErrorResponse>)> {
+) -> Result<(StatusCode, HeaderMap, Json<LogResponse>), (StatusCode, Json<ErrorResponse>)> {
     if payload.schema_id.is_nil() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -119,18 +617,123 @@ pub async fn create_log(
         ));
     }
 
-    match state
-        .log_service
-        .create_log(payload.schema_id, payload.log_data)
-        .await
-    {
-        Ok(log) => {
-            let _ = state
-                .log_broadcast
-                .send(LogEvent::created_from(log.clone()));
-            Ok((StatusCode::CREATED, Json(LogResponse::from(log))))
+    let idempotency_key = match headers.get(IDEMPOTENCY_KEY_HEADER) {
+        Some(value) => match value.to_str() {
+            Ok(key) if !key.trim().is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH => {
+                Some(hash_idempotency_key(key, payload.schema_id))
+            }
+            _ => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::new(
+                        "INVALID_INPUT",
+                        format!(
+                            "{} must be a non-empty ASCII string of at most {} characters",
+                            IDEMPOTENCY_KEY_HEADER, MAX_IDEMPOTENCY_KEY_LENGTH
+                        ),
+                    )),
+                ));
+            }
+        },
+        None => None,
+    };
+
+    let metadata = LogMetadata {
+        source: payload.source.or_else(|| source_from_user_agent(&headers)),
+        correlation_id: payload.correlation_id,
+        severity: payload.severity,
+        attachments: payload.attachments,
+    };
+
+    let result = match idempotency_key {
+        Some(key) => {
+            state
+                .log_service
+                .create_log_idempotent(
+                    &key,
+                    payload.schema_id,
+                    payload.log_data,
+                    metadata,
+                    &audit_ctx,
+                )
+                .await
         }
-        Err(e) => {
+        None => state
+            .log_service
+            .validate_and_create(payload.schema_id, payload.log_data, metadata, &audit_ctx)
+            .await
+            .map(|(log, schema)| (log, schema, true)),
+    };
+
+    match result {
+        Ok((log, schema, true)) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::LOCATION,
+                format!("/logs/{}", log.id).parse().unwrap(),
+            );
+
+            Ok((
+                StatusCode::CREATED,
+                headers,
+                Json(announce_log(&state, log, schema)),
+            ))
+        }
+        Ok((log, _, false)) => Ok((
+            StatusCode::OK,
+            HeaderMap::new(),
+            Json(LogResponse::from(log)),
+        )),
+        Err(e) => Err(create_log_error(e)),
+    }
+}
+
+/// Broadcasts a newly created log to WebSocket subscribers and webhooks.
+pub(crate) fn announce_log(state: &AppState, log: Log, schema: Schema) -> LogResponse {
+    let _ = state.log_broadcast.send(LogEvent::created_from(
+        log.clone(),
+        schema.name,
+        schema.version,
+    ));
+
+    let response = LogResponse::from(log);
+    state
+        .webhook_service
+        .dispatch(EVENT_LOG_CREATED, json!(response));
+    response
+}
+
+/// Maps a failed log creation to its HTTP error.
+pub(crate) fn create_log_error(e: AppError) -> (StatusCode, Json<ErrorResponse>) {
+    match e {
+        AppError::FieldValidationError {
+            message,
+            field_errors,
+            errors,
+        } => (
+            StatusCode::BAD_REQUEST,
+            Json(
+                ErrorResponse::with_field_errors(
+                    "VALIDATION_FAILED",
+                    format!("Schema validation failed: {}", message),
+                    field_errors,
+                )
+                .with_validation_errors(errors),
+            ),
+        ),
+        AppError::PayloadTooLarge(message) => (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(ErrorResponse::new("PAYLOAD_TOO_LARGE", message)),
+        ),
+        AppError::BadRequest(message) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ),
+        AppError::NotFound(message) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+        ),
+        e => {
             let (status_code, error) = if e.to_string().contains("not found") {
                 (StatusCode::NOT_FOUND, "NOT_FOUND")
             } else if e.to_string().contains("validation")
@@ -141,29 +744,240 @@ pub async fn create_log(
                 (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_SERVER_ERROR")
             };
 
-            Err((status_code, Json(ErrorResponse::new(error, e.to_string()))))
+            (status_code, Json(ErrorResponse::new(error, e.to_string())))
         }
     }
 }
 
+/// The product name of a `User-Agent` such as `billing-service/2.1 (linux)`, when it
+/// is a valid log source.
+pub(crate) fn source_from_user_agent(headers: &HeaderMap) -> Option<String> {
+    let user_agent = headers.get(header::USER_AGENT)?.to_str().ok()?;
+    let product = user_agent
+        .split(|c: char| c == '/' || c.is_whitespace())
+        .next()?;
+
+    LogService::validate_source(Some(product))
+        .is_ok()
+        .then(|| product.to_string())
+}
+
+/// Maximum number of entries accepted by `POST /logs/batch`.
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// Content type of a batch sent as one `CreateLogRequest` per line.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Reads a batch from either a JSON `BulkCreateLogRequest` or an NDJSON body, where
+/// `fail_fast` comes from the query string instead.
+impl<S> FromRequest<S> for BulkCreateLogRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_ndjson = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with(NDJSON_CONTENT_TYPE));
+
+        if !is_ndjson {
+            let Json(payload) = Json::<Self>::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            return Ok(payload);
+        }
+
+        let Query(query) = Query::<BulkCreateLogQuery>::try_from_uri(req.uri())
+            .map_err(IntoResponse::into_response)?;
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let mut logs = Vec::new();
+        for (index, line) in body.split(|&byte| byte == b'\n').enumerate() {
+            if line.trim_ascii().is_empty() {
+                continue;
+            }
+
+            let log = serde_json::from_slice::<CreateLogRequest>(line).map_err(|e| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::new(
+                        "INVALID_INPUT",
+                        format!("Invalid log on line {}: {}", index + 1, e),
+                    )),
+                )
+                    .into_response()
+            })?;
+            logs.push(log);
+        }
+
+        Ok(BulkCreateLogRequest {
+            logs,
+            fail_fast: query.fail_fast,
+        })
+    }
+}
+
+/// ## POST /logs/batch
+/// Create up to 1000 logs in one request.
+///
+/// By default each entry is validated and stored on its own. With `fail_fast` every
+/// entry is validated first and nothing is written unless all of them are valid.
+///
+/// The body may also be NDJSON (`Content-Type: application/x-ndjson`), one log per
+/// line, with `fail_fast` passed as a query parameter.
+#[utoipa::path(
+    post,
+    path = "/logs/batch",
+    tag = "logs",
+    params(BulkCreateLogQuery),
+    request_body(
+        content(
+            (BulkCreateLogRequest = "application/json"),
+            (CreateLogRequest = "application/x-ndjson"),
+        )
+    ),
+    responses(
+        (status = 201, description = "All logs created", body = BulkCreateLogResponse),
+        (status = 207, description = "Some logs created; see `failed`", body = BulkCreateLogResponse),
+        (status = 400, description = "No logs created, or the batch is empty or too large", body = BulkCreateLogResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn create_logs_batch(
+    State(state): State<AppState>,
+    audit_ctx: AuditContext,
+    payload: BulkCreateLogRequest,
+) -> Result<(StatusCode, Json<BulkCreateLogResponse>), (StatusCode, Json<ErrorResponse>)> {
+    if payload.logs.is_empty() || payload.logs.len() > MAX_BATCH_SIZE {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "INVALID_INPUT",
+                format!("A batch must contain between 1 and {} logs", MAX_BATCH_SIZE),
+            )),
+        ));
+    }
+
+    let total_submitted = payload.logs.len();
+    let mut failed = Vec::new();
+    let mut entries = Vec::with_capacity(total_submitted);
+    let mut indices = Vec::with_capacity(total_submitted);
+
+    for (index, entry) in payload.logs.into_iter().enumerate() {
+        if entry.schema_id.is_nil() || !entry.log_data.is_object() {
+            failed.push(BulkCreateFailure {
+                index,
+                schema_id: entry.schema_id,
+                error_code: "INVALID_INPUT".to_string(),
+                error_message: "Schema ID cannot be empty and log data must be a JSON object"
+                    .to_string(),
+            });
+        } else {
+            indices.push(index);
+            let metadata = LogMetadata {
+                source: entry.source,
+                correlation_id: entry.correlation_id,
+                severity: entry.severity,
+                attachments: entry.attachments,
+            };
+            entries.push((entry.schema_id, entry.log_data, metadata));
+        }
+    }
+
+    let fail_fast = payload.fail_fast.unwrap_or(false);
+    let result = if fail_fast && !failed.is_empty() {
+        Vec::new()
+    } else {
+        match state
+            .log_service
+            .bulk_create_logs(entries, fail_fast, &audit_ctx)
+            .await
+        {
+            Ok(result) => {
+                failed.extend(result.failed.into_iter().map(|failure| BulkCreateFailure {
+                    // Map back to the position in the submitted batch
+                    index: indices[failure.index],
+                    schema_id: failure.schema_id,
+                    ..BulkCreateFailure::from(failure.error)
+                }));
+                result.created
+            }
+            Err(e) => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+                ))
+            }
+        }
+    };
+    failed.sort_by_key(|failure| failure.index);
+
+    let created: Vec<LogResponse> = result.into_iter().map(LogResponse::from).collect();
+    for log in &created {
+        state
+            .webhook_service
+            .dispatch(EVENT_LOG_CREATED, json!(log));
+    }
+
+    let status = if failed.is_empty() {
+        StatusCode::CREATED
+    } else if created.is_empty() {
+        StatusCode::BAD_REQUEST
+    } else {
+        StatusCode::MULTI_STATUS
+    };
+
+    Ok((
+        status,
+        Json(BulkCreateLogResponse {
+            total_submitted,
+            total_created: created.len(),
+            total_failed: failed.len(),
+            created,
+            failed,
+        }),
+    ))
+}
+
+/// ## DELETE /logs/{id}
+/// Delete one log.
+#[utoipa::path(
+    delete,
+    path = "/logs/{id}",
+    tag = "logs",
+    params(("id" = i32, Path, description = "Log ID")),
+    responses(
+        (status = 204, description = "Log deleted"),
+        (status = 400, description = "Invalid log ID"),
+        (status = 404, description = "Log not found", body = ErrorResponse),
+    )
+)]
 pub async fn delete_log(
     State(state): State<AppState>,
     Path(id): Path<i32>,
+    audit_ctx: AuditContext,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    let log = state.log_service.get_log_by_id(id).await;
-    match state.log_service.delete_log(id).await {
-        Ok(true) => {
-            if let Ok(Some(log)) = log {
-                let _ = state.log_broadcast.send(LogEvent::deleted_from(log));
-            }
+    match state.log_service.delete_log(id, &audit_ctx).await {
+        Ok(Some(log)) => {
+            let _ = state
+                .log_broadcast
+                .send(LogEvent::deleted_from(log.clone()));
+            state
+                .webhook_service
+                .dispatch(EVENT_LOG_DELETED, json!(LogResponse::from(log)));
             Ok(StatusCode::NO_CONTENT)
         }
-        Ok(false) => Err((
+        Ok(None) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!("Log with id '{}' not found", id),
-            )),
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Log with id '{}' not found", id))
+                    .with_resource_type("log"),
+            ),
         )),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -171,3 +985,227 @@ pub async fn delete_log(
         )),
     }
 }
+
+/// ## DELETE /logs/schema/{schema_name}/before
+/// Delete all logs of a schema created before `timestamp`. Requires the `admin`
+/// permission when authentication is enabled.
+#[utoipa::path(
+    delete,
+    path = "/logs/schema/{schema_name}/before",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        DeleteLogsBeforeQuery,
+    ),
+    responses(
+        (status = 200, description = "Number of deleted logs", body = DeleteLogsResponse),
+        (status = 400, description = "Invalid timestamp", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_logs_before(
+    State(state): State<AppState>,
+    Path(schema_name): Path<String>,
+    Query(params): Query<DeleteLogsBeforeQuery>,
+    audit_ctx: AuditContext,
+) -> Result<Json<DeleteLogsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let before = match DateTime::parse_from_rfc3339(&params.timestamp) {
+        Ok(timestamp) => timestamp.with_timezone(&Utc),
+        Err(_) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    format!(
+                        "timestamp '{}' is not a valid RFC 3339 date-time",
+                        params.timestamp
+                    ),
+                )),
+            ));
+        }
+    };
+
+    let schema = resolve_schema_version(&state, &schema_name, params.version.as_deref()).await?;
+
+    match state
+        .log_service
+        .delete_logs_before(schema.id, before, &audit_ctx)
+        .await
+    {
+        Ok(deleted_count) => Ok(Json(DeleteLogsResponse { deleted_count })),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("DELETION_FAILED", e.to_string())),
+        )),
+    }
+}
+
+/// Looks up the schema named `schema_name` at `version`, or at its highest version when
+/// no version is given.
+async fn resolve_schema_version(
+    state: &AppState,
+    schema_name: &str,
+    version: Option<&str>,
+) -> Result<Schema, (StatusCode, Json<ErrorResponse>)> {
+    let lookup = match version {
+        Some(version) => {
+            state
+                .schema_service
+                .get_by_name_and_version(schema_name, version)
+                .await
+        }
+        None => state.schema_service.get_latest_by_name(schema_name).await,
+    };
+
+    match lookup {
+        Ok(Some(schema)) => Ok(schema),
+        Ok(None) => {
+            let message = match version {
+                Some(version) => format!(
+                    "Schema with name:version '{}:{}' not found",
+                    schema_name, version
+                ),
+                None => format!("Schema with name '{}' not found", schema_name),
+            };
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+            ))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## DELETE /schemas/{id}/logs
+/// Delete all logs of a schema, or those created before `before`, keeping the schema.
+/// Requires the `admin` permission when authentication is enabled.
+#[utoipa::path(
+    delete,
+    path = "/schemas/{id}/logs",
+    tag = "logs",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        DeleteSchemaLogsQuery,
+    ),
+    responses(
+        (status = 200, description = "Number of deleted logs", body = DeleteLogsResponse),
+        (status = 204, description = "The schema has no logs to delete"),
+        (status = 400, description = "Invalid timestamp", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_schema_logs(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<DeleteSchemaLogsQuery>,
+    audit_ctx: AuditContext,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let before = params
+        .before
+        .as_deref()
+        .map(|before| parse_timestamp("before", before))
+        .transpose()
+        .map_err(|message| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("INVALID_INPUT", message)),
+            )
+        })?;
+
+    match state.schema_service.get_schema_by_id(id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(
+                    ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                        .with_resource_type("schema"),
+                ),
+            ));
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+            ));
+        }
+    }
+
+    match state
+        .log_service
+        .delete_schema_logs(id, before, &audit_ctx)
+        .await
+    {
+        Ok(0) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Ok(deleted_count) => Ok(Json(DeleteLogsResponse { deleted_count }).into_response()),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("DELETION_FAILED", e.to_string())),
+        )),
+    }
+}
+
+/// ## DELETE /logs/schema/{schema_name}/bulk
+/// Delete the logs of a schema matching all given criteria, for retention policies.
+/// Requires the `admin` permission when authentication is enabled.
+#[utoipa::path(
+    delete,
+    path = "/logs/schema/{schema_name}/bulk",
+    tag = "logs",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        DeleteLogsByFilterQuery,
+    ),
+    responses(
+        (status = 200, description = "Number of deleted logs", body = DeleteLogsResponse),
+        (status = 400, description = "Invalid timestamp or no criteria given", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_logs_by_filter(
+    State(state): State<AppState>,
+    Path(schema_name): Path<String>,
+    Query(params): Query<DeleteLogsByFilterQuery>,
+    audit_ctx: AuditContext,
+) -> Result<Json<DeleteLogsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        )
+    };
+
+    let filter = LogDeleteFilter {
+        before: params
+            .before
+            .as_deref()
+            .map(|before| parse_timestamp("before", before))
+            .transpose()
+            .map_err(bad_request)?,
+        after: params
+            .after
+            .as_deref()
+            .map(|after| parse_timestamp("after", after))
+            .transpose()
+            .map_err(bad_request)?,
+        source: params.source,
+    };
+
+    let schema = resolve_schema_version(&state, &schema_name, params.version.as_deref()).await?;
+
+    match state
+        .log_service
+        .delete_logs_by_filter(schema.id, &filter, &audit_ctx)
+        .await
+    {
+        Ok(deleted_count) => Ok(Json(DeleteLogsResponse { deleted_count })),
+        Err(AppError::BadRequest(message)) => Err(bad_request(message)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("DELETION_FAILED", e.to_string())),
+        )),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::AppState;
+
+/// Gauge refreshed on every scrape from `SchemaService::cache_hit_rate`.
+pub const SCHEMA_CACHE_HIT_RATE: &str = "schema_cache_hit_rate";
+
+/// ## GET /metrics
+/// Report server metrics in the Prometheus text format.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text exposition format", content_type = "text/plain"),
+    )
+)]
+pub async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    metrics::gauge!(SCHEMA_CACHE_HIT_RATE).set(state.schema_service.cache_hit_rate());
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
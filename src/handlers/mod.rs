@@ -1,10 +1,33 @@
+pub mod admin_handlers;
+pub mod audit_handlers;
+pub mod fallback_handlers;
+pub mod ingest_webhook_handlers;
 pub mod log_handlers;
+pub mod metrics_handlers;
 pub mod schema_handlers;
+pub mod webhook_handlers;
 pub mod ws_handlers;
 
-pub use log_handlers::{create_log, delete_log, get_log_by_id, get_logs, get_logs_default};
+pub use admin_handlers::{get_admin_stats, run_migrations};
+pub use audit_handlers::get_audit_log;
+pub use fallback_handlers::{handle_404, handle_405};
+pub use ingest_webhook_handlers::{
+    create_ingest_webhook, delete_ingest_webhook, get_ingest_webhook_by_id, get_ingest_webhooks,
+    ingest_webhook_log, update_ingest_webhook,
+};
+pub use log_handlers::{
+    create_log, create_logs_batch, delete_log, delete_logs_before, delete_logs_by_filter,
+    delete_schema_logs, get_log_attachments, get_log_by_id, get_logs, get_logs_across_versions,
+    get_logs_by_correlation_id, get_logs_default, get_logs_grouped_by_time,
+};
+pub use metrics_handlers::get_metrics;
 pub use schema_handlers::{
-    create_schema, delete_schema, get_schema_by_id, get_schema_by_name_and_version, get_schemas,
-    update_schema,
+    create_schema, delete_schema, deprecate_schema, export_schema, get_schema_by_id,
+    get_schema_by_name_and_version, get_schema_fields, get_schema_versions, get_schemas,
+    import_openapi_schemas, patch_schema_definition, patch_schema_tags, search_schemas,
+    undeprecate_schema, update_schema, validate_schema_example, validate_schemas,
+};
+pub use webhook_handlers::{
+    create_webhook, delete_webhook, get_webhook_by_id, get_webhooks, update_webhook,
 };
-pub use ws_handlers::ws_handler;
+pub use ws_handlers::{ws_handler, ws_replay_handler};
@@ -1,21 +1,107 @@
 use axum::{
+    body::{Body, Bytes},
     extract::{Path, Query, State},
     http::{header, HeaderMap, StatusCode},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
+use futures_util::stream::{self, BoxStream, StreamExt};
 use serde_json::{json, Value};
 use uuid::Uuid;
 
 use crate::{
     dto::{
-        CreateSchemaRequest, DeleteSchemaQuery, ErrorResponse, GetSchemasQuery, SchemaResponse,
-        UpdateSchemaRequest,
+        BulkCreateSchemaFailure, BulkCreateSchemaResponse, CreateSchemaRequest, DeleteSchemaQuery,
+        ErrorResponse, ExportSchemaQuery, GetSchemaQuery, GetSchemasQuery, PaginatedResponse,
+        PatchSchemaDefinitionRequest, PatchTagsRequest, SchemaDraft, SchemaFieldsResponse,
+        SchemaResponse, SchemaVersionSort, SchemaVersionsQuery, SchemaVersionsResponse,
+        SearchSchemasQuery, UpdateSchemaQuery, UpdateSchemaRequest, ValidateExampleQuery,
+        ValidateExampleResponse, ValidationReport, TOTAL_COUNT_HEADER,
+    },
+    error::{AppError, AppResult},
+    models::{
+        webhook_model::{EVENT_SCHEMA_CREATED, EVENT_SCHEMA_DELETED, EVENT_SCHEMA_UPDATED},
+        AuditContext,
     },
     repositories::schema_repository::SchemaQueryParams,
-    AppState,
+    services::SchemaService,
+    utils::{compute_schema_etag, if_none_match_satisfied},
+    AppState, Schema,
 };
 
+/// Bytes collected before a chunk of a streamed schema listing is flushed.
+const STREAM_FLUSH_BYTES: usize = 16 * 1024;
+
+/// Serializes `schemas` as a JSON array with one schema per line, flushing the
+/// output whenever 16KB have accumulated. A database error ends the body early.
+fn schema_array_body(schemas: BoxStream<'static, AppResult<Schema>>) -> Body {
+    let chunks = stream::unfold(Some((schemas, b"[\n".to_vec(), true)), |state| async move {
+        let (mut schemas, mut buffer, mut first) = state?;
+
+        loop {
+            match schemas.next().await {
+                Some(Ok(schema)) => {
+                    if !first {
+                        buffer.extend_from_slice(b",\n");
+                    }
+                    first = false;
+
+                    if let Err(e) =
+                        serde_json::to_writer(&mut buffer, &SchemaResponse::from(schema))
+                    {
+                        return Some((Err(AppError::InternalError(e.to_string())), None));
+                    }
+
+                    if buffer.len() >= STREAM_FLUSH_BYTES {
+                        let chunk = Bytes::from(std::mem::take(&mut buffer));
+                        return Some((Ok(chunk), Some((schemas, buffer, first))));
+                    }
+                }
+                Some(Err(e)) => {
+                    tracing::error!("Schema stream failed: {}", e);
+                    return Some((Err(e), None));
+                }
+                None => {
+                    buffer.extend_from_slice(b"\n]\n");
+                    return Some((Ok(Bytes::from(buffer)), None));
+                }
+            }
+        }
+    });
+
+    Body::from_stream(chunks)
+}
+
+/// Responds with the schema and its `ETag`, or `304 Not Modified` when the
+/// client's `If-None-Match` already matches.
+fn schema_response_with_etag(schema: Schema, headers: &HeaderMap) -> Response {
+    let schema = SchemaResponse::from(schema);
+    let etag = compute_schema_etag(&schema);
+
+    if if_none_match_satisfied(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response();
+    }
+
+    ([(header::ETAG, etag)], Json(schema)).into_response()
+}
+
+/// Responds with the schema and its log count. Counts change without the schema
+/// changing, so no `ETag` is sent.
+async fn schema_response_with_stats(
+    state: &AppState,
+    schema: Schema,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    match state.schema_service.get_log_count(schema.id).await {
+        Ok(log_count) => {
+            Ok(Json(SchemaResponse::from(schema).with_log_count(log_count)).into_response())
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
 /// ## GET /schemas
 /// Get all schemas with optional filtering by name and/or version.
 ///
@@ -23,6 +109,12 @@ use crate::{
 /// - name: Filter schemas by exact name match
 /// - version: Filter schemas by exact version match
 /// - Both can be combined for precise filtering
+/// - name_contains: Case-insensitive substring of the name, ordered by name; cannot be
+///   combined with the other filters, after_seq, include_stats or stream
+/// - limit, offset: Pagination (default 100 and 0); when given, the response also
+///   carries `total` and `has_more`
+/// - after_seq: Keyset pagination over all schemas in `seq` order; pass the last
+///   `seq` of the previous page
 ///
 /// All filtering is performed at the database level for optimal performance.
 ///
@@ -31,25 +123,240 @@ use crate::{
 /// - /schemas?name=web-server-logs - Get all versions of "web-server-logs"
 /// - /schemas?version=1.0.0 - Get all schemas with version "1.0.0"
 /// - /schemas?name=web-server-logs&version=1.0.0 - Get specific schema by name+version
+/// - /schemas?name_contains=audit - Get schemas with "audit" anywhere in their name
+#[utoipa::path(
+    get,
+    path = "/schemas",
+    tag = "schemas",
+    params(GetSchemasQuery),
+    responses(
+        (status = 200, description = "Matching schemas; with `stream=true`, a plain JSON array of schemas instead", body = PaginatedResponse<SchemaResponse>,
+            headers(("X-Total-Count" = i64, description = "Number of matching schemas across all pages; not sent with `stream=true`"))),
+        (status = 400, description = "Invalid limit or offset, or incompatible parameters combined", body = ErrorResponse),
+    )
+)]
 pub async fn get_schemas(
     State(state): State<AppState>,
     Query(query): Query<GetSchemasQuery>,
-) -> Result<Json<Value>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let pagination = query.pagination();
+    if let Err(message) = pagination.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ));
+    }
+
+    if let Some(substring) = query.name_contains.as_deref() {
+        let combined = query.name.is_some()
+            || query.version.is_some()
+            || query.after_seq.is_some()
+            || query.include_stats.unwrap_or(false)
+            || query.stream.unwrap_or(false);
+        if combined {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    "name_contains cannot be combined with name, version, after_seq, include_stats or stream",
+                )),
+            ));
+        }
+
+        return match state
+            .schema_service
+            .get_schemas_by_name_containing(substring)
+            .await
+        {
+            Ok(schemas) => {
+                let total = schemas.len() as i64;
+                let page = schemas
+                    .into_iter()
+                    .skip(pagination.offset() as usize)
+                    .take(pagination.limit() as usize)
+                    .map(SchemaResponse::from)
+                    .collect();
+
+                Ok((
+                    [(TOTAL_COUNT_HEADER, total.to_string())],
+                    Json(PaginatedResponse::new(
+                        page,
+                        &pagination,
+                        pagination.is_requested().then_some(total),
+                    )),
+                )
+                    .into_response())
+            }
+            Err(e) => Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+            )),
+        };
+    }
+
+    if query.after_seq.is_some() {
+        let combined = query.name.is_some()
+            || query.version.is_some()
+            || query.offset.is_some()
+            || query.include_stats.unwrap_or(false)
+            || query.stream.unwrap_or(false);
+        if combined {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    "after_seq cannot be combined with name, version, offset, include_stats or stream",
+                )),
+            ));
+        }
+
+        let params = SchemaQueryParams::from(query);
+        // The total covers the whole catalog, not just the schemas after the cursor
+        let count_params = SchemaQueryParams {
+            after_seq: None,
+            ..params.clone()
+        };
+
+        return match tokio::try_join!(
+            state.schema_service.get_all_schemas(Some(params)),
+            state.schema_service.count_schemas(&count_params)
+        ) {
+            Ok((schemas, total)) => Ok((
+                [(TOTAL_COUNT_HEADER, total.to_string())],
+                Json(PaginatedResponse::new(
+                    schemas.into_iter().map(SchemaResponse::from).collect(),
+                    &pagination,
+                    None,
+                )),
+            )
+                .into_response()),
+            Err(e) => Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+            )),
+        };
+    }
+
+    if query.stream.unwrap_or(false) {
+        if query.include_stats.unwrap_or(false) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    "stream cannot be combined with include_stats",
+                )),
+            ));
+        }
+
+        let params = SchemaQueryParams {
+            limit: query.limit,
+            offset: query.offset,
+            name: query.name,
+            version: query.version,
+            after_seq: None,
+        };
+
+        return Ok((
+            [(header::CONTENT_TYPE, "application/json")],
+            schema_array_body(state.schema_service.stream_schemas(params)),
+        )
+            .into_response());
+    }
+
+    let include_stats = query.include_stats.unwrap_or(false);
     let repo_params = SchemaQueryParams::from(query);
 
+    let schemas = async {
+        if include_stats {
+            state
+                .schema_service
+                .get_all_schemas_with_counts(Some(repo_params.clone()))
+                .await
+                .map(|schemas| {
+                    schemas
+                        .into_iter()
+                        .map(|(schema, log_count)| {
+                            SchemaResponse::from(schema).with_log_count(log_count)
+                        })
+                        .collect()
+                })
+        } else {
+            state
+                .schema_service
+                .get_all_schemas(Some(repo_params.clone()))
+                .await
+                .map(|schemas| schemas.into_iter().map(SchemaResponse::from).collect())
+        }
+    };
+
+    match tokio::try_join!(schemas, state.schema_service.count_schemas(&repo_params)) {
+        Ok((schemas, total)) => Ok((
+            [(TOTAL_COUNT_HEADER, total.to_string())],
+            Json(PaginatedResponse::new(
+                schemas,
+                &pagination,
+                pagination.is_requested().then_some(total),
+            )),
+        )
+            .into_response()),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /schemas/search
+/// Find schemas by keywords in their name or description, best matches first.
+///
+/// Example: /schemas/search?q=audit+event
+#[utoipa::path(
+    get,
+    path = "/schemas/search",
+    tag = "schemas",
+    params(SearchSchemasQuery),
+    responses(
+        (status = 200, description = "Matching schemas", body = PaginatedResponse<SchemaResponse>,
+            headers(("X-Total-Count" = i64, description = "Number of matching schemas across all pages"))),
+        (status = 400, description = "Empty query, or invalid limit or offset", body = ErrorResponse),
+    )
+)]
+pub async fn search_schemas(
+    State(state): State<AppState>,
+    Query(query): Query<SearchSchemasQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let pagination = query.pagination();
+    if let Err(message) = pagination.validate() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        ));
+    }
+
+    let terms = query.q.as_deref().map(str::trim).unwrap_or_default();
+    if terms.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "INVALID_INPUT",
+                "Search query 'q' cannot be empty",
+            )),
+        ));
+    }
+
     match state
         .schema_service
-        .get_all_schemas(Some(repo_params))
+        .search_schemas(terms, &pagination)
         .await
     {
-        Ok(schemas) => {
-            let schema_responses: Vec<SchemaResponse> = schemas
-                .into_iter()
-                .map(|schema| SchemaResponse::from(schema))
-                .collect();
-
-            Ok(Json(json!({ "schemas": schema_responses })))
-        }
+        Ok((schemas, total)) => Ok((
+            [(TOTAL_COUNT_HEADER, total.to_string())],
+            Json(PaginatedResponse::new(
+                schemas.into_iter().map(SchemaResponse::from).collect(),
+                &pagination,
+                pagination.is_requested().then_some(total),
+            )),
+        )),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
@@ -59,10 +366,32 @@ pub async fn get_schemas(
 
 /// ## GET /schemas/{schema_name}/{schema_version}
 /// Get one schema with matching name and version.
+///
+/// Supports conditional requests via `ETag` / `If-None-Match`.
+#[utoipa::path(
+    get,
+    path = "/schemas/{schema_name}/{schema_version}",
+    tag = "schemas",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        ("schema_version" = String, Path, description = "Schema version"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from a previous response"),
+        GetSchemaQuery,
+    ),
+    responses(
+        (status = 200, description = "Schema found", body = SchemaResponse,
+            headers(("ETag" = String, description = "Hash of the schema definition"))),
+        (status = 304, description = "Schema unchanged since the given ETag"),
+        (status = 400, description = "Invalid schema name or version", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_schema_by_name_and_version(
     State(state): State<AppState>,
     Path((schema_name, schema_version)): Path<(String, String)>,
-) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    Query(query): Query<GetSchemaQuery>,
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     if schema_name.trim().is_empty() || schema_version.trim().is_empty() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -78,17 +407,86 @@ pub async fn get_schema_by_name_and_version(
         .get_by_name_and_version(&schema_name, &schema_version)
         .await
     {
-        Ok(Some(schema)) => Ok(Json(SchemaResponse::from(schema))),
+        Ok(Some(schema)) if query.include_stats.unwrap_or(false) => {
+            schema_response_with_stats(&state, schema).await
+        }
+        Ok(Some(schema)) => Ok(schema_response_with_etag(schema, &headers)),
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!(
-                    "Schema with name '{}' and version '{}' not found",
-                    schema_name, schema_version
-                ),
-            )),
+            Json(
+                ErrorResponse::new(
+                    "NOT_FOUND",
+                    format!(
+                        "Schema with name '{}' and version '{}' not found",
+                        schema_name, schema_version
+                    ),
+                )
+                .with_resource_type("schema"),
+            ),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /schemas/{schema_name}/versions
+/// Get every version of a schema, newest first or, with `sort_by=version`, highest
+/// version first.
+#[utoipa::path(
+    get,
+    path = "/schemas/{schema_name}/versions",
+    tag = "schemas",
+    params(
+        ("schema_name" = String, Path, description = "Schema name"),
+        SchemaVersionsQuery,
+    ),
+    responses(
+        (status = 200, description = "All versions of the schema", body = SchemaVersionsResponse),
+        (status = 400, description = "Unknown sort_by", body = ErrorResponse),
+        (status = 404, description = "No schema with this name", body = ErrorResponse),
+    )
+)]
+pub async fn get_schema_versions(
+    State(state): State<AppState>,
+    Path(schema_name): Path<String>,
+    Query(query): Query<SchemaVersionsQuery>,
+) -> Result<Json<SchemaVersionsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let sort_by = match query
+        .sort_by
+        .as_deref()
+        .map(str::parse::<SchemaVersionSort>)
+    {
+        Some(Ok(sort_by)) => sort_by,
+        Some(Err(message)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("INVALID_INPUT", message)),
+            ));
+        }
+        None => SchemaVersionSort::default(),
+    };
+
+    match state
+        .schema_service
+        .get_schema_versions(&schema_name, sort_by)
+        .await
+    {
+        Ok(schemas) if schemas.is_empty() => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new(
+                    "NOT_FOUND",
+                    format!("Schema with name '{}' not found", schema_name),
+                )
+                .with_resource_type("schema"),
+            ),
         )),
+        Ok(schemas) => Ok(Json(SchemaVersionsResponse {
+            schema_name,
+            versions: schemas.into_iter().map(SchemaResponse::from).collect(),
+        })),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
@@ -98,10 +496,31 @@ pub async fn get_schema_by_name_and_version(
 
 /// ## GET /schemas/{schema_id}
 /// Get one schema with matching id.
+///
+/// Supports conditional requests via `ETag` / `If-None-Match`.
+#[utoipa::path(
+    get,
+    path = "/schemas/{id}",
+    tag = "schemas",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from a previous response"),
+        GetSchemaQuery,
+    ),
+    responses(
+        (status = 200, description = "Schema found", body = SchemaResponse,
+            headers(("ETag" = String, description = "Hash of the schema definition"))),
+        (status = 304, description = "Schema unchanged since the given ETag"),
+        (status = 400, description = "Invalid schema ID", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_schema_by_id(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    Query(query): Query<GetSchemaQuery>,
+    headers: HeaderMap,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     if id.is_nil() {
         return Err((
             StatusCode::BAD_REQUEST,
@@ -113,13 +532,98 @@ pub async fn get_schema_by_id(
     }
 
     match state.schema_service.get_schema_by_id(id).await {
-        Ok(Some(schema)) => Ok(Json(SchemaResponse::from(schema))),
+        Ok(Some(schema)) if query.include_stats.unwrap_or(false) => {
+            schema_response_with_stats(&state, schema).await
+        }
+        Ok(Some(schema)) => Ok(schema_response_with_etag(schema, &headers)),
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!("Schema with id '{}' not found", id),
-            )),
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /schemas/{schema_id}/fields
+/// List the fields declared in the schema's `properties`, including nested ones.
+#[utoipa::path(
+    get,
+    path = "/schemas/{id}/fields",
+    tag = "schemas",
+    params(("id" = Uuid, Path, description = "Schema ID")),
+    responses(
+        (status = 200, description = "Schema fields", body = SchemaFieldsResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_schema_fields(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SchemaFieldsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.schema_service.get_schema_by_id(id).await {
+        Ok(Some(schema)) => Ok(Json(SchemaFieldsResponse {
+            schema_id: schema.id,
+            fields: SchemaService::extract_fields(&schema),
+        })),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /schemas/{schema_id}/export.json
+/// Export the definition as a self-contained JSON Schema document for external tooling.
+#[utoipa::path(
+    get,
+    path = "/schemas/{id}/export.json",
+    tag = "schemas",
+    params(("id" = Uuid, Path, description = "Schema ID"), ExportSchemaQuery),
+    responses(
+        (status = 200, description = "JSON Schema document", body = Object,
+            content_type = "application/schema+json"),
+        (status = 400, description = "Unknown draft", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn export_schema(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<ExportSchemaQuery>,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let draft = match params.draft.as_deref().map(str::parse::<SchemaDraft>) {
+        Some(Ok(draft)) => Some(draft),
+        Some(Err(message)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("INVALID_INPUT", message)),
+            ));
+        }
+        None => None,
+    };
+
+    match state.schema_service.export_as_json_schema(id, draft).await {
+        Ok(document) => Ok((
+            [(header::CONTENT_TYPE, "application/schema+json")],
+            document.to_string(),
+        )
+            .into_response()),
+        Err(AppError::NotFound(message)) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
         )),
         Err(e) => Err((
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -130,8 +634,24 @@ pub async fn get_schema_by_id(
 
 /// ## POST /schemas
 /// Create a new schema.
+///
+/// With `resolve_refs`, remote `$ref`s in the definition are fetched and inlined first.
+#[utoipa::path(
+    post,
+    path = "/schemas",
+    tag = "schemas",
+    request_body = CreateSchemaRequest,
+    responses(
+        (status = 201, description = "Schema created", body = SchemaResponse,
+            headers(("Location" = String, description = "URL of the new schema"))),
+        (status = 400, description = "Invalid input or JSON Schema", body = ErrorResponse),
+        (status = 409, description = "Schema with this name and version already exists", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
 pub async fn create_schema(
     State(state): State<AppState>,
+    audit_ctx: AuditContext,
     Json(payload): Json<CreateSchemaRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
     if payload.name.trim().is_empty() {
@@ -154,13 +674,43 @@ pub async fn create_schema(
         ));
     }
 
+    if let Err(e) = SchemaService::validate_schema_name(&payload.name)
+        .and_then(|_| state.schema_service.validate_version(&payload.version))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+        ));
+    }
+
+    let schema_definition = match payload.examples {
+        Some(examples) => SchemaService::with_examples(payload.schema_definition, examples),
+        None => payload.schema_definition,
+    };
+
+    let schema_definition = if payload.resolve_refs {
+        match state.schema_service.resolve_refs(schema_definition).await {
+            Ok(schema_definition) => schema_definition,
+            Err(e) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+                ))
+            }
+        }
+    } else {
+        schema_definition
+    };
+
     match state
         .schema_service
         .create_schema(
             payload.name,
             payload.version,
             payload.description,
-            payload.schema_definition,
+            schema_definition,
+            payload.validation_mode.unwrap_or_default(),
+            &audit_ctx,
         )
         .await
     {
@@ -172,34 +722,110 @@ pub async fn create_schema(
                 format!("/schemas/{}", schema_id).parse().unwrap(),
             );
 
-            Ok((
-                StatusCode::CREATED,
-                headers,
-                Json(SchemaResponse::from(schema)),
-            ))
+            let response = SchemaResponse::from(schema);
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_CREATED, json!(response));
+            Ok((StatusCode::CREATED, headers, Json(response)))
         }
         Err(e) => {
-            let error_msg = e.to_string();
-            let (status_code, error_code) = if error_msg.contains("already exists") {
-                (StatusCode::CONFLICT, "SCHEMA_CONFLICT")
-            } else if error_msg.contains("Invalid JSON Schema")
-                || error_msg.contains("Schema definition must be")
-            {
-                (StatusCode::BAD_REQUEST, "INVALID_SCHEMA")
-            } else {
-                (StatusCode::BAD_REQUEST, "CREATION_FAILED")
+            let (status_code, error_code) = match &e {
+                AppError::Conflict(_) => (StatusCode::CONFLICT, "SCHEMA_CONFLICT"),
+                AppError::ValidationError(_) | AppError::SchemaValidationError(_) => {
+                    (StatusCode::BAD_REQUEST, "INVALID_SCHEMA")
+                }
+                _ => (StatusCode::BAD_REQUEST, "CREATION_FAILED"),
+            };
+
+            Err((
+                status_code,
+                Json(ErrorResponse::new(error_code, e.to_string())),
+            ))
+        }
+    }
+}
+
+/// ## POST /schemas/import-openapi
+/// Create a schema (version 1.0.0) for every entry of an OpenAPI `components/schemas`
+/// object. Accepts either a whole OpenAPI document or just that object.
+///
+/// Local `$ref`s are inlined before creation; schemas with external or circular
+/// references are reported in `failed` without affecting the others.
+#[utoipa::path(
+    post,
+    path = "/schemas/import-openapi",
+    tag = "schemas",
+    request_body(content = Object, description = "OpenAPI document or its components/schemas object"),
+    responses(
+        (status = 200, description = "Per-schema import results", body = BulkCreateSchemaResponse),
+        (status = 400, description = "Body contains no components/schemas object", body = ErrorResponse),
+    )
+)]
+pub async fn import_openapi_schemas(
+    State(state): State<AppState>,
+    audit_ctx: AuditContext,
+    Json(body): Json<Value>,
+) -> Result<Json<BulkCreateSchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .schema_service
+        .import_openapi_schemas(&body, &audit_ctx)
+        .await
+    {
+        Ok(results) => {
+            let mut response = BulkCreateSchemaResponse {
+                created: Vec::new(),
+                failed: Vec::new(),
             };
 
-            Err((status_code, Json(ErrorResponse::new(error_code, error_msg))))
+            for (name, result) in results {
+                match result {
+                    Ok(schema) => {
+                        let schema = SchemaResponse::from(schema);
+                        state
+                            .webhook_service
+                            .dispatch(EVENT_SCHEMA_CREATED, json!(schema));
+                        response.created.push(schema);
+                    }
+                    Err(e) => response.failed.push(BulkCreateSchemaFailure {
+                        name,
+                        error: e.to_string(),
+                    }),
+                }
+            }
+
+            Ok(Json(response))
         }
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+        )),
     }
 }
 
 /// ## PUT /schemas/{schema_id}
 /// Update an existing schema.
+#[utoipa::path(
+    put,
+    path = "/schemas/{id}",
+    tag = "schemas",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        UpdateSchemaQuery,
+    ),
+    request_body = UpdateSchemaRequest,
+    responses(
+        (status = 200, description = "Schema updated", body = SchemaResponse),
+        (status = 400, description = "Invalid input or JSON Schema", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 409, description = "Schema with this name and version already exists, existing logs fail the new definition, or the schema changed since `expected_updated_at`", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
 pub async fn update_schema(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    Query(params): Query<UpdateSchemaQuery>,
+    audit_ctx: AuditContext,
     Json(payload): Json<UpdateSchemaRequest>,
 ) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
     if id.is_nil() {
@@ -222,6 +848,15 @@ pub async fn update_schema(
         ));
     }
 
+    if let Err(e) = SchemaService::validate_schema_name(&payload.name)
+        .and_then(|_| state.schema_service.validate_version(&payload.version))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+        ));
+    }
+
     match state
         .schema_service
         .update_schema(
@@ -230,40 +865,322 @@ pub async fn update_schema(
             payload.version,
             payload.description,
             payload.schema_definition,
+            payload.validation_mode,
+            payload.expected_updated_at,
+            params.strict_compatibility.unwrap_or(false),
+            &audit_ctx,
         )
         .await
     {
-        Ok(Some(schema)) => Ok(Json(SchemaResponse::from(schema))),
+        Ok(Some(schema)) => {
+            let response = SchemaResponse::from(schema);
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_UPDATED, json!(response));
+            Ok(Json(response))
+        }
         Ok(None) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!("Schema with id '{}' not found", id),
-            )),
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
         )),
         Err(e) => {
-            let error_msg = e.to_string();
-            let (status_code, error_code) = if error_msg.contains("already exists") {
-                (StatusCode::CONFLICT, "SCHEMA_CONFLICT")
-            } else if error_msg.contains("Invalid JSON Schema")
-                || error_msg.contains("Schema definition must be")
-            {
-                (StatusCode::BAD_REQUEST, "INVALID_SCHEMA")
-            } else {
-                (StatusCode::BAD_REQUEST, "UPDATE_FAILED")
+            let (status_code, error_code) = match &e {
+                AppError::ValidationError(_) | AppError::SchemaValidationError(_) => {
+                    (StatusCode::BAD_REQUEST, "INVALID_SCHEMA")
+                }
+                AppError::Conflict(message) if message.contains("incompatible with") => {
+                    (StatusCode::CONFLICT, "INCOMPATIBLE_SCHEMA")
+                }
+                AppError::Conflict(message) if message.contains("modified by another request") => {
+                    (StatusCode::CONFLICT, "SCHEMA_MODIFIED")
+                }
+                AppError::Conflict(_) => (StatusCode::CONFLICT, "SCHEMA_CONFLICT"),
+                _ => (StatusCode::BAD_REQUEST, "UPDATE_FAILED"),
             };
 
-            Err((status_code, Json(ErrorResponse::new(error_code, error_msg))))
+            Err((
+                status_code,
+                Json(ErrorResponse::new(error_code, e.to_string())),
+            ))
         }
     }
 }
 
+/// ## POST /schemas/{id}/deprecate
+/// Mark a schema as deprecated. Logs are still accepted for it.
+#[utoipa::path(
+    post,
+    path = "/schemas/{id}/deprecate",
+    tag = "schemas",
+    params(("id" = Uuid, Path, description = "Schema ID")),
+    responses(
+        (status = 200, description = "Schema deprecated", body = SchemaResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn deprecate_schema(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    audit_ctx: AuditContext,
+) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    set_schema_deprecated(state, id, true, audit_ctx).await
+}
+
+/// ## POST /schemas/{id}/undeprecate
+/// Clear a schema's deprecation.
+#[utoipa::path(
+    post,
+    path = "/schemas/{id}/undeprecate",
+    tag = "schemas",
+    params(("id" = Uuid, Path, description = "Schema ID")),
+    responses(
+        (status = 200, description = "Schema no longer deprecated", body = SchemaResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn undeprecate_schema(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    audit_ctx: AuditContext,
+) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    set_schema_deprecated(state, id, false, audit_ctx).await
+}
+
+async fn set_schema_deprecated(
+    state: AppState,
+    id: Uuid,
+    deprecated: bool,
+    audit_ctx: AuditContext,
+) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let result = if deprecated {
+        state.schema_service.deprecate_schema(id, &audit_ctx).await
+    } else {
+        state
+            .schema_service
+            .undeprecate_schema(id, &audit_ctx)
+            .await
+    };
+
+    match result {
+        Ok(Some(schema)) => {
+            let response = SchemaResponse::from(schema);
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_UPDATED, json!(response));
+            Ok(Json(response))
+        }
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## PATCH /schemas/{id}/schema_definition
+/// Replace only the schema's definition, keeping its name, version and description.
+#[utoipa::path(
+    patch,
+    path = "/schemas/{id}/schema_definition",
+    tag = "schemas",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        UpdateSchemaQuery,
+    ),
+    request_body = PatchSchemaDefinitionRequest,
+    responses(
+        (status = 200, description = "Schema with its new definition", body = SchemaResponse),
+        (status = 400, description = "Invalid JSON Schema", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 409, description = "Existing logs fail the new definition", body = ErrorResponse),
+    )
+)]
+pub async fn patch_schema_definition(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<UpdateSchemaQuery>,
+    audit_ctx: AuditContext,
+    Json(payload): Json<PatchSchemaDefinitionRequest>,
+) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .schema_service
+        .update_schema_definition_only(
+            id,
+            payload.schema_definition,
+            params.strict_compatibility.unwrap_or(false),
+            &audit_ctx,
+        )
+        .await
+    {
+        Ok(Some(schema)) => {
+            let response = SchemaResponse::from(schema);
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_UPDATED, json!(response));
+            Ok(Json(response))
+        }
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
+        )),
+        Err(e @ (AppError::ValidationError(_) | AppError::SchemaValidationError(_))) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_SCHEMA", e.to_string())),
+        )),
+        Err(AppError::Conflict(message)) => Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new("INCOMPATIBLE_SCHEMA", message)),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## PATCH /schemas/{id}/tags
+/// Add and remove individual tags, keeping the others in place.
+#[utoipa::path(
+    patch,
+    path = "/schemas/{id}/tags",
+    tag = "schemas",
+    params(("id" = Uuid, Path, description = "Schema ID")),
+    request_body = PatchTagsRequest,
+    responses(
+        (status = 200, description = "Schema with its new tags", body = SchemaResponse),
+        (status = 400, description = "Resulting tags are invalid or too many", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+    )
+)]
+pub async fn patch_schema_tags(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    audit_ctx: AuditContext,
+    Json(payload): Json<PatchTagsRequest>,
+) -> Result<Json<SchemaResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .schema_service
+        .patch_tags(id, payload.add, payload.remove, &audit_ctx)
+        .await
+    {
+        Ok(Some(schema)) => {
+            let response = SchemaResponse::from(schema);
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_UPDATED, json!(response));
+            Ok(Json(response))
+        }
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
+        )),
+        Err(AppError::ValidationError(message)) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_SERVER_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## POST /schemas/validate
+/// Check a batch of schemas before importing them, without creating any.
+#[utoipa::path(
+    post,
+    path = "/schemas/validate",
+    tag = "schemas",
+    request_body = Vec<CreateSchemaRequest>,
+    responses(
+        (status = 200, description = "Validation result for the whole batch", body = ValidationReport),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn validate_schemas(
+    State(state): State<AppState>,
+    Json(payload): Json<Vec<CreateSchemaRequest>>,
+) -> Json<ValidationReport> {
+    Json(state.schema_service.validate_schemas_dry_run(payload))
+}
+
+/// ## POST /schemas/{schema_id}/validate-example
+/// Validate one of the schema's stored `examples` against the schema.
+#[utoipa::path(
+    post,
+    path = "/schemas/{id}/validate-example",
+    tag = "schemas",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        ValidateExampleQuery,
+    ),
+    responses(
+        (status = 200, description = "Validation result", body = ValidateExampleResponse),
+        (status = 404, description = "Schema or example not found", body = ErrorResponse),
+    )
+)]
+pub async fn validate_schema_example(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ValidateExampleQuery>,
+) -> Result<Json<ValidateExampleResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let index = query.index.unwrap_or(0);
+
+    match state.schema_service.validate_example(id, index).await {
+        Ok(field_errors) => Ok(Json(ValidateExampleResponse {
+            schema_id: id,
+            index,
+            valid: field_errors.is_empty(),
+            field_errors: (!field_errors.is_empty()).then_some(field_errors),
+        })),
+        Err(AppError::NotFound(message)) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", message).with_resource_type("schema")),
+        )),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
 /// ## DELETE /schema/{schema_id}
 /// Delete a schema.
+#[utoipa::path(
+    delete,
+    path = "/schemas/{id}",
+    tag = "schemas",
+    params(
+        ("id" = Uuid, Path, description = "Schema ID"),
+        DeleteSchemaQuery,
+    ),
+    responses(
+        (status = 204, description = "Schema deleted"),
+        (status = 400, description = "Invalid schema ID", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 409, description = "Schema still has logs and `force` was not set", body = ErrorResponse),
+    )
+)]
 pub async fn delete_schema(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
     Query(params): Query<DeleteSchemaQuery>,
+    audit_ctx: AuditContext,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
     if id.is_nil() {
         return Err((
@@ -277,14 +1194,23 @@ pub async fn delete_schema(
 
     let force = params.force.unwrap_or(false);
 
-    match state.schema_service.delete_schema(id, force).await {
-        Ok(true) => Ok(StatusCode::NO_CONTENT),
+    match state
+        .schema_service
+        .delete_schema(id, force, &audit_ctx)
+        .await
+    {
+        Ok(true) => {
+            state
+                .webhook_service
+                .dispatch(EVENT_SCHEMA_DELETED, json!({ "id": id }));
+            Ok(StatusCode::NO_CONTENT)
+        }
         Ok(false) => Err((
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(
-                "NOT_FOUND",
-                format!("Schema with id '{}' not found", id),
-            )),
+            Json(
+                ErrorResponse::new("NOT_FOUND", format!("Schema with id '{}' not found", id))
+                    .with_resource_type("schema"),
+            ),
         )),
         Err(e) => {
             let error_msg = e.to_string();
@@ -293,7 +1219,10 @@ pub async fn delete_schema(
             {
                 Err((
                     StatusCode::CONFLICT,
-                    Json(ErrorResponse::new("SCHEMA_HAS_LOGS", error_msg)),
+                    Json(
+                        ErrorResponse::new("SCHEMA_HAS_LOGS", error_msg)
+                            .with_resource_type("schema"),
+                    ),
                 ))
             } else {
                 Err((
@@ -0,0 +1,189 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    dto::{
+        CreateWebhookRequest, ErrorResponse, UpdateWebhookRequest, WebhookListResponse,
+        WebhookResponse,
+    },
+    AppState,
+};
+
+/// ## GET /webhooks
+/// List all webhooks.
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    tag = "webhooks",
+    responses(
+        (status = 200, description = "All webhooks", body = WebhookListResponse),
+    )
+)]
+pub async fn get_webhooks(
+    State(state): State<AppState>,
+) -> Result<Json<WebhookListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.webhook_service.get_webhooks().await {
+        Ok(webhooks) => Ok(Json(WebhookListResponse {
+            webhooks: webhooks.into_iter().map(WebhookResponse::from).collect(),
+        })),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## GET /webhooks/{id}
+/// Get one webhook.
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Webhook found", body = WebhookResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_webhook_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WebhookResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state.webhook_service.get_webhook(id).await {
+        Ok(Some(webhook)) => Ok(Json(WebhookResponse::from(webhook))),
+        Ok(None) => Err(webhook_not_found(id)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
+}
+
+/// ## POST /webhooks
+/// Register a webhook. Deliveries are signed with `X-Hub-Signature-256`.
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    tag = "webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created", body = WebhookResponse,
+            headers(("Location" = String, description = "URL of the new webhook"))),
+        (status = 400, description = "Invalid URL, events or secret", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .webhook_service
+        .create_webhook(
+            payload.url,
+            payload.events,
+            payload.secret,
+            payload.active.unwrap_or(true),
+        )
+        .await
+    {
+        Ok(webhook) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::LOCATION,
+                format!("/webhooks/{}", webhook.id).parse().unwrap(),
+            );
+
+            Ok((
+                StatusCode::CREATED,
+                headers,
+                Json(WebhookResponse::from(webhook)),
+            ))
+        }
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+        )),
+    }
+}
+
+/// ## PUT /webhooks/{id}
+/// Replace a webhook's settings.
+#[utoipa::path(
+    put,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = WebhookResponse),
+        (status = 400, description = "Invalid URL, events or secret", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+        (status = 422, description = "Request body does not match the expected shape"),
+    )
+)]
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateWebhookRequest>,
+) -> Result<Json<WebhookResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match state
+        .webhook_service
+        .update_webhook(
+            id,
+            payload.url,
+            payload.events,
+            payload.secret,
+            payload.active.unwrap_or(true),
+        )
+        .await
+    {
+        Ok(Some(webhook)) => Ok(Json(WebhookResponse::from(webhook))),
+        Ok(None) => Err(webhook_not_found(id)),
+        Err(e) => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", e.to_string())),
+        )),
+    }
+}
+
+/// ## DELETE /webhooks/{id}
+/// Delete a webhook.
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    match state.webhook_service.delete_webhook(id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(webhook_not_found(id)),
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("DELETION_FAILED", e.to_string())),
+        )),
+    }
+}
+
+fn webhook_not_found(id: Uuid) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(
+            ErrorResponse::new("NOT_FOUND", format!("Webhook with id '{}' not found", id))
+                .with_resource_type("webhook"),
+        ),
+    )
+}
@@ -1,78 +1,464 @@
 use axum::{
     extract::{
-        ws::{Message, WebSocket, WebSocketUpgrade},
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
         Query, State,
     },
-    http::StatusCode,
+    http::{HeaderValue, StatusCode},
     response::Response,
     Json,
 };
-use futures_util::{sink::SinkExt, stream::StreamExt};
+use chrono::{DateTime, Utc};
+use futures_util::{
+    sink::SinkExt,
+    stream::{SplitSink, StreamExt},
+};
 use serde::Deserialize;
+use serde_json::json;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::{timeout, Instant};
+use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::dto::ErrorResponse;
+use crate::dto::{ErrorResponse, LogEvent};
+use crate::models::Schema;
 use crate::AppState;
 
-#[derive(Debug, Deserialize)]
+/// Version of the `LogEvent` message format, sent in the upgrade response's
+/// `X-Log-Events-Version` header. Bumped on breaking changes to the messages.
+pub const WS_PROTOCOL_VERSION: u32 = 2;
+pub const WS_PROTOCOL_VERSION_HEADER: &str = "X-Log-Events-Version";
+
+/// How long `?batch_mode=true` connections collect events before sending them as one
+/// `batch` message.
+pub const WS_BATCH_WINDOW: Duration = Duration::from_millis(50);
+
+/// Holds one of the `max_ws_connections` slots until the connection ends.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    /// Returns `None` when `max` connections (`0` meaning unlimited) are already open.
+    fn acquire(connections: &Arc<AtomicUsize>, max: usize) -> Option<Self> {
+        connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |open| {
+                (max == 0 || open < max).then_some(open + 1)
+            })
+            .ok()
+            .map(|_| Self(connections.clone()))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// `503` answer for upgrades beyond `max_ws_connections`.
+fn too_many_connections(state: &AppState) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse::new(
+            "TOO_MANY_CONNECTIONS",
+            format!(
+                "WebSocket connection limit of {} reached",
+                state.config.max_ws_connections
+            ),
+        )),
+    )
+}
+
+fn with_protocol_version(mut response: Response) -> Response {
+    response.headers_mut().insert(
+        WS_PROTOCOL_VERSION_HEADER,
+        HeaderValue::from(WS_PROTOCOL_VERSION),
+    );
+    response
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct WebSocketQuery {
+    /// Only stream events for this schema
     pub schema_id: Option<Uuid>,
+    /// Only stream events for the schema with this name; cannot be combined with `schema_id`
+    pub schema_name: Option<String>,
+    /// Version of `schema_name` (default: latest)
+    pub schema_version: Option<String>,
+    /// Send events in `batch` messages, each holding the events of a 50ms window
+    pub batch_mode: Option<bool>,
+}
+
+/// Resolves the query's schema filter to an ID so the send loop only compares UUIDs.
+async fn resolve_schema_filter(
+    state: &AppState,
+    query: &WebSocketQuery,
+) -> Result<Option<Uuid>, (StatusCode, Json<ErrorResponse>)> {
+    let lookup = match (&query.schema_id, &query.schema_name, &query.schema_version) {
+        (None, None, None) => return Ok(None),
+        (Some(_), Some(_), _) | (Some(_), _, Some(_)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    "schema_id cannot be combined with schema_name or schema_version",
+                )),
+            ));
+        }
+        (None, None, Some(_)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_INPUT",
+                    "schema_version requires schema_name",
+                )),
+            ));
+        }
+        (Some(id), None, None) => state.schema_service.get_schema_by_id(*id).await,
+        (None, Some(name), Some(version)) => {
+            state
+                .schema_service
+                .get_by_name_and_version(name, version)
+                .await
+        }
+        (None, Some(name), None) => state.schema_service.get_latest_by_name(name).await,
+    };
+
+    match lookup {
+        Ok(Some(schema)) => Ok(Some(schema.id)),
+        Ok(None) => {
+            let message = match (&query.schema_id, &query.schema_name, &query.schema_version) {
+                (Some(id), _, _) => format!("Schema with id '{}' not found", id),
+                (_, Some(name), Some(version)) => format!(
+                    "Schema with name '{}' and version '{}' not found",
+                    name, version
+                ),
+                (_, name, _) => format!(
+                    "Schema with name '{}' not found",
+                    name.as_deref().unwrap_or_default()
+                ),
+            };
+            Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("SCHEMA_NOT_FOUND", message).with_resource_type("schema")),
+            ))
+        }
+        Err(e) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+        )),
+    }
 }
 
+/// ## GET /ws/logs
+/// Upgrade to a WebSocket streaming `LogEvent` messages as logs are created and deleted.
+#[utoipa::path(
+    get,
+    path = "/ws/logs",
+    tag = "websocket",
+    params(WebSocketQuery),
+    responses(
+        (status = 101, description = "Switching Protocols; each text frame is a JSON `LogEvent`, always a `batch` with `batch_mode=true`", body = LogEvent,
+            headers(("X-Log-Events-Version" = u32, description = "Version of the `LogEvent` message format"))),
+        (status = 400, description = "Conflicting schema filters", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 503, description = "Too many open WebSocket connections", body = ErrorResponse),
+    )
+)]
 pub async fn ws_handler(
     State(state): State<AppState>,
     Query(query): Query<WebSocketQuery>,
     ws: WebSocketUpgrade,
 ) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
-    if let Some(schema_id) = query.schema_id {
-        match state.schema_service.get_schema_by_id(schema_id).await {
-            Ok(None) => {
-                return Err((
-                    StatusCode::NOT_FOUND,
-                    Json(ErrorResponse::new(
+    let schema_id = resolve_schema_filter(&state, &query).await?;
+
+    match schema_id {
+        Some(schema_id) => tracing::debug!(
+            "WebSocket connection requested for schema_id: {}",
+            schema_id
+        ),
+        None => tracing::debug!("WebSocket connection requested for all schemas"),
+    }
+
+    let slot = ConnectionSlot::acquire(&state.ws_connections, state.config.max_ws_connections)
+        .ok_or_else(|| too_many_connections(&state))?;
+
+    let batch_mode = query.batch_mode.unwrap_or(false);
+
+    let response = ws.on_upgrade(move |socket| async move {
+        handle_socket(socket, state, schema_id, batch_mode).await;
+        drop(slot);
+    });
+
+    Ok(with_protocol_version(response))
+}
+
+pub const DEFAULT_REPLAY_LIMIT: i64 = 500;
+pub const MAX_REPLAY_LIMIT: i64 = 5000;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ReplayQuery {
+    /// Schema whose logs are replayed and then streamed
+    pub schema_id: Uuid,
+    /// Number of historical logs to replay (default 500, max 5000)
+    pub limit: Option<i64>,
+    /// RFC 3339; replay logs created at or after this time instead of the most recent ones
+    pub from: Option<String>,
+}
+
+/// ## GET /ws/logs/replay
+/// Upgrade to a WebSocket that first replays a schema's historical logs as `created`
+/// events, then sends `{"event_type":"replay_complete","count":N}` and continues with
+/// live events like `/ws/logs`.
+#[utoipa::path(
+    get,
+    path = "/ws/logs/replay",
+    tag = "websocket",
+    params(ReplayQuery),
+    responses(
+        (status = 101, description = "Switching Protocols; replayed and live `LogEvent` text frames", body = LogEvent,
+            headers(("X-Log-Events-Version" = u32, description = "Version of the `LogEvent` message format"))),
+        (status = 400, description = "Invalid limit or from", body = ErrorResponse),
+        (status = 404, description = "Schema not found", body = ErrorResponse),
+        (status = 503, description = "Too many open WebSocket connections", body = ErrorResponse),
+    )
+)]
+pub async fn ws_replay_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ReplayQuery>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_INPUT", message)),
+        )
+    };
+
+    let limit = query.limit.unwrap_or(DEFAULT_REPLAY_LIMIT);
+    if !(1..=MAX_REPLAY_LIMIT).contains(&limit) {
+        return Err(bad_request(format!(
+            "limit must be between 1 and {}",
+            MAX_REPLAY_LIMIT
+        )));
+    }
+
+    let from = match query.from.as_deref() {
+        Some(from) => Some(
+            DateTime::parse_from_rfc3339(from)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .map_err(|_| {
+                    bad_request(format!("from '{}' is not a valid RFC 3339 date-time", from))
+                })?,
+        ),
+        None => None,
+    };
+
+    let schema = match state.schema_service.get_schema_by_id(query.schema_id).await {
+        Ok(Some(schema)) => schema,
+        Ok(None) => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(
+                    ErrorResponse::new(
                         "SCHEMA_NOT_FOUND",
-                        format!("Schema with id '{}' not found", schema_id),
-                    )),
-                ));
-            }
-            Err(e) => {
-                return Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
-                ));
-            }
-            Ok(Some(_)) => {
-                tracing::debug!(
-                    "WebSocket connection requested for schema_id: {}",
-                    schema_id
-                );
-            }
+                        format!("Schema with id '{}' not found", query.schema_id),
+                    )
+                    .with_resource_type("schema"),
+                ),
+            ));
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("INTERNAL_ERROR", e.to_string())),
+            ));
+        }
+    };
+
+    let slot = ConnectionSlot::acquire(&state.ws_connections, state.config.max_ws_connections)
+        .ok_or_else(|| too_many_connections(&state))?;
+
+    let response = ws.on_upgrade(move |socket| async move {
+        handle_replay_socket(socket, state, schema, from, limit).await;
+        drop(slot);
+    });
+
+    Ok(with_protocol_version(response))
+}
+
+async fn handle_socket(
+    socket: WebSocket,
+    state: AppState,
+    schema_id: Option<Uuid>,
+    batch_mode: bool,
+) {
+    let rx = state.log_broadcast.subscribe();
+    forward_events(socket, state, rx, schema_id, None, batch_mode).await;
+}
+
+/// Sends the schema's historical logs before switching to live events. Live `created`
+/// events for logs that were already replayed are skipped.
+async fn handle_replay_socket(
+    socket: WebSocket,
+    state: AppState,
+    schema: Schema,
+    from: Option<DateTime<Utc>>,
+    limit: i64,
+) {
+    // Subscribe before querying so logs created during the replay are not missed
+    let rx = state.log_broadcast.subscribe();
+    let (mut sender, receiver) = socket.split();
+
+    let logs = match state
+        .log_service
+        .get_logs_for_replay(schema.id, from, limit)
+        .await
+    {
+        Ok(logs) => logs,
+        Err(e) => {
+            tracing::error!("Failed to load logs for replay: {}", e);
+            let _ = sender
+                .send(Message::Close(Some(CloseFrame {
+                    code: close_code::ERROR,
+                    reason: "Failed to load logs for replay".into(),
+                })))
+                .await;
+            return;
+        }
+    };
+
+    let count = logs.len();
+    let replayed_up_to = logs.last().map(|log| log.id);
+
+    for log in logs {
+        let event = LogEvent::created_from(log, schema.name.clone(), schema.version.clone());
+        if !send_json(&mut sender, &event).await {
+            return;
         }
-    } else {
-        tracing::debug!("WebSocket connection requested for all schemas");
     }
 
-    Ok(ws.on_upgrade(move |socket| handle_socket(socket, state, query)))
+    let complete = json!({ "event_type": "replay_complete", "count": count });
+    if !send_json(&mut sender, &complete).await {
+        return;
+    }
+
+    tracing::debug!("Replayed {} logs for schema_id: {}", count, schema.id);
+
+    let socket = match sender.reunite(receiver) {
+        Ok(socket) => socket,
+        Err(_) => return,
+    };
+    forward_events(socket, state, rx, Some(schema.id), replayed_up_to, false).await;
+}
+
+/// Returns `false` once the client is gone.
+async fn send_json(
+    sender: &mut SplitSink<WebSocket, Message>,
+    message: &impl serde::Serialize,
+) -> bool {
+    match serde_json::to_string(message) {
+        Ok(json) => sender.send(Message::Text(json.into())).await.is_ok(),
+        Err(_) => true,
+    }
+}
+
+/// Collects the wanted events received within [`WS_BATCH_WINDOW`] of `first` into a
+/// `Batch`. The flag is `true` when the channel failed during the window.
+async fn collect_batch(
+    rx: &mut broadcast::Receiver<LogEvent>,
+    first: LogEvent,
+    wanted: impl Fn(&LogEvent) -> bool,
+) -> (LogEvent, bool) {
+    let deadline = Instant::now() + WS_BATCH_WINDOW;
+    let mut events = vec![first];
+
+    let failed = loop {
+        match timeout(
+            deadline.saturating_duration_since(Instant::now()),
+            rx.recv(),
+        )
+        .await
+        {
+            Ok(Ok(event)) if wanted(&event) => events.push(event),
+            Ok(Ok(_)) => {}
+            Ok(Err(_)) => break true,
+            Err(_) => break false,
+        }
+    };
+
+    let batch = LogEvent::Batch {
+        events,
+        batch_at: Utc::now().to_rfc3339(),
+    };
+    (batch, failed)
 }
 
-async fn handle_socket(socket: WebSocket, state: AppState, query: WebSocketQuery) {
+/// Streams broadcast events to the client until either side closes or the server shuts
+/// down. `Created` events with an ID up to `skip_created_up_to` are not sent. With
+/// `batch_mode`, events are sent in `Batch` messages instead of one message each.
+async fn forward_events(
+    socket: WebSocket,
+    state: AppState,
+    mut rx: broadcast::Receiver<LogEvent>,
+    schema_id: Option<Uuid>,
+    skip_created_up_to: Option<i32>,
+    batch_mode: bool,
+) {
     let (mut sender, mut receiver) = socket.split();
-    let mut rx = state.log_broadcast.subscribe();
+    let shutdown = state.shutdown.clone();
+
+    let wanted = move |log_event: &LogEvent| {
+        let matches_schema = match schema_id {
+            Some(schema_id) => log_event.schema_id() == Some(schema_id),
+            None => true,
+        };
+        let already_replayed = match (log_event, skip_created_up_to) {
+            (LogEvent::Created { id, .. }, Some(last_id)) => *id <= last_id,
+            _ => false,
+        };
+        matches_schema && !already_replayed
+    };
 
     let mut send_task = tokio::spawn(async move {
-        while let Ok(log_event) = rx.recv().await {
-            let should_send = match &query.schema_id {
-                Some(schema_id) => log_event.schema_id() == *schema_id,
-                None => true,
+        loop {
+            let log_event = tokio::select! {
+                event = rx.recv() => match event {
+                    Ok(log_event) => log_event,
+                    Err(_) => break,
+                },
+                _ = shutdown.cancelled() => {
+                    let _ = sender
+                        .send(Message::Close(Some(CloseFrame {
+                            code: close_code::AWAY,
+                            reason: "Server shutting down".into(),
+                        })))
+                        .await;
+                    break;
+                }
+            };
+
+            if !wanted(&log_event) {
+                continue;
+            }
+
+            let (log_event, failed) = if batch_mode {
+                collect_batch(&mut rx, log_event, wanted).await
+            } else {
+                (log_event, false)
             };
 
-            if should_send {
-                if let Ok(json) = serde_json::to_string(&log_event) {
-                    if sender.send(Message::Text(json.into())).await.is_err() {
-                        break;
-                    }
+            if let Ok(json) = serde_json::to_string(&log_event) {
+                if sender.send(Message::Text(json.into())).await.is_err() {
+                    break;
                 }
             }
+            if failed {
+                break;
+            }
         }
     });
 
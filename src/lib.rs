@@ -1,91 +1,455 @@
 use axum::{
+    error_handling::HandleErrorLayer,
+    extract::{DefaultBodyLimit, State},
     http::StatusCode,
     middleware as axum_middleware,
     response::Json,
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
     Router,
 };
+use chrono::{DateTime, Utc};
+use metrics_exporter_prometheus::PrometheusHandle;
 use serde_json::json;
-use std::sync::Arc;
-use tokio::sync::broadcast;
-use tower::ServiceBuilder;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
+use std::sync::{atomic::AtomicUsize, Arc};
+use tokio::sync::{broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
+use tower::{timeout::TimeoutLayer, ServiceBuilder};
+use tower_http::{
+    cors::{AllowOrigin, Any, CorsLayer},
+    limit::RequestBodyLimitLayer,
+};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
-pub use middleware::request_id::{RequestIdLayer, RequestIdMakeSpan};
+pub use middleware::request_id::{RequestIdLayer, RequestIdMakeSpan, RequestIdOnResponse};
+use middleware::{
+    auth, body_limit, compression, problem_details, rate_limit, request_id, schema_audit, timeout,
+};
 
+pub mod client;
+pub mod config;
 pub mod dto;
 pub mod error;
 pub mod handlers;
 pub mod middleware;
 pub mod models;
+pub mod openapi;
 pub mod repositories;
 pub mod services;
+pub mod telemetry;
+pub mod utils;
 
+pub use client::LogServerClient;
+pub use config::AppConfig;
 pub use dto::{ErrorResponse, LogEvent, SchemaResponse};
 pub use error::{AppError, AppResult};
 pub use handlers::{
-    create_log, create_schema, delete_log, delete_schema, get_log_by_id, get_logs,
-    get_logs_default, get_schema_by_id, get_schema_by_name_and_version, get_schemas, update_schema,
-    ws_handler,
+    create_ingest_webhook, create_log, create_logs_batch, create_schema, create_webhook,
+    delete_ingest_webhook, delete_log, delete_logs_before, delete_logs_by_filter, delete_schema,
+    delete_schema_logs, delete_webhook, deprecate_schema, export_schema, get_admin_stats,
+    get_audit_log, get_ingest_webhook_by_id, get_ingest_webhooks, get_log_attachments,
+    get_log_by_id, get_logs, get_logs_across_versions, get_logs_by_correlation_id,
+    get_logs_default, get_logs_grouped_by_time, get_metrics, get_schema_by_id,
+    get_schema_by_name_and_version, get_schema_fields, get_schema_versions, get_schemas,
+    get_webhook_by_id, get_webhooks, handle_404, handle_405, import_openapi_schemas,
+    ingest_webhook_log, patch_schema_definition, patch_schema_tags, run_migrations, search_schemas,
+    undeprecate_schema, update_ingest_webhook, update_schema, update_webhook,
+    validate_schema_example, validate_schemas, ws_handler, ws_replay_handler,
+};
+pub use models::{
+    ApiKey, AttachmentRef, AuditContext, AuditEvent, AuditLog, IngestWebhook, Log, Schema,
+    ValidationMode, Webhook,
+};
+pub use openapi::ApiDoc;
+pub use repositories::{
+    ApiKeyRepository, AuditRepository, IngestWebhookRepository, LogRepository, SchemaRepository,
+    WebhookRepository,
+};
+pub use services::{
+    AuditService, IngestWebhookService, LogService, MigrationService, SchemaCache, SchemaService,
+    WebhookService,
 };
-pub use models::{Log, Schema};
-pub use repositories::{LogRepository, SchemaRepository};
-pub use services::{LogService, SchemaService};
 
 #[derive(Clone)]
 pub struct AppState {
+    pub config: Arc<AppConfig>,
     pub schema_service: Arc<SchemaService>,
     pub log_service: Arc<LogService>,
+    pub audit_service: Arc<AuditService>,
+    pub webhook_service: Arc<WebhookService>,
+    pub ingest_webhook_service: Arc<IngestWebhookService>,
+    pub migration_service: Arc<MigrationService>,
     pub log_broadcast: broadcast::Sender<LogEvent>,
+    pub api_key_repository: Arc<ApiKeyRepository>,
+    pub api_key_cache: Arc<auth::ApiKeyCache>,
+    pub rate_limiters: Arc<rate_limit::RateLimiters>,
+    /// Cancelled when the server starts shutting down, so long-lived WebSocket
+    /// connections can close instead of holding up the drain.
+    pub shutdown: CancellationToken,
+    /// Currently open WebSocket connections, bounded by `max_ws_connections`
+    pub ws_connections: Arc<AtomicUsize>,
+    /// Renders the global metrics recorder for `GET /metrics`
+    pub metrics: PrometheusHandle,
+    /// When the state was built, for the uptime reported by `/health`
+    pub started_at: DateTime<Utc>,
+    /// Successful schema mutations, written to `schema_audit_log` in the background
+    pub schema_audit_events: mpsc::Sender<AuditEvent>,
 }
 
 impl AppState {
-    pub fn new(
-        schema_service: Arc<SchemaService>,
-        log_service: Arc<LogService>,
-        log_broadcast: broadcast::Sender<LogEvent>,
+    pub fn builder() -> AppStateBuilder {
+        AppStateBuilder::new()
+    }
+}
+
+/// Assembles an [`AppState`]; every service must be set before [`build`](Self::build).
+#[derive(Default)]
+pub struct AppStateBuilder {
+    config: Option<Arc<AppConfig>>,
+    schema_service: Option<Arc<SchemaService>>,
+    log_service: Option<Arc<LogService>>,
+    audit_service: Option<Arc<AuditService>>,
+    webhook_service: Option<Arc<WebhookService>>,
+    ingest_webhook_service: Option<Arc<IngestWebhookService>>,
+    migration_service: Option<Arc<MigrationService>>,
+    log_broadcast: Option<broadcast::Sender<LogEvent>>,
+    api_key_repository: Option<Arc<ApiKeyRepository>>,
+    metrics: Option<PrometheusHandle>,
+    schema_audit_events: Option<mpsc::Sender<AuditEvent>>,
+}
+
+impl AppStateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn config(mut self, config: Arc<AppConfig>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn schema_service(mut self, schema_service: Arc<SchemaService>) -> Self {
+        self.schema_service = Some(schema_service);
+        self
+    }
+
+    pub fn log_service(mut self, log_service: Arc<LogService>) -> Self {
+        self.log_service = Some(log_service);
+        self
+    }
+
+    pub fn audit_service(mut self, audit_service: Arc<AuditService>) -> Self {
+        self.audit_service = Some(audit_service);
+        self
+    }
+
+    pub fn webhook_service(mut self, webhook_service: Arc<WebhookService>) -> Self {
+        self.webhook_service = Some(webhook_service);
+        self
+    }
+
+    pub fn ingest_webhook_service(
+        mut self,
+        ingest_webhook_service: Arc<IngestWebhookService>,
     ) -> Self {
-        Self {
-            schema_service,
-            log_service,
-            log_broadcast,
+        self.ingest_webhook_service = Some(ingest_webhook_service);
+        self
+    }
+
+    pub fn migration_service(mut self, migration_service: Arc<MigrationService>) -> Self {
+        self.migration_service = Some(migration_service);
+        self
+    }
+
+    pub fn log_broadcast(mut self, log_broadcast: broadcast::Sender<LogEvent>) -> Self {
+        self.log_broadcast = Some(log_broadcast);
+        self
+    }
+
+    pub fn api_key_repository(mut self, api_key_repository: Arc<ApiKeyRepository>) -> Self {
+        self.api_key_repository = Some(api_key_repository);
+        self
+    }
+
+    pub fn metrics(mut self, metrics: PrometheusHandle) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sender half of the channel drained by [`AuditService::write_schema_events`].
+    pub fn schema_audit_events(mut self, schema_audit_events: mpsc::Sender<AuditEvent>) -> Self {
+        self.schema_audit_events = Some(schema_audit_events);
+        self
+    }
+
+    /// Fails naming the first field that was never set.
+    pub fn build(self) -> anyhow::Result<AppState> {
+        fn required<T>(value: Option<T>, name: &str) -> anyhow::Result<T> {
+            value.ok_or_else(|| anyhow::anyhow!("AppState is missing `{}`", name))
         }
+
+        let config = required(self.config, "config")?;
+
+        Ok(AppState {
+            rate_limiters: Arc::new(rate_limit::RateLimiters::from_config(&config)),
+            config,
+            schema_service: required(self.schema_service, "schema_service")?,
+            log_service: required(self.log_service, "log_service")?,
+            audit_service: required(self.audit_service, "audit_service")?,
+            webhook_service: required(self.webhook_service, "webhook_service")?,
+            ingest_webhook_service: required(
+                self.ingest_webhook_service,
+                "ingest_webhook_service",
+            )?,
+            migration_service: required(self.migration_service, "migration_service")?,
+            log_broadcast: required(self.log_broadcast, "log_broadcast")?,
+            api_key_repository: required(self.api_key_repository, "api_key_repository")?,
+            api_key_cache: Arc::new(auth::ApiKeyCache::new()),
+            shutdown: CancellationToken::new(),
+            ws_connections: Arc::new(AtomicUsize::new(0)),
+            metrics: required(self.metrics, "metrics")?,
+            started_at: Utc::now(),
+            schema_audit_events: required(self.schema_audit_events, "schema_audit_events")?,
+        })
     }
 }
 
-async fn health_check() -> Result<Json<serde_json::Value>, StatusCode> {
+/// Release of the running binary.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Build date as `YYYY-MM-DD`, set by `build.rs` (overridable with `BUILD_DATE`).
+pub const BUILD_DATE: &str = env!("BUILD_DATE");
+/// Short commit hash, set by `build.rs` from git or `GIT_COMMIT`; `unknown` otherwise.
+pub const GIT_COMMIT: &str = env!("GIT_COMMIT");
+
+/// ## GET /health
+/// Report that the service is up, and which build is running.
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses((status = 200, description = "Service is healthy; includes `version`, `build_date`, `git_commit` and `uptime_seconds`"))
+)]
+async fn health_check(
+    State(state): State<AppState>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     tracing::info!("Health check endpoint called");
+    let uptime_seconds = (Utc::now() - state.started_at).num_milliseconds() as f64 / 1000.0;
     Ok(Json(json!({
         "status": "healthy",
         "service": "log-server",
-        "timestamp": chrono::Utc::now().to_rfc3339()
+        "version": VERSION,
+        "build_date": BUILD_DATE,
+        "git_commit": GIT_COMMIT,
+        "uptime_seconds": uptime_seconds,
+        "timestamp": Utc::now().to_rfc3339()
     })))
 }
 
-pub fn create_app(app_state: AppState) -> Router {
-    Router::new()
-        .route("/", get(health_check))
-        .route("/health", get(health_check))
+/// Any origin is allowed unless `CORS_ALLOWED_ORIGINS` lists specific ones.
+fn cors_layer(config: &AppConfig) -> CorsLayer {
+    if config.cors_allowed_origins.is_empty() {
+        return CorsLayer::permissive();
+    }
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(config.cors_allowed_origins.clone()))
+        .allow_methods(Any)
+        .allow_headers(Any)
+        .expose_headers(Any)
+}
+
+pub fn create_app(app_state: AppState, config: &AppConfig) -> Router {
+    let schema_write_routes = Router::new()
+        .route("/schemas", post(create_schema))
+        .route("/schemas/import-openapi", post(import_openapi_schemas))
+        .route("/schemas/validate", post(validate_schemas))
+        .route("/schemas/{id}", put(update_schema))
+        .route(
+            "/schemas/{id}/schema_definition",
+            patch(patch_schema_definition),
+        )
+        .layer(RequestBodyLimitLayer::new(
+            config.schema_max_request_body_bytes(),
+        ))
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(timeout::handle_timeout_error))
+                .layer(TimeoutLayer::new(config.request_timeout())),
+        );
+
+    // Log listings are unpaginated and may legitimately take longer
+    let log_listing_routes = Router::new()
+        .route("/logs/schema/{schema_name}", get(get_logs_default))
+        .route("/logs/schema/{schema_name}/{schema_version}", get(get_logs))
+        .route(
+            "/logs/schema/{schema_name}/{schema_version}/grouped-by-time",
+            get(get_logs_grouped_by_time),
+        )
+        .route(
+            "/logs/schema/{schema_name}/across-versions",
+            get(get_logs_across_versions),
+        )
+        .route(
+            "/logs/correlation/{correlation_id}",
+            get(get_logs_by_correlation_id),
+        )
+        .layer(RequestBodyLimitLayer::new(config.max_request_body_bytes))
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(timeout::handle_timeout_error))
+                .layer(TimeoutLayer::new(config.long_request_timeout())),
+        );
+
+    let mut admin_routes = Router::new()
+        .route("/audit", get(get_audit_log))
+        .route("/admin/migrate", post(run_migrations))
+        .route("/admin/stats", get(get_admin_stats))
+        .route(
+            "/logs/schema/{schema_name}/before",
+            delete(delete_logs_before),
+        )
+        .route(
+            "/logs/schema/{schema_name}/bulk",
+            delete(delete_logs_by_filter),
+        )
+        .route("/schemas/{id}/logs", delete(delete_schema_logs))
+        .route("/webhooks", get(get_webhooks).post(create_webhook))
+        .route(
+            "/webhooks/{id}",
+            get(get_webhook_by_id)
+                .put(update_webhook)
+                .delete(delete_webhook),
+        )
+        .route(
+            "/admin/webhooks",
+            get(get_ingest_webhooks).post(create_ingest_webhook),
+        )
+        .route(
+            "/admin/webhooks/{id}",
+            get(get_ingest_webhook_by_id)
+                .put(update_ingest_webhook)
+                .delete(delete_ingest_webhook),
+        );
+    if config.require_api_key {
+        admin_routes = admin_routes.route_layer(axum_middleware::from_fn(auth::require_admin));
+    }
+
+    let log_ingest_routes = Router::new().route("/logs", post(create_log)).route_layer(
+        axum_middleware::from_fn_with_state(app_state.clone(), rate_limit::limit_log_ingest),
+    );
+
+    let log_batch_routes = Router::new()
+        .route("/logs/batch", post(create_logs_batch))
+        .route_layer(axum_middleware::from_fn_with_state(
+            app_state.clone(),
+            rate_limit::limit_log_ingest,
+        ))
+        .layer(RequestBodyLimitLayer::new(
+            config.log_batch_max_request_body_bytes(),
+        ))
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(timeout::handle_timeout_error))
+                .layer(TimeoutLayer::new(config.request_timeout())),
+        );
+
+    let api_routes = Router::new()
         .route("/ws/logs", get(ws_handler))
+        .route("/ws/logs/replay", get(ws_replay_handler))
         .route("/schemas", get(get_schemas))
-        .route("/schemas", post(create_schema))
+        .route("/schemas/search", get(search_schemas))
         .route("/schemas/{id}", get(get_schema_by_id))
-        .route("/schemas/{id}", put(update_schema))
         .route("/schemas/{id}", delete(delete_schema))
+        .route("/schemas/{id}/fields", get(get_schema_fields))
+        .route("/schemas/{schema_name}/versions", get(get_schema_versions))
+        .route("/schemas/{id}/export.json", get(export_schema))
+        .route(
+            "/schemas/{id}/validate-example",
+            post(validate_schema_example),
+        )
+        .route("/schemas/{id}/deprecate", post(deprecate_schema))
+        .route("/schemas/{id}/undeprecate", post(undeprecate_schema))
+        .route("/schemas/{id}/tags", patch(patch_schema_tags))
         .route(
             "/schemas/{schema_name}/{schema_version}",
             get(get_schema_by_name_and_version),
         )
-        .route("/logs", post(create_log))
-        .route("/logs/schema/{schema_name}", get(get_logs_default))
-        .route("/logs/schema/{schema_name}/{schema_version}", get(get_logs))
         .route("/logs/{id}", get(get_log_by_id))
+        .route("/logs/{id}/attachments", get(get_log_attachments))
         .route("/logs/{id}", delete(delete_log))
-        .with_state(app_state)
+        .merge(log_ingest_routes)
+        .merge(admin_routes)
+        .layer(RequestBodyLimitLayer::new(config.max_request_body_bytes))
         .layer(
             ServiceBuilder::new()
-                .layer(axum_middleware::from_fn(RequestIdLayer::middleware))
-                .layer(TraceLayer::new_for_http().make_span_with(RequestIdMakeSpan))
-                .layer(CorsLayer::permissive()),
+                .layer(HandleErrorLayer::new(timeout::handle_timeout_error))
+                .layer(TimeoutLayer::new(config.request_timeout())),
         )
+        .merge(schema_write_routes)
+        .merge(log_batch_routes)
+        .merge(log_listing_routes);
+
+    // Inside authentication, so events carry the API key
+    let api_routes = api_routes.route_layer(axum_middleware::from_fn_with_state(
+        app_state.clone(),
+        schema_audit::audit_schema_mutations,
+    ));
+
+    let api_routes = if config.require_api_key {
+        api_routes.route_layer(axum_middleware::from_fn_with_state(
+            app_state.clone(),
+            auth::require_api_key,
+        ))
+    } else {
+        api_routes
+    };
+
+    // Rate limiting runs before authentication so floods never reach the key lookup
+    let api_routes = api_routes.route_layer(axum_middleware::from_fn_with_state(
+        app_state.clone(),
+        rate_limit::limit_requests,
+    ));
+
+    // The token in the path is the credential, so these skip API key authentication
+    let ingest_webhook_routes = Router::new()
+        .route("/webhooks/ingest/{token}", post(ingest_webhook_log))
+        .route_layer(axum_middleware::from_fn_with_state(
+            app_state.clone(),
+            rate_limit::limit_ingest_webhook,
+        ))
+        .layer(RequestBodyLimitLayer::new(config.max_request_body_bytes))
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(timeout::handle_timeout_error))
+                .layer(TimeoutLayer::new(config.request_timeout())),
+        );
+
+    // Health checks, metrics and API docs stay reachable without an API key
+    let app = Router::new()
+        .route("/", get(health_check))
+        .route("/health", get(health_check))
+        .route("/metrics", get(get_metrics))
+        .merge(SwaggerUi::new("/swagger-ui").url("/openapi.json", ApiDoc::openapi()))
+        .merge(ingest_webhook_routes)
+        .merge(api_routes)
+        .fallback(handle_404)
+        // Must follow every route so each one gets the JSON 405
+        .method_not_allowed_fallback(handle_405)
+        .with_state(app_state)
+        .layer(axum_middleware::map_response(
+            body_limit::json_payload_too_large,
+        ))
+        // Inside compression, which cannot be undone to rewrite the body
+        .layer(axum_middleware::from_fn(
+            problem_details::to_problem_details,
+        ))
+        .layer(
+            ServiceBuilder::new()
+                .layer(compression::layer(!config.disable_compression))
+                .layer(cors_layer(config))
+                // Limits are enforced by RequestBodyLimitLayer; axum's 2MB default would cap schema writes
+                .layer(DefaultBodyLimit::disable()),
+        );
+
+    request_id::trace_requests(app)
 }
@@ -1,69 +1,279 @@
 use log_server::{
-    create_app, AppState, LogRepository, LogService, SchemaRepository, SchemaService,
+    config::LogFormat,
+    create_app,
+    middleware::{auth, rate_limit, schema_audit},
+    openapi,
+    repositories::slow_query,
+    telemetry, ApiDoc, ApiKeyRepository, AppConfig, AppState, AuditRepository, AuditService,
+    IngestWebhookRepository, IngestWebhookService, LogRepository, LogService, MigrationService,
+    SchemaCache, SchemaRepository, SchemaService, WebhookRepository, WebhookService,
 };
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use scopeguard::defer;
 use std::net::SocketAddr;
-use std::{env, sync::Arc};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
-use tokio::sync::broadcast;
+use tokio::signal;
+use tokio::sync::{broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
+use tracing_subscriber::{fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt};
+use utoipa::OpenApi;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    use tracing_subscriber::fmt::format::FmtSpan;
-    tracing_subscriber::fmt()
-        .with_env_filter(
+/// How long in-flight requests may take to finish once shutdown has started.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Resolves on Ctrl-C or SIGTERM, after cancelling `shutdown` so WebSocket
+/// subscribers are sent a close frame before the server stops accepting connections.
+async fn shutdown_signal(shutdown: CancellationToken) {
+    let ctrl_c = async {
+        if let Err(e) = signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutting down gracefully");
+    shutdown.cancel();
+}
+
+/// Installs the global subscriber. `LOG_FORMAT=json` emits one JSON object per line
+/// (with the enclosing span's fields, such as `request_id`) for log aggregation
+/// pipelines; anything else keeps the human-readable output.
+fn configure_logging(log_format: LogFormat, tracer_provider: Option<&SdkTracerProvider>) {
+    let json = log_format == LogFormat::Json;
+
+    let json_layer = json.then(|| {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_target(true)
+            .with_span_events(FmtSpan::CLOSE)
+    });
+
+    let text_layer = (!json).then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_target(true)
+            .with_thread_ids(false)
+            .with_level(true)
+            .with_span_events(FmtSpan::CLOSE)
+    });
+
+    let otel_layer = tracer_provider.map(|provider| {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer(telemetry::SERVICE_NAME))
+    });
+
+    tracing_subscriber::registry()
+        .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| "tower_http=debug,log_server=debug,info".into()),
         )
-        .with_target(true)
-        .with_thread_ids(false)
-        .with_level(true)
-        .with_span_events(FmtSpan::CLOSE)
+        .with(json_layer)
+        .with(text_layer)
+        .with(otel_layer)
         .init();
+}
+
+/// Lists every documented route, so the banner can't drift from the router.
+fn log_routes(openapi: &utoipa::openapi::OpenApi) {
+    for (method, path, summary) in openapi::routes(openapi) {
+        tracing::info!("   {:<6} {:<50} {}", method, path, summary);
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = Arc::new(AppConfig::from_env()?);
+
+    let tracer_provider =
+        telemetry::init_tracer_provider(config.otel_exporter_otlp_endpoint.as_deref())?;
+    configure_logging(config.log_format, tracer_provider.as_ref());
 
-    let database_url =
-        env::var("DATABASE_URL").expect("DATABASE_URL environment variable is not set");
+    // Flush buffered spans however main exits
+    defer! {
+        if let Some(provider) = &tracer_provider {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to shut down tracer provider: {}", e);
+            }
+        }
+    }
 
-    let pool = sqlx::postgres::PgPool::connect(&database_url).await?;
+    if let Some(endpoint) = &config.otel_exporter_otlp_endpoint {
+        tracing::info!("📡 Exporting traces via OTLP to {}", endpoint);
+    }
+
+    slow_query::set_threshold(config.slow_query_threshold());
+
+    let pool = sqlx::postgres::PgPool::connect(&config.database_url).await?;
     tracing::info!("✅ Database connected successfully!");
 
+    let migration_service = Arc::new(MigrationService::new(pool.clone()));
+    for migration in migration_service.run_pending().await? {
+        tracing::info!(
+            "🗃️  Applied migration {} ({})",
+            migration.version,
+            migration.description
+        );
+    }
+
     let schema_repository = Arc::new(SchemaRepository::new(pool.clone()));
     let log_repository = Arc::new(LogRepository::new(pool.clone()));
+    let api_key_repository = Arc::new(ApiKeyRepository::new(pool.clone()));
+    let audit_repository = Arc::new(AuditRepository::new(pool.clone()));
+    let webhook_repository = Arc::new(WebhookRepository::new(pool.clone()));
+    let ingest_webhook_repository = Arc::new(IngestWebhookRepository::new(pool.clone()));
 
-    let schema_service = Arc::new(SchemaService::new(
+    let audit_service = Arc::new(AuditService::new(audit_repository));
+    let webhook_service = Arc::new(WebhookService::new(webhook_repository));
+    let ingest_webhook_service = Arc::new(IngestWebhookService::new(
+        ingest_webhook_repository,
         schema_repository.clone(),
+    ));
+
+    let (log_broadcast_tx, _) = broadcast::channel(config.broadcast_capacity);
+
+    let (schema_audit_tx, schema_audit_rx) =
+        mpsc::channel(schema_audit::SCHEMA_AUDIT_CHANNEL_CAPACITY);
+    tokio::spawn(audit_service.clone().write_schema_events(schema_audit_rx));
+
+    let schema_cache = Arc::new(SchemaCache::new(config.schema_cache_size));
+
+    let log_service = Arc::new(LogService::new(
         log_repository.clone(),
+        schema_repository.clone(),
+        schema_cache.clone(),
+        audit_service.clone(),
+        log_broadcast_tx.clone(),
+        config.enrich_logs,
+        config.server_version.clone(),
+        config.max_log_data_bytes,
+    ));
+    let schema_service = Arc::new(SchemaService::new(
+        schema_repository,
+        log_repository.clone(),
+        log_service.clone(),
+        audit_service.clone(),
+        schema_cache,
+        config.version_format,
     ));
-    let log_service = Arc::new(LogService::new(log_repository.clone(), schema_repository));
 
-    let (log_broadcast_tx, _) = broadcast::channel(100);
+    let app_state = AppState::builder()
+        .config(config.clone())
+        .schema_service(schema_service)
+        .log_service(log_service)
+        .audit_service(audit_service)
+        .webhook_service(webhook_service)
+        .ingest_webhook_service(ingest_webhook_service)
+        .migration_service(migration_service)
+        .log_broadcast(log_broadcast_tx)
+        .api_key_repository(api_key_repository)
+        .metrics(telemetry::init_metrics_recorder()?)
+        .schema_audit_events(schema_audit_tx)
+        .build()?;
 
-    let app_state = AppState {
-        schema_service,
-        log_service,
-        log_broadcast: log_broadcast_tx,
-    };
+    rate_limit::spawn_pruning(app_state.rate_limiters.clone());
+    let shutdown = app_state.shutdown.clone();
 
-    let app = create_app(app_state);
+    let app = create_app(app_state, &config);
 
     tracing::info!("📊 Available endpoints:");
-    tracing::info!("   GET    /                     - Health check");
-    tracing::info!("   GET    /health               - Health check");
-    tracing::info!("   GET    /ws/logs              - WebSocket for live log updates");
-    tracing::info!("   GET    /schemas              - Get all schemas");
-    tracing::info!("   POST   /schemas              - Create new schema");
-    tracing::info!("   GET    /schemas/:id          - Get schema by ID");
-    tracing::info!("   PUT    /schemas/:id          - Update schema");
-    tracing::info!("   DELETE /schemas/:id          - Delete schema");
-    tracing::info!("   POST   /logs                      - Create new log entry");
-    tracing::info!("   GET    /logs/schema/:schema_id - Get logs by schema ID");
-    tracing::info!("   GET    /logs/:id               - Get log by ID");
-    tracing::info!("   DELETE /logs/:id               - Delete log");
-
-    let addr: SocketAddr = "0.0.0.0:8080".parse()?;
+    log_routes(&ApiDoc::openapi());
+
+    if config.enrich_logs {
+        tracing::info!("🧩 Log enrichment enabled (_ingested_at, _schema_id)");
+    }
+
+    if config.require_api_key {
+        tracing::info!(
+            "🔐 API key authentication enabled ({} header)",
+            auth::API_KEY_HEADER
+        );
+    } else {
+        tracing::warn!(
+            "🔓 API key authentication disabled; set {}=true to enable it",
+            auth::REQUIRE_API_KEY_ENV
+        );
+    }
+
+    tracing::info!("🚦 Rate limits per IP:");
+    tracing::info!(
+        "   default                {}/min (burst {})",
+        config.rate_limit_per_minute,
+        config.rate_limit_burst
+    );
+    tracing::info!(
+        "   POST /logs[/batch]     {}/min",
+        config.log_ingest_rate_limit_per_minute
+    );
+
+    tracing::info!("📦 Request body limits:");
+    tracing::info!(
+        "   default                {} bytes",
+        config.max_request_body_bytes
+    );
+    tracing::info!(
+        "   POST/PUT /schemas      {} bytes",
+        config.schema_max_request_body_bytes()
+    );
+    tracing::info!(
+        "   POST /logs/batch       {} bytes",
+        config.log_batch_max_request_body_bytes()
+    );
+
+    tracing::info!("⏱️  Request timeouts:");
+    tracing::info!("   default                {:?}", config.request_timeout());
+    tracing::info!(
+        "   GET /logs/schema/...   {:?}",
+        config.long_request_timeout()
+    );
+
+    let addr = config.socket_addr();
     tracing::info!("🚀 Log Server running at http://{}", addr);
 
     let listener = TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    // Peer addresses are recorded in the audit log
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal(shutdown.clone()));
+
+    tokio::select! {
+        result = server => result?,
+        _ = async {
+            shutdown.cancelled().await;
+            tokio::time::sleep(SHUTDOWN_TIMEOUT).await;
+        } => {
+            tracing::warn!(
+                "In-flight requests did not finish within {:?}; shutting down anyway",
+                SHUTDOWN_TIMEOUT
+            );
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,138 @@
+use axum::{
+    extract::{Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dashmap::DashMap;
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant};
+
+use crate::{
+    dto::ErrorResponse,
+    error::{AppError, AppResult},
+    models::{
+        api_key_model::{PERMISSION_ADMIN, PERMISSION_READ, PERMISSION_WRITE},
+        ApiKey,
+    },
+    repositories::api_key_repository::ApiKeyRepositoryTrait,
+    AppState,
+};
+
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+pub const REQUIRE_API_KEY_ENV: &str = "REQUIRE_API_KEY";
+
+/// How long a validated key is trusted before it is looked up again.
+pub const API_KEY_CACHE_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct CachedApiKey {
+    pub api_key: ApiKey,
+    pub cached_at: Instant,
+}
+
+/// Validated keys indexed by their SHA-256 hash.
+pub type ApiKeyCache = DashMap<String, CachedApiKey>;
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Safe methods only need `read`; everything else needs `write`.
+fn required_permission(method: &Method) -> &'static str {
+    match *method {
+        Method::GET | Method::HEAD | Method::OPTIONS => PERMISSION_READ,
+        _ => PERMISSION_WRITE,
+    }
+}
+
+/// Rejects requests without an active `X-API-Key` (401) or lacking the permission
+/// required by the HTTP method (403). The matched [`ApiKey`] is stored in the request
+/// extensions for downstream handlers.
+pub async fn require_api_key(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(raw_key) = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return AppError::unauthorized(format!("Missing {} header", API_KEY_HEADER))
+            .into_response();
+    };
+
+    let api_key = match lookup_api_key(&state, &hash_api_key(raw_key)).await {
+        Ok(Some(api_key)) => api_key,
+        Ok(None) => return AppError::unauthorized("Invalid API key").into_response(),
+        Err(e) => {
+            tracing::error!("API key lookup failed: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(
+                    "INTERNAL_ERROR",
+                    "An internal error occurred",
+                )),
+            )
+                .into_response();
+        }
+    };
+
+    let permission = required_permission(request.method());
+    if !api_key.has_permission(permission) {
+        return AppError::Forbidden(format!("API key lacks the '{}' permission", permission))
+            .into_response();
+    }
+
+    request.extensions_mut().insert(api_key);
+    next.run(request).await
+}
+
+async fn lookup_api_key(state: &AppState, key_hash: &str) -> AppResult<Option<ApiKey>> {
+    if let Some(cached) = state.api_key_cache.get(key_hash) {
+        if cached.cached_at.elapsed() < API_KEY_CACHE_TTL {
+            return Ok(Some(cached.api_key.clone()));
+        }
+    }
+    state.api_key_cache.remove(key_hash);
+
+    let api_key = state
+        .api_key_repository
+        .get_active_by_hash(key_hash)
+        .await?;
+
+    // Only valid keys are cached so random keys cannot grow the map
+    if let Some(api_key) = &api_key {
+        state.api_key_cache.insert(
+            key_hash.to_string(),
+            CachedApiKey {
+                api_key: api_key.clone(),
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    Ok(api_key)
+}
+
+/// Restricts a route to API keys holding the `admin` permission. Must run after
+/// `require_api_key`, which places the authenticated key in the request extensions.
+pub async fn require_admin(request: Request, next: Next) -> Response {
+    let is_admin = request
+        .extensions()
+        .get::<ApiKey>()
+        .is_some_and(|api_key| api_key.has_permission(PERMISSION_ADMIN));
+
+    if !is_admin {
+        return AppError::Forbidden(format!(
+            "API key lacks the '{}' permission",
+            PERMISSION_ADMIN
+        ))
+        .into_response();
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,36 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::dto::ErrorResponse;
+use crate::middleware::problem_details::is_json;
+
+pub const MAX_REQUEST_BODY_BYTES_ENV: &str = "MAX_REQUEST_BODY_BYTES";
+
+/// Limit applied to every route unless overridden (1MB).
+pub const DEFAULT_MAX_REQUEST_BODY_BYTES: usize = 1024 * 1024;
+
+/// Limit for schema writes, which may carry large `examples` (4MB).
+pub const SCHEMA_MAX_REQUEST_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+/// Limit for batch log ingestion (10MB).
+pub const LOG_BATCH_MAX_REQUEST_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Replaces the plain-text `413 Payload Too Large` sent by `RequestBodyLimitLayer` and
+/// axum's body extractors with a JSON [`ErrorResponse`].
+pub async fn json_payload_too_large(response: Response) -> Response {
+    if response.status() != StatusCode::PAYLOAD_TOO_LARGE || is_json(response.headers()) {
+        return response;
+    }
+
+    (
+        StatusCode::PAYLOAD_TOO_LARGE,
+        Json(ErrorResponse::new(
+            "PAYLOAD_TOO_LARGE",
+            "Request body exceeds the size limit of this endpoint",
+        )),
+    )
+        .into_response()
+}
@@ -0,0 +1,46 @@
+use axum::http::{header, Extensions, HeaderMap, StatusCode, Version};
+use tower_http::compression::{
+    predicate::{Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+pub const DISABLE_COMPRESSION_ENV: &str = "DISABLE_COMPRESSION";
+
+/// Responses smaller than this are sent as-is; same threshold as tower-http's default.
+const MIN_COMPRESSIBLE_SIZE: u16 = 32;
+
+/// Response content types eligible for compression.
+pub const COMPRESSIBLE_CONTENT_TYPES: &[&str] = &[
+    "application/json",
+    "application/problem+json",
+    "application/x-ndjson",
+    "text/csv",
+];
+
+fn is_compressible_content_type(
+    _status: StatusCode,
+    _version: Version,
+    headers: &HeaderMap,
+    _extensions: &Extensions,
+) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|content_type| {
+            COMPRESSIBLE_CONTENT_TYPES
+                .iter()
+                .any(|compressible| content_type.starts_with(compressible))
+        })
+        .unwrap_or(false)
+}
+
+/// Gzip/Brotli compression negotiated via `Accept-Encoding`, limited to
+/// [`COMPRESSIBLE_CONTENT_TYPES`]. With `enabled` unset responses pass through as-is.
+pub fn layer(enabled: bool) -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new()
+        .gzip(enabled)
+        .br(enabled)
+        .no_deflate()
+        .no_zstd()
+        .compress_when(SizeAbove::new(MIN_COMPRESSIBLE_SIZE).and(is_compressible_content_type))
+}
@@ -1,3 +1,10 @@
+pub mod auth;
+pub mod body_limit;
+pub mod compression;
+pub mod problem_details;
+pub mod rate_limit;
 pub mod request_id;
+pub mod schema_audit;
+pub mod timeout;
 
-pub use request_id::{RequestIdLayer, RequestIdMakeSpan};
+pub use request_id::{RequestIdLayer, RequestIdMakeSpan, RequestIdOnResponse};
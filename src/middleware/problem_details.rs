@@ -0,0 +1,65 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::{Map, Value};
+
+use crate::dto::ErrorResponse;
+
+/// Content type of RFC 7807 problem details.
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Rewrites every 4xx/5xx JSON [`ErrorResponse`] into an RFC 7807 problem detail via
+/// [`ErrorResponse::as_problem_detail`], with the request path as `instance`. Other
+/// responses, including errors without a JSON body, pass through unchanged.
+pub async fn to_problem_details(req: Request, next: Next) -> Response {
+    let path = req.uri().path().to_string();
+    let response = next.run(req).await;
+
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) || !is_json(response.headers()) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!("Failed to read error response body: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let Ok(fields) = serde_json::from_slice::<Map<String, Value>>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    let Ok(error) = serde_json::from_value::<ErrorResponse>(Value::Object(fields.clone())) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let mut problem = error.as_problem_detail(status.as_u16(), &path);
+    // Keep any fields `ErrorResponse` does not model
+    if let Some(problem) = problem.as_object_mut() {
+        for (key, value) in fields {
+            problem.entry(key).or_insert(value);
+        }
+    }
+
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+    );
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(problem.to_string()))
+}
+
+pub(crate) fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"))
+}
@@ -0,0 +1,167 @@
+use axum::{
+    extract::{ConnectInfo, Path, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use governor::{clock::Clock, DefaultKeyedRateLimiter, Quota, RateLimiter};
+use std::{
+    hash::Hash,
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU32,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{config::AppConfig, dto::ErrorResponse, AppState};
+
+pub const RATE_LIMIT_PER_MINUTE_ENV: &str = "RATE_LIMIT_PER_MINUTE";
+pub const RATE_LIMIT_BURST_ENV: &str = "RATE_LIMIT_BURST";
+pub const LOG_INGEST_RATE_LIMIT_PER_MINUTE_ENV: &str = "LOG_INGEST_RATE_LIMIT_PER_MINUTE";
+pub const INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE_ENV: &str = "INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE";
+
+pub const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 600;
+pub const DEFAULT_RATE_LIMIT_BURST: u32 = 100;
+
+/// Stricter limit for `POST /logs`, the main flooding vector.
+pub const DEFAULT_LOG_INGEST_RATE_LIMIT_PER_MINUTE: u32 = 120;
+
+/// Per-token limit for `POST /webhooks/ingest/{token}`, which serves whole log pipelines.
+pub const DEFAULT_INGEST_WEBHOOK_RATE_LIMIT_PER_MINUTE: u32 = 1000;
+
+/// How often idle per-IP entries are dropped from the limiter state.
+pub const RATE_LIMIT_PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Per-IP GCRA state, stored as `DashMap<IpAddr, InMemoryState>`.
+pub type IpRateLimiter = DefaultKeyedRateLimiter<IpAddr>;
+
+/// GCRA state per ingest webhook token.
+pub type TokenRateLimiter = DefaultKeyedRateLimiter<String>;
+
+/// Limiters shared by the rate limiting middleware. A `None` limiter is disabled.
+pub struct RateLimiters {
+    pub global: Option<IpRateLimiter>,
+    pub log_ingest: Option<IpRateLimiter>,
+    pub ingest_webhook: Option<TokenRateLimiter>,
+}
+
+impl RateLimiters {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            global: limiter(config.rate_limit_per_minute, config.rate_limit_burst),
+            log_ingest: limiter(
+                config.log_ingest_rate_limit_per_minute,
+                config.rate_limit_burst,
+            ),
+            ingest_webhook: limiter(
+                config.ingest_webhook_rate_limit_per_minute,
+                config.rate_limit_burst,
+            ),
+        }
+    }
+
+    fn prune(&self) {
+        for limiter in [&self.global, &self.log_ingest].into_iter().flatten() {
+            limiter.retain_recent();
+            limiter.shrink_to_fit();
+        }
+        if let Some(limiter) = &self.ingest_webhook {
+            limiter.retain_recent();
+            limiter.shrink_to_fit();
+        }
+    }
+}
+
+/// `0` disables the limiter. The burst never exceeds the per-minute rate, so small limits stay meaningful.
+fn limiter<K: Hash + Eq + Clone>(
+    per_minute: u32,
+    burst: u32,
+) -> Option<DefaultKeyedRateLimiter<K>> {
+    let per_minute = NonZeroU32::new(per_minute)?;
+    let burst = NonZeroU32::new(burst.min(per_minute.get())).unwrap_or(per_minute);
+
+    Some(RateLimiter::dashmap(
+        Quota::per_minute(per_minute).allow_burst(burst),
+    ))
+}
+
+/// Periodically drops per-IP entries that have fully replenished so the maps
+/// do not grow with every client ever seen.
+pub fn spawn_pruning(limiters: Arc<RateLimiters>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RATE_LIMIT_PRUNE_INTERVAL);
+        loop {
+            interval.tick().await;
+            limiters.prune();
+        }
+    });
+}
+
+/// Applies the global per-IP limit.
+pub async fn limit_requests(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    check(state.rate_limiters.global.as_ref(), request, next).await
+}
+
+/// Applies the stricter per-IP limit for log ingestion.
+pub async fn limit_log_ingest(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    check(state.rate_limiters.log_ingest.as_ref(), request, next).await
+}
+
+/// Applies the per-token limit of ingest webhooks, whatever address the requests come from.
+pub async fn limit_ingest_webhook(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(limiter) = &state.rate_limiters.ingest_webhook {
+        if let Err(not_until) = limiter.check_key(&token) {
+            return too_many_requests(not_until.wait_time_from(limiter.clock().now()));
+        }
+    }
+
+    next.run(request).await
+}
+
+/// Requests without a peer address (e.g. when served without connect info) are not limited.
+async fn check(limiter: Option<&IpRateLimiter>, request: Request, next: Next) -> Response {
+    let (Some(limiter), Some(ConnectInfo(addr))) = (
+        limiter,
+        request.extensions().get::<ConnectInfo<SocketAddr>>(),
+    ) else {
+        return next.run(request).await;
+    };
+
+    if let Err(not_until) = limiter.check_key(&addr.ip()) {
+        return too_many_requests(not_until.wait_time_from(limiter.clock().now()));
+    }
+
+    next.run(request).await
+}
+
+fn too_many_requests(retry_after: Duration) -> Response {
+    // Round up so clients never retry before the limiter allows it
+    let retry_after_secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(header::RETRY_AFTER, retry_after_secs.to_string())],
+        Json(ErrorResponse::new(
+            "RATE_LIMIT_EXCEEDED",
+            format!(
+                "Too many requests, retry after {} second(s)",
+                retry_after_secs
+            ),
+        )),
+    )
+        .into_response()
+}
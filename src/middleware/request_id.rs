@@ -1,10 +1,34 @@
-use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
-use tower_http::trace::MakeSpan;
-use tracing::Span;
+use axum::{
+    extract::Request,
+    http::{HeaderMap, HeaderValue},
+    middleware::{self as axum_middleware, Next},
+    response::Response,
+    Router,
+};
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::time::Duration;
+use tower_http::trace::{DefaultOnResponse, MakeSpan, OnResponse, TraceLayer};
+use tracing::{field::Empty, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use uuid::Uuid;
 
 pub const REQUEST_ID_HEADER: &str = "X-Request-ID";
 
+/// W3C Trace Context header sent by upstream services; `tracestate` is read alongside it.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+pub const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Incoming IDs must match `^[a-zA-Z0-9\-_]{1,128}$`; anything else is replaced so
+/// arbitrary client input never ends up in logs or response headers.
+pub fn is_valid_request_id(request_id: &str) -> bool {
+    (1..=MAX_REQUEST_ID_LENGTH).contains(&request_id.len())
+        && request_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
 #[derive(Clone)]
 pub struct RequestIdLayer;
 
@@ -18,6 +42,7 @@ impl RequestIdLayer {
             .headers()
             .get(REQUEST_ID_HEADER)
             .and_then(|v| v.to_str().ok())
+            .filter(|s| is_valid_request_id(s))
             .map(|s| s.to_string())
             .unwrap_or_else(|| Uuid::new_v4().to_string());
 
@@ -41,6 +66,61 @@ impl Default for RequestIdLayer {
     }
 }
 
+/// Wraps `router` in request ID assignment and HTTP tracing. `RequestIdLayer` is the
+/// outer layer so the ID is already in the request extensions when
+/// [`RequestIdMakeSpan`] creates the span.
+pub fn trace_requests<S>(router: Router<S>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(RequestIdMakeSpan)
+                .on_response(RequestIdOnResponse),
+        )
+        .layer(axum_middleware::from_fn(RequestIdLayer::middleware))
+}
+
+/// Splits a `traceparent` of the form `{version}-{trace-id}-{parent-id}-{flags}` into its
+/// trace and parent IDs. All-zero IDs are invalid per the W3C spec.
+pub fn parse_traceparent(traceparent: &str) -> Option<(&str, &str)> {
+    let is_id = |value: &str, len: usize| {
+        value.len() == len
+            && value
+                .bytes()
+                .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+            && value.bytes().any(|b| b != b'0')
+    };
+
+    let mut parts = traceparent.trim().split('-');
+    let version = parts.next()?;
+    let trace_id = parts.next()?;
+    let parent_id = parts.next()?;
+    let flags = parts.next()?;
+
+    let valid = version.len() == 2
+        && version != "ff"
+        && flags.len() == 2
+        && is_id(trace_id, 32)
+        && is_id(parent_id, 16);
+
+    valid.then_some((trace_id, parent_id))
+}
+
+/// Reads propagation headers for the OpenTelemetry propagator.
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RequestIdMakeSpan;
 
@@ -52,12 +132,58 @@ impl<B> MakeSpan<B> for RequestIdMakeSpan {
             .map(|s| s.as_str())
             .unwrap_or("unknown");
 
-        tracing::info_span!(
+        let span = tracing::info_span!(
             "http_request",
             method = %request.method(),
             uri = %request.uri(),
             version = ?request.version(),
             request_id = %request_id,
-        )
+            trace_id = Empty,
+            parent_id = Empty,
+            otel.kind = "server",
+            otel.status_code = Empty,
+            http.response.status_code = Empty,
+        );
+
+        let traceparent = request
+            .headers()
+            .get(TRACEPARENT_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_traceparent);
+
+        if let Some((trace_id, parent_id)) = traceparent {
+            span.record("trace_id", trace_id);
+            span.record("parent_id", parent_id);
+
+            // Continues the upstream trace (including `tracestate`) when OpenTelemetry
+            // export is enabled; without the OpenTelemetry layer this is a no-op.
+            let context =
+                TraceContextPropagator::new().extract(&HeaderExtractor(request.headers()));
+            let _ = span.set_parent(context);
+        }
+
+        span
+    }
+}
+
+/// Records the response status on the request span (for OpenTelemetry export) before
+/// emitting tower-http's default response event.
+#[derive(Clone, Debug, Default)]
+pub struct RequestIdOnResponse;
+
+impl<B> OnResponse<B> for RequestIdOnResponse {
+    fn on_response(self, response: &axum::http::Response<B>, latency: Duration, span: &Span) {
+        let status = response.status();
+        span.record("http.response.status_code", status.as_u16());
+        span.record(
+            "otel.status_code",
+            if status.is_server_error() {
+                "ERROR"
+            } else {
+                "OK"
+            },
+        );
+
+        DefaultOnResponse::default().on_response(response, latency, span);
     }
 }
@@ -0,0 +1,107 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use tokio::sync::mpsc::error::TrySendError;
+use uuid::Uuid;
+
+use crate::{
+    models::{ApiKey, AuditEvent},
+    AppState,
+};
+
+/// Events buffered for the `schema_audit_log` writer before new ones are dropped.
+pub const SCHEMA_AUDIT_CHANNEL_CAPACITY: usize = 1024;
+
+/// The audited operation for a schema route, or `None` if the route does not
+/// mutate schemas.
+pub fn schema_operation(method: &Method, route: &str) -> Option<&'static str> {
+    let operation = match (method.as_str(), route) {
+        ("POST", "/schemas") => "create",
+        ("POST", "/schemas/import-openapi") => "import",
+        ("PUT", "/schemas/{id}") => "update",
+        ("PATCH", "/schemas/{id}/schema_definition") => "update_definition",
+        ("PATCH", "/schemas/{id}/tags") => "update_tags",
+        ("POST", "/schemas/{id}/deprecate") => "deprecate",
+        ("POST", "/schemas/{id}/undeprecate") => "undeprecate",
+        ("DELETE", "/schemas/{id}") => "delete",
+        _ => return None,
+    };
+
+    Some(operation)
+}
+
+/// Sends an [`AuditEvent`] for every successful (200, 201 or 204) schema mutation to
+/// the `schema_audit_log` writer. Runs after authentication so the API key is known.
+pub async fn audit_schema_mutations(
+    State(state): State<AppState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let operation = req
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|route| schema_operation(req.method(), route.as_str()));
+    let Some(operation) = operation else {
+        return next.run(req).await;
+    };
+
+    // `/schemas/{id}/...`
+    let path_schema_id = req
+        .uri()
+        .path()
+        .split('/')
+        .nth(2)
+        .and_then(|segment| segment.parse::<Uuid>().ok());
+    let actor = req
+        .extensions()
+        .get::<ApiKey>()
+        .map(|api_key| api_key.id.to_string());
+    let request_id = req.extensions().get::<String>().cloned();
+
+    let response = next.run(req).await;
+
+    if !matches!(
+        response.status(),
+        StatusCode::OK | StatusCode::CREATED | StatusCode::NO_CONTENT
+    ) {
+        return response;
+    }
+
+    // Created schemas are only known from the `Location` header
+    let schema_id = path_schema_id.or_else(|| {
+        response
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| location.strip_prefix("/schemas/"))
+            .and_then(|id| id.parse().ok())
+    });
+
+    let event = AuditEvent {
+        operation: operation.to_string(),
+        schema_id,
+        actor,
+        request_id,
+        timestamp: Utc::now(),
+    };
+
+    match state.schema_audit_events.try_send(event) {
+        Ok(()) => {}
+        Err(TrySendError::Full(event)) => {
+            tracing::warn!(
+                "Schema audit queue is full; dropping {} event for {:?}",
+                event.operation,
+                event.schema_id
+            );
+        }
+        Err(TrySendError::Closed(_)) => {
+            tracing::error!("Schema audit writer has stopped");
+        }
+    }
+
+    response
+}
@@ -0,0 +1,31 @@
+use axum::{http::StatusCode, Json};
+use tower::{timeout::error::Elapsed, BoxError};
+
+use crate::dto::ErrorResponse;
+
+pub const REQUEST_TIMEOUT_MS_ENV: &str = "REQUEST_TIMEOUT_MS";
+
+pub const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 30_000;
+
+/// Timeout for routes returning unbounded result sets, such as log listings (5 minutes).
+pub const LONG_REQUEST_TIMEOUT_MS: u64 = 300_000;
+
+/// Converts errors raised by `tower::timeout::TimeoutLayer` into JSON responses.
+pub async fn handle_timeout_error(err: BoxError) -> (StatusCode, Json<ErrorResponse>) {
+    if err.is::<Elapsed>() {
+        tracing::warn!("Request timed out");
+        (
+            StatusCode::REQUEST_TIMEOUT,
+            Json(ErrorResponse::new("TIMEOUT", "Request timed out")),
+        )
+    } else {
+        tracing::error!("Unhandled middleware error: {}", err);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new(
+                "INTERNAL_ERROR",
+                "An internal error occurred",
+            )),
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const PERMISSION_READ: &str = "read";
+pub const PERMISSION_WRITE: &str = "write";
+pub const PERMISSION_ADMIN: &str = "admin";
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub key_hash: String,
+    pub description: Option<String>,
+    pub active: bool,
+    pub permissions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    /// `admin` implies every other permission.
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|p| p == permission || p == PERMISSION_ADMIN)
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+pub const ENTITY_SCHEMA: &str = "schema";
+pub const ENTITY_LOG: &str = "log";
+
+pub const OPERATION_CREATE: &str = "create";
+pub const OPERATION_UPDATE: &str = "update";
+pub const OPERATION_DELETE: &str = "delete";
+/// Summary entry for a bulk log deletion; `entity_id` is the schema ID.
+pub const OPERATION_BULK_DELETE: &str = "bulk_delete";
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AuditLog {
+    pub id: i64,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub operation: String,
+    pub actor: Option<String>,
+    pub old_data: Option<Value>,
+    pub new_data: Option<Value>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// A successful schema mutation, recorded in `schema_audit_log` by the schema audit
+/// middleware.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub operation: String,
+    /// Absent when the response did not identify a single schema (e.g. OpenAPI import)
+    pub schema_id: Option<Uuid>,
+    pub actor: Option<String>,
+    pub request_id: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Who performed a mutation and from where, threaded from handlers into services.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor: Option<String>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+}
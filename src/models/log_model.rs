@@ -1,13 +1,50 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use sqlx::FromRow;
+use sqlx::{types::Json, FromRow};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Lowest log severity, TRACE.
+pub const MIN_SEVERITY: i16 = 0;
+
+/// Highest log severity, FATAL.
+pub const MAX_SEVERITY: i16 = 5;
+
+/// Most attachments accepted on one log.
+pub const MAX_ATTACHMENTS: usize = 10;
+
+/// Longest accepted attachment name.
+pub const MAX_ATTACHMENT_NAME_LENGTH: usize = 255;
+
+/// Reference to an artifact stored outside the log server, such as a screenshot or heap dump.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentRef {
+    /// `https://` URL of the artifact
+    pub url: String,
+    pub name: String,
+    pub content_type: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Log {
     pub id: i32,
     pub schema_id: Uuid,
     pub log_data: Value,
+    /// Service or client that submitted the log
+    pub source: Option<String>,
+    /// Shared by logs of one request or workflow, across schemas
+    #[serde(default)]
+    pub correlation_id: Option<String>,
+    /// From `MIN_SEVERITY` (TRACE) to `MAX_SEVERITY` (FATAL)
+    #[serde(default)]
+    pub severity: Option<i16>,
+    /// Version of the schema the log was validated against; absent on logs created
+    /// before it was recorded
+    #[serde(default)]
+    pub schema_version_at_creation: Option<String>,
+    /// Stored as JSONB; serialized as the plain list
+    #[serde(default)]
+    pub attachments: Option<Json<Vec<AttachmentRef>>>,
     pub created_at: DateTime<Utc>,
 }
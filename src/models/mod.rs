@@ -1,5 +1,11 @@
+pub mod api_key_model;
+pub mod audit_model;
 pub mod log_model;
 pub mod schema_model;
+pub mod webhook_model;
 
-pub use log_model::Log;
-pub use schema_model::Schema;
+pub use api_key_model::ApiKey;
+pub use audit_model::{AuditContext, AuditEvent, AuditLog};
+pub use log_model::{AttachmentRef, Log};
+pub use schema_model::{Schema, ValidationMode};
+pub use webhook_model::{IngestWebhook, Webhook};
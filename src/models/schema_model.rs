@@ -2,15 +2,41 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::FromRow;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// How logs are checked against a schema's definition.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum ValidationMode {
+    /// The definition is used as-is
+    #[default]
+    Strict,
+    /// Extra top-level fields are accepted even with `additionalProperties: false`
+    Lenient,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Schema {
     pub id: Uuid,
+    /// Assigned by the database in creation order, for keyset pagination
+    pub seq: i64,
     pub name: String,
     pub version: String,
     pub description: Option<String>,
     pub schema_definition: Value,
+    /// Deprecated schemas still accept logs, but creating one is logged as a warning
+    #[serde(default)]
+    pub deprecated: bool,
+    pub deprecated_at: Option<DateTime<Utc>>,
+    /// Labels for organising schemas, in the order they were added
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub validation_mode: ValidationMode,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+pub const EVENT_LOG_CREATED: &str = "log.created";
+pub const EVENT_LOG_DELETED: &str = "log.deleted";
+pub const EVENT_SCHEMA_CREATED: &str = "schema.created";
+pub const EVENT_SCHEMA_UPDATED: &str = "schema.updated";
+pub const EVENT_SCHEMA_DELETED: &str = "schema.deleted";
+
+pub const WEBHOOK_EVENTS: [&str; 5] = [
+    EVENT_LOG_CREATED,
+    EVENT_LOG_DELETED,
+    EVENT_SCHEMA_CREATED,
+    EVENT_SCHEMA_UPDATED,
+    EVENT_SCHEMA_DELETED,
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Inbound webhook: logs POSTed to `/webhooks/ingest/{token}` are created for `schema_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct IngestWebhook {
+    pub id: Uuid,
+    pub token: String,
+    pub schema_id: Uuid,
+    /// Object mapping log fields to JSON Pointers into the request body
+    pub transform: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
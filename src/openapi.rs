@@ -0,0 +1,185 @@
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::{
+    dto::{
+        AcrossVersionsResponse, AuditLogListResponse, BulkCreateFailure, BulkCreateLogRequest,
+        BulkCreateLogResponse, BulkCreateSchemaFailure, BulkCreateSchemaResponse,
+        CreateIngestWebhookRequest, CreateLogRequest, CreateSchemaRequest, CreateWebhookRequest,
+        DeleteLogsResponse, ErrorResponse, FieldInfo, GlobalLogStatsResponse,
+        GroupedByTimeResponse, IngestWebhookListResponse, IngestWebhookResponse,
+        LogAttachmentsResponse, LogEvent, LogResponse, MigrationInfo, MigrationResponse,
+        PatchSchemaDefinitionRequest, PatchTagsRequest, SchemaFieldsResponse, SchemaResponse,
+        SchemaVersionsResponse, TimeBucketCount, UpdateIngestWebhookRequest, UpdateSchemaRequest,
+        UpdateWebhookRequest, ValidateExampleResponse, ValidationError, ValidationReport,
+        ValidationReportError, WebhookListResponse, WebhookResponse,
+    },
+    handlers::{
+        admin_handlers, audit_handlers, ingest_webhook_handlers, log_handlers, metrics_handlers,
+        schema_handlers, webhook_handlers, ws_handlers,
+    },
+    middleware::auth::API_KEY_HEADER,
+    models::{AttachmentRef, AuditLog},
+};
+
+/// OpenAPI document served at `GET /openapi.json` and rendered at `/swagger-ui`.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Log Server API",
+        description = "Schema-validated log ingestion and retrieval"
+    ),
+    paths(
+        crate::health_check,
+        metrics_handlers::get_metrics,
+        schema_handlers::get_schemas,
+        schema_handlers::search_schemas,
+        schema_handlers::get_schema_by_id,
+        schema_handlers::get_schema_fields,
+        schema_handlers::export_schema,
+        schema_handlers::get_schema_versions,
+        schema_handlers::get_schema_by_name_and_version,
+        schema_handlers::create_schema,
+        schema_handlers::import_openapi_schemas,
+        schema_handlers::update_schema,
+        schema_handlers::delete_schema,
+        schema_handlers::validate_schema_example,
+        schema_handlers::validate_schemas,
+        schema_handlers::deprecate_schema,
+        schema_handlers::undeprecate_schema,
+        schema_handlers::patch_schema_tags,
+        schema_handlers::patch_schema_definition,
+        log_handlers::create_log,
+        log_handlers::create_logs_batch,
+        log_handlers::get_log_by_id,
+        log_handlers::get_log_attachments,
+        log_handlers::get_logs,
+        log_handlers::get_logs_default,
+        log_handlers::get_logs_grouped_by_time,
+        log_handlers::get_logs_across_versions,
+        log_handlers::get_logs_by_correlation_id,
+        log_handlers::delete_log,
+        log_handlers::delete_logs_before,
+        log_handlers::delete_logs_by_filter,
+        log_handlers::delete_schema_logs,
+        audit_handlers::get_audit_log,
+        admin_handlers::run_migrations,
+        admin_handlers::get_admin_stats,
+        webhook_handlers::get_webhooks,
+        webhook_handlers::get_webhook_by_id,
+        webhook_handlers::create_webhook,
+        webhook_handlers::update_webhook,
+        webhook_handlers::delete_webhook,
+        ingest_webhook_handlers::ingest_webhook_log,
+        ingest_webhook_handlers::get_ingest_webhooks,
+        ingest_webhook_handlers::get_ingest_webhook_by_id,
+        ingest_webhook_handlers::create_ingest_webhook,
+        ingest_webhook_handlers::update_ingest_webhook,
+        ingest_webhook_handlers::delete_ingest_webhook,
+        ws_handlers::ws_handler,
+        ws_handlers::ws_replay_handler,
+    ),
+    components(schemas(
+        ErrorResponse,
+        ValidationError,
+        CreateSchemaRequest,
+        UpdateSchemaRequest,
+        PatchSchemaDefinitionRequest,
+        PatchTagsRequest,
+        SchemaResponse,
+        BulkCreateSchemaResponse,
+        ValidateExampleResponse,
+        ValidationReport,
+        ValidationReportError,
+        SchemaFieldsResponse,
+        SchemaVersionsResponse,
+        FieldInfo,
+        BulkCreateSchemaFailure,
+        CreateLogRequest,
+        LogResponse,
+        LogAttachmentsResponse,
+        AttachmentRef,
+        BulkCreateLogRequest,
+        BulkCreateLogResponse,
+        BulkCreateFailure,
+        DeleteLogsResponse,
+        GroupedByTimeResponse,
+        TimeBucketCount,
+        AcrossVersionsResponse,
+        LogEvent,
+        AuditLog,
+        AuditLogListResponse,
+        MigrationInfo,
+        MigrationResponse,
+        GlobalLogStatsResponse,
+        CreateWebhookRequest,
+        UpdateWebhookRequest,
+        WebhookResponse,
+        WebhookListResponse,
+        CreateIngestWebhookRequest,
+        UpdateIngestWebhookRequest,
+        IngestWebhookResponse,
+        IngestWebhookListResponse,
+    )),
+    modifiers(&ApiKeySecurity),
+    // The API key is only enforced when REQUIRE_API_KEY is set
+    security((), ("api_key" = [])),
+    tags(
+        (name = "health", description = "Liveness checks"),
+        (name = "schemas", description = "JSON Schema management"),
+        (name = "logs", description = "Log ingestion and retrieval"),
+        (name = "audit", description = "Audit trail of mutations"),
+        (name = "admin", description = "Server administration"),
+        (name = "webhooks", description = "Outgoing event notifications"),
+        (name = "websocket", description = "Live log event stream"),
+    )
+)]
+pub struct ApiDoc;
+
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+    }
+}
+
+/// `(method, path, summary)` of every documented route, sorted by path.
+///
+/// Used for the startup banner so the listed endpoints always match the API. The summary
+/// is the first line of the handler's doc comment after its `## METHOD path` heading.
+pub fn routes(openapi: &utoipa::openapi::OpenApi) -> Vec<(&'static str, String, String)> {
+    let mut routes = Vec::new();
+
+    for (path, item) in &openapi.paths.paths {
+        let operations = [
+            ("GET", &item.get),
+            ("POST", &item.post),
+            ("PUT", &item.put),
+            ("PATCH", &item.patch),
+            ("DELETE", &item.delete),
+            ("HEAD", &item.head),
+            ("OPTIONS", &item.options),
+        ];
+
+        for (method, operation) in operations {
+            if let Some(operation) = operation {
+                let summary = operation
+                    .summary
+                    .iter()
+                    .flat_map(|summary| summary.lines())
+                    .find(|line| !line.starts_with("## "))
+                    .unwrap_or_default();
+                routes.push((method, path.clone(), summary.to_string()));
+            }
+        }
+    }
+
+    routes
+}
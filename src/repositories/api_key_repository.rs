@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::error::AppResult;
+use crate::models::ApiKey;
+use crate::repositories::slow_query::timed;
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const GET_ACTIVE_BY_HASH: &str = "api_keys.get_active_by_hash";
+}
+
+#[async_trait]
+pub trait ApiKeyRepositoryTrait {
+    async fn get_active_by_hash(&self, key_hash: &str) -> AppResult<Option<ApiKey>>;
+}
+
+#[derive(Clone)]
+pub struct ApiKeyRepository {
+    pool: PgPool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepositoryTrait for ApiKeyRepository {
+    async fn get_active_by_hash(&self, key_hash: &str) -> AppResult<Option<ApiKey>> {
+        timed(query_name::GET_ACTIVE_BY_HASH, async {
+            let api_key = sqlx::query_as::<_, ApiKey>(
+                "SELECT * FROM api_keys WHERE key_hash = $1 AND active = TRUE",
+            )
+            .bind(key_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(api_key)
+        })
+        .await
+    }
+}
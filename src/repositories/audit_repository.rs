@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::error::AppResult;
+use crate::models::{AuditEvent, AuditLog};
+use crate::repositories::slow_query::timed;
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const RECORD: &str = "audit.record";
+    pub const GET_BY_ENTITY: &str = "audit.get_by_entity";
+    pub const RECORD_SCHEMA_EVENT: &str = "audit.record_schema_event";
+}
+
+#[async_trait]
+pub trait AuditRepositoryTrait: Send + Sync {
+    async fn record(&self, entry: &AuditLog) -> AppResult<AuditLog>;
+    async fn get_by_entity(
+        &self,
+        entity_type: Option<&str>,
+        entity_id: Option<&str>,
+        limit: i64,
+    ) -> AppResult<Vec<AuditLog>>;
+    /// Appends to `schema_audit_log`.
+    async fn record_schema_event(&self, event: &AuditEvent) -> AppResult<()>;
+}
+
+#[derive(Clone)]
+pub struct AuditRepository {
+    pool: PgPool,
+}
+
+impl AuditRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for AuditRepository {
+    async fn record(&self, entry: &AuditLog) -> AppResult<AuditLog> {
+        timed(query_name::RECORD, async {
+            let recorded = sqlx::query_as::<_, AuditLog>(
+                r#"
+                INSERT INTO audit_log (entity_type, entity_id, operation, actor, old_data, new_data, request_id, ip_address, occurred_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING *
+                "#,
+            )
+            .bind(&entry.entity_type)
+            .bind(&entry.entity_id)
+            .bind(&entry.operation)
+            .bind(&entry.actor)
+            .bind(&entry.old_data)
+            .bind(&entry.new_data)
+            .bind(&entry.request_id)
+            .bind(&entry.ip_address)
+            .bind(entry.occurred_at)
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(recorded)
+        })
+        .await
+    }
+
+    async fn get_by_entity(
+        &self,
+        entity_type: Option<&str>,
+        entity_id: Option<&str>,
+        limit: i64,
+    ) -> AppResult<Vec<AuditLog>> {
+        timed(query_name::GET_BY_ENTITY, async {
+            let entries = sqlx::query_as::<_, AuditLog>(
+                r#"
+                SELECT * FROM audit_log
+                WHERE ($1::text IS NULL OR entity_type = $1)
+                  AND ($2::text IS NULL OR entity_id = $2)
+                ORDER BY occurred_at DESC, id DESC
+                LIMIT $3
+                "#,
+            )
+            .bind(entity_type)
+            .bind(entity_id)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(entries)
+        })
+        .await
+    }
+
+    async fn record_schema_event(&self, event: &AuditEvent) -> AppResult<()> {
+        timed(query_name::RECORD_SCHEMA_EVENT, async {
+            sqlx::query(
+                r#"
+                INSERT INTO schema_audit_log (operation, schema_id, actor, request_id, timestamp)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(&event.operation)
+            .bind(event.schema_id)
+            .bind(&event.actor)
+            .bind(&event.request_id)
+            .bind(event.timestamp)
+            .execute(&self.pool)
+            .await?;
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::IngestWebhook;
+use crate::repositories::slow_query::timed;
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const GET_ALL: &str = "ingest_webhooks.get_all";
+    pub const GET_BY_ID: &str = "ingest_webhooks.get_by_id";
+    pub const GET_BY_TOKEN: &str = "ingest_webhooks.get_by_token";
+    pub const CREATE: &str = "ingest_webhooks.create";
+    pub const UPDATE: &str = "ingest_webhooks.update";
+    pub const DELETE: &str = "ingest_webhooks.delete";
+}
+
+#[async_trait]
+pub trait IngestWebhookRepositoryTrait {
+    async fn get_all(&self) -> AppResult<Vec<IngestWebhook>>;
+    async fn get_by_id(&self, id: Uuid) -> AppResult<Option<IngestWebhook>>;
+    async fn get_by_token(&self, token: &str) -> AppResult<Option<IngestWebhook>>;
+    async fn create(&self, webhook: &IngestWebhook) -> AppResult<IngestWebhook>;
+    async fn update(&self, id: Uuid, webhook: &IngestWebhook) -> AppResult<Option<IngestWebhook>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct IngestWebhookRepository {
+    pool: PgPool,
+}
+
+impl IngestWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IngestWebhookRepositoryTrait for IngestWebhookRepository {
+    async fn get_all(&self) -> AppResult<Vec<IngestWebhook>> {
+        timed(query_name::GET_ALL, async {
+            let webhooks = sqlx::query_as::<_, IngestWebhook>(
+                "SELECT * FROM ingest_webhooks ORDER BY created_at DESC",
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(webhooks)
+        })
+        .await
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> AppResult<Option<IngestWebhook>> {
+        timed(query_name::GET_BY_ID, async {
+            let webhook =
+                sqlx::query_as::<_, IngestWebhook>("SELECT * FROM ingest_webhooks WHERE id = $1")
+                    .bind(id)
+                    .fetch_optional(&self.pool)
+                    .await?;
+
+            Ok(webhook)
+        })
+        .await
+    }
+
+    async fn get_by_token(&self, token: &str) -> AppResult<Option<IngestWebhook>> {
+        timed(query_name::GET_BY_TOKEN, async {
+            let webhook = sqlx::query_as::<_, IngestWebhook>(
+                "SELECT * FROM ingest_webhooks WHERE token = $1",
+            )
+            .bind(token)
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(webhook)
+        })
+        .await
+    }
+
+    async fn create(&self, webhook: &IngestWebhook) -> AppResult<IngestWebhook> {
+        timed(query_name::CREATE, async {
+            let created = sqlx::query_as::<_, IngestWebhook>(
+                r#"
+                INSERT INTO ingest_webhooks (id, token, schema_id, transform, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING *
+                "#,
+            )
+            .bind(webhook.id)
+            .bind(&webhook.token)
+            .bind(webhook.schema_id)
+            .bind(&webhook.transform)
+            .bind(webhook.created_at)
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(created)
+        })
+        .await
+    }
+
+    async fn update(&self, id: Uuid, webhook: &IngestWebhook) -> AppResult<Option<IngestWebhook>> {
+        timed(query_name::UPDATE, async {
+            let updated = sqlx::query_as::<_, IngestWebhook>(
+                r#"
+                UPDATE ingest_webhooks
+                SET schema_id = $2, transform = $3
+                WHERE id = $1
+                RETURNING *
+                "#,
+            )
+            .bind(id)
+            .bind(webhook.schema_id)
+            .bind(&webhook.transform)
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(updated)
+        })
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        timed(query_name::DELETE, async {
+            let result = sqlx::query("DELETE FROM ingest_webhooks WHERE id = $1")
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+            Ok(result.rows_affected() > 0)
+        })
+        .await
+    }
+}
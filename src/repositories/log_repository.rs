@@ -1,23 +1,301 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use serde_json::Value;
-use sqlx::PgPool;
+use sqlx::{types::Json, FromRow, PgPool};
 use uuid::Uuid;
 
 use crate::error::AppResult;
-use crate::models::Log;
+use crate::models::{
+    log_model::{MAX_SEVERITY, MIN_SEVERITY},
+    AttachmentRef, Log,
+};
+use crate::repositories::slow_query::timed;
+use crate::utils::idempotency::IDEMPOTENCY_KEY_TTL_HOURS;
+
+/// Granularity of [`LogRepositoryTrait::get_grouped_by_time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+impl TimeBucket {
+    /// Field name understood by PostgreSQL's `date_trunc`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeBucket::Minute => "minute",
+            TimeBucket::Hour => "hour",
+            TimeBucket::Day => "day",
+            TimeBucket::Week => "week",
+        }
+    }
+}
+
+impl std::str::FromStr for TimeBucket {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "minute" => Ok(TimeBucket::Minute),
+            "hour" => Ok(TimeBucket::Hour),
+            "day" => Ok(TimeBucket::Day),
+            "week" => Ok(TimeBucket::Week),
+            _ => Err(format!(
+                "bucket '{}' is not one of minute, hour, day, week",
+                value
+            )),
+        }
+    }
+}
+
+/// Longest `log_data` field name accepted by [`LogSort`].
+pub const MAX_SORT_FIELD_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(format!("sort_order '{}' is not one of asc, desc", value)),
+        }
+    }
+}
+
+/// Order of [`LogRepositoryTrait::get_by_schema_id`]: by a top-level `log_data` field
+/// (compared as text) when `field` is set, then by `created_at`.
+#[derive(Debug, Clone)]
+pub struct LogSort {
+    field: Option<String>,
+    order: SortOrder,
+}
+
+impl Default for LogSort {
+    /// Newest first
+    fn default() -> Self {
+        Self {
+            field: None,
+            order: SortOrder::Desc,
+        }
+    }
+}
+
+impl LogSort {
+    /// `field` must match `^[a-zA-Z0-9_]{1,50}$`. Without an explicit `order`, fields sort
+    /// ascending and `created_at` descending.
+    pub fn new(field: Option<String>, order: Option<SortOrder>) -> Result<Self, String> {
+        if let Some(field) = &field {
+            let is_safe = !field.is_empty()
+                && field.len() <= MAX_SORT_FIELD_LENGTH
+                && field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !is_safe {
+                return Err(format!(
+                    "sort_field must be 1 to {} letters, digits or underscores",
+                    MAX_SORT_FIELD_LENGTH
+                ));
+            }
+        }
+
+        let default_order = if field.is_some() {
+            SortOrder::Asc
+        } else {
+            SortOrder::Desc
+        };
+
+        Ok(Self {
+            order: order.unwrap_or(default_order),
+            field,
+        })
+    }
+}
+
+/// Inclusive `severity` bounds of a listing; logs without a severity only match when
+/// neither bound is set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeverityRange {
+    min: Option<i16>,
+    max: Option<i16>,
+}
+
+impl SeverityRange {
+    /// Bounds must lie within `MIN_SEVERITY..=MAX_SEVERITY` and `min` cannot exceed `max`.
+    pub fn new(min: Option<i16>, max: Option<i16>) -> Result<Self, String> {
+        for bound in [min, max].into_iter().flatten() {
+            if !(MIN_SEVERITY..=MAX_SEVERITY).contains(&bound) {
+                return Err(format!(
+                    "severity must be between {} and {}",
+                    MIN_SEVERITY, MAX_SEVERITY
+                ));
+            }
+        }
+
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err("min_severity cannot be greater than max_severity".to_string());
+            }
+        }
+
+        Ok(Self { min, max })
+    }
+
+    /// `None` when the range matches every log; otherwise both bounds, with a missing
+    /// one widened to the end of the scale.
+    fn bounds(&self) -> Option<(i16, i16)> {
+        if self.min.is_none() && self.max.is_none() {
+            return None;
+        }
+
+        Some((
+            self.min.unwrap_or(MIN_SEVERITY),
+            self.max.unwrap_or(MAX_SEVERITY),
+        ))
+    }
+}
+
+/// Criteria of [`LogRepositoryTrait::delete_by_filter`]; unset fields match every log.
+#[derive(Debug, Clone, Default)]
+pub struct LogDeleteFilter {
+    /// Only logs created strictly before this instant
+    pub before: Option<DateTime<Utc>>,
+    /// Only logs created strictly after this instant
+    pub after: Option<DateTime<Utc>>,
+    /// Only logs submitted by this source
+    pub source: Option<String>,
+}
+
+impl LogDeleteFilter {
+    pub fn is_empty(&self) -> bool {
+        self.before.is_none() && self.after.is_none() && self.source.is_none()
+    }
+}
+
+/// Outcome of [`LogRepositoryTrait::delete_by_filter`].
+#[derive(Debug, Default)]
+pub struct DeletedLogs {
+    pub count: i64,
+    /// IDs of the first deleted logs, lowest first, capped at the requested limit
+    pub ids: Vec<i32>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct TimeBucketedCount {
+    /// Start of the bucket
+    pub bucket: DateTime<Utc>,
+    pub count: i64,
+}
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const GET_BY_SCHEMA_ID: &str = "logs.get_by_schema_id";
+    pub const COUNT_BY_SCHEMA_ID_WITH_FILTER: &str = "logs.count_by_schema_id_with_filter";
+    pub const GET_BY_SCHEMA_IDS: &str = "logs.get_by_schema_ids";
+    pub const COUNT_BY_SCHEMA_IDS: &str = "logs.count_by_schema_ids";
+    pub const GET_FOR_REPLAY: &str = "logs.get_for_replay";
+    pub const GET_BY_CORRELATION_ID: &str = "logs.get_by_correlation_id";
+    pub const COUNT_BY_CORRELATION_ID: &str = "logs.count_by_correlation_id";
+    pub const GET_BY_ID: &str = "logs.get_by_id";
+    pub const CREATE: &str = "logs.create";
+    pub const CREATE_MANY: &str = "logs.create_many";
+    pub const DELETE: &str = "logs.delete";
+    pub const COUNT_BY_SCHEMA_ID: &str = "logs.count_by_schema_id";
+    pub const COUNT_ALL: &str = "logs.count_all";
+    pub const COUNT_SINCE: &str = "logs.count_since";
+    pub const CREATED_AT_RANGE: &str = "logs.created_at_range";
+    pub const SAMPLE_BY_SCHEMA_ID: &str = "logs.sample_by_schema_id";
+    pub const DELETE_BY_SCHEMA_ID: &str = "logs.delete_by_schema_id";
+    pub const DELETE_BY_FILTER: &str = "logs.delete_by_filter";
+    pub const GET_GROUPED_BY_TIME: &str = "logs.get_grouped_by_time";
+    pub const GET_OR_CREATE_IDEMPOTENT: &str = "logs.get_or_create_idempotent";
+}
 
 #[async_trait]
-pub trait LogRepositoryTrait {
+pub trait LogRepositoryTrait: Send + Sync {
+    #[allow(clippy::too_many_arguments)]
     async fn get_by_schema_id(
         &self,
         schema_id: Uuid,
         filters: Option<Value>,
+        source: Option<&str>,
+        severity: &SeverityRange,
+        sort: &LogSort,
+        limit: Option<i64>,
+        offset: i64,
+    ) -> AppResult<Vec<Log>>;
+    async fn count_by_schema_id_with_filter(
+        &self,
+        schema_id: Uuid,
+        filter: Option<&Value>,
+        source: Option<&str>,
+        severity: &SeverityRange,
+    ) -> AppResult<i64>;
+    async fn get_by_severity_range(
+        &self,
+        schema_id: Uuid,
+        severity: &SeverityRange,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>>;
+    async fn get_by_schema_ids(
+        &self,
+        schema_ids: &[Uuid],
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>>;
+    async fn count_by_schema_ids(
+        &self,
+        schema_ids: &[Uuid],
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> AppResult<i64>;
+    async fn get_for_replay(
+        &self,
+        schema_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        limit: i64,
     ) -> AppResult<Vec<Log>>;
+    async fn get_by_correlation_id(
+        &self,
+        correlation_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>>;
+    async fn count_by_correlation_id(&self, correlation_id: &str) -> AppResult<i64>;
     async fn get_by_id(&self, id: i32) -> AppResult<Option<Log>>;
     async fn create(&self, log: &Log) -> AppResult<Log>;
+    async fn create_many(&self, logs: &[Log]) -> AppResult<Vec<Log>>;
     async fn delete(&self, id: i32) -> AppResult<bool>;
     async fn count_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64>;
+    async fn count_all(&self) -> AppResult<i64>;
+    async fn count_since(&self, since: DateTime<Utc>) -> AppResult<i64>;
+    /// Creation times of the oldest and newest logs; `None` when there are no logs.
+    async fn created_at_range(&self) -> AppResult<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)>;
+    async fn sample_by_schema_id(&self, schema_id: Uuid, limit: i64) -> AppResult<Vec<Log>>;
     async fn delete_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64>;
+    async fn delete_by_filter(
+        &self,
+        schema_id: Uuid,
+        filter: &LogDeleteFilter,
+        id_limit: i64,
+    ) -> AppResult<DeletedLogs>;
+    async fn get_grouped_by_time(
+        &self,
+        schema_id: Uuid,
+        bucket: TimeBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<Vec<TimeBucketedCount>>;
+    async fn get_or_create_idempotent(&self, key: &str, log: &Log) -> AppResult<(Log, bool)>;
 }
 
 #[derive(Clone)]
@@ -37,94 +315,643 @@ impl LogRepositoryTrait for LogRepository {
         &self,
         schema_id: Uuid,
         filters: Option<Value>,
+        source: Option<&str>,
+        severity: &SeverityRange,
+        sort: &LogSort,
+        limit: Option<i64>,
+        offset: i64,
     ) -> AppResult<Vec<Log>> {
-        if let Some(filter_obj) = &filters {
-            if let Some(filter_map) = filter_obj.as_object() {
-                let logs = sqlx::query_as::<_, Log>(
-                    "SELECT * FROM logs WHERE schema_id = $1 AND log_data @> $2 ORDER BY created_at DESC"
-                )
-                .bind(schema_id)
-                .bind(filter_obj)
-                .fetch_all(&self.pool)
-                .await?;
+        timed(query_name::GET_BY_SCHEMA_ID, async {
+            let (min_severity, max_severity) = severity.bounds().unzip();
+            // Every object contains the empty object, so no filters match all logs.
+            // Without a sort field `log_data->>NULL` ties every row so `created_at` decides.
+            let logs = match sort.order {
+                SortOrder::Asc => {
+                    sqlx::query_as!(
+                        Log,
+                        r#"
+                        SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                               attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                        FROM logs
+                        WHERE schema_id = $1 AND log_data @> COALESCE($2, '{}'::jsonb)
+                          AND ($5::text IS NULL OR source = $5)
+                          AND ($7::smallint IS NULL OR severity BETWEEN $7 AND $8)
+                        ORDER BY log_data->>($6::text) ASC, created_at ASC
+                        LIMIT $3 OFFSET $4
+                        "#,
+                        schema_id,
+                        filters,
+                        limit,
+                        offset,
+                        source,
+                        sort.field,
+                        min_severity,
+                        max_severity,
+                    )
+                    .fetch_all(&self.pool)
+                    .await?
+                }
+                SortOrder::Desc => {
+                    sqlx::query_as!(
+                        Log,
+                        r#"
+                        SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                               attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                        FROM logs
+                        WHERE schema_id = $1 AND log_data @> COALESCE($2, '{}'::jsonb)
+                          AND ($5::text IS NULL OR source = $5)
+                          AND ($7::smallint IS NULL OR severity BETWEEN $7 AND $8)
+                        ORDER BY log_data->>($6::text) DESC, created_at DESC
+                        LIMIT $3 OFFSET $4
+                        "#,
+                        schema_id,
+                        filters,
+                        limit,
+                        offset,
+                        source,
+                        sort.field,
+                        min_severity,
+                        max_severity,
+                    )
+                    .fetch_all(&self.pool)
+                    .await?
+                }
+            };
 
-                tracing::debug!(
-                    "Fetched {} logs for schema_id={} with filters: {:?}",
-                    logs.len(),
-                    schema_id,
-                    filter_map.keys().collect::<Vec<_>>()
-                );
+            tracing::debug!(
+                "Fetched {} logs for schema_id={} with filters: {:?}",
+                logs.len(),
+                schema_id,
+                filters
+                    .as_ref()
+                    .and_then(|filters| filters.as_object())
+                    .map(|filters| filters.keys().collect::<Vec<_>>())
+            );
 
-                return Ok(logs);
-            }
-        }
+            Ok(logs)
+        })
+        .await
+    }
 
-        let logs = sqlx::query_as::<_, Log>(
-            "SELECT * FROM logs WHERE schema_id = $1 ORDER BY created_at DESC",
+    /// Number of logs matched by the same `filter`, `source` and `severity` as
+    /// `get_by_schema_id`, for the total of a filtered page.
+    async fn count_by_schema_id_with_filter(
+        &self,
+        schema_id: Uuid,
+        filter: Option<&Value>,
+        source: Option<&str>,
+        severity: &SeverityRange,
+    ) -> AppResult<i64> {
+        timed(query_name::COUNT_BY_SCHEMA_ID_WITH_FILTER, async {
+            let (min_severity, max_severity) = severity.bounds().unzip();
+            let count = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!" FROM logs
+                WHERE schema_id = $1 AND ($2::jsonb IS NULL OR log_data @> $2)
+                  AND ($3::text IS NULL OR source = $3)
+                  AND ($4::smallint IS NULL OR severity BETWEEN $4 AND $5)
+                "#,
+                schema_id,
+                filter,
+                source,
+                min_severity,
+                max_severity,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(count)
+        })
+        .await
+    }
+
+    /// Newest logs of the schema whose severity lies in `severity`.
+    async fn get_by_severity_range(
+        &self,
+        schema_id: Uuid,
+        severity: &SeverityRange,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        self.get_by_schema_id(
+            schema_id,
+            None,
+            None,
+            severity,
+            &LogSort::default(),
+            Some(limit),
+            offset,
         )
-        .bind(schema_id)
-        .fetch_all(&self.pool)
-        .await?;
+        .await
+    }
 
-        tracing::debug!(
-            "Fetched {} logs for schema_id={} (no filters)",
-            logs.len(),
-            schema_id
-        );
+    /// Logs of any of the schemas, newest first, optionally limited to `from..=to`.
+    async fn get_by_schema_ids(
+        &self,
+        schema_ids: &[Uuid],
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        timed(query_name::GET_BY_SCHEMA_IDS, async {
+            let logs = sqlx::query_as!(
+                Log,
+                r#"
+                SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                       attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                FROM logs
+                WHERE schema_id = ANY($1::uuid[])
+                  AND ($2::timestamptz IS NULL OR created_at >= $2)
+                  AND ($3::timestamptz IS NULL OR created_at <= $3)
+                ORDER BY created_at DESC
+                LIMIT $4 OFFSET $5
+                "#,
+                schema_ids,
+                from,
+                to,
+                limit,
+                offset,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(logs)
+        })
+        .await
+    }
+
+    async fn count_by_schema_ids(
+        &self,
+        schema_ids: &[Uuid],
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> AppResult<i64> {
+        timed(query_name::COUNT_BY_SCHEMA_IDS, async {
+            let count = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!" FROM logs
+                WHERE schema_id = ANY($1::uuid[])
+                  AND ($2::timestamptz IS NULL OR created_at >= $2)
+                  AND ($3::timestamptz IS NULL OR created_at <= $3)
+                "#,
+                schema_ids,
+                from,
+                to,
+            )
+            .fetch_one(&self.pool)
+            .await?;
 
-        Ok(logs)
+            Ok(count)
+        })
+        .await
+    }
+
+    /// Up to `limit` logs of the schema, oldest first: the first ones created at or after
+    /// `from`, or the most recent ones when `from` is not given.
+    async fn get_for_replay(
+        &self,
+        schema_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> AppResult<Vec<Log>> {
+        timed(query_name::GET_FOR_REPLAY, async {
+            let logs = match from {
+                Some(from) => {
+                    sqlx::query_as!(
+                        Log,
+                        r#"
+                        SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                               attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                        FROM logs
+                        WHERE schema_id = $1 AND created_at >= $2
+                        ORDER BY created_at ASC, id ASC
+                        LIMIT $3
+                        "#,
+                        schema_id,
+                        from,
+                        limit,
+                    )
+                    .fetch_all(&self.pool)
+                    .await?
+                }
+                None => {
+                    let mut logs = sqlx::query_as!(
+                        Log,
+                        r#"
+                        SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                               attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                        FROM logs
+                        WHERE schema_id = $1
+                        ORDER BY created_at DESC, id DESC
+                        LIMIT $2
+                        "#,
+                        schema_id,
+                        limit,
+                    )
+                    .fetch_all(&self.pool)
+                    .await?;
+                    logs.reverse();
+                    logs
+                }
+            };
+
+            Ok(logs)
+        })
+        .await
+    }
+
+    /// Logs sharing `correlation_id`, from any schema, oldest first.
+    async fn get_by_correlation_id(
+        &self,
+        correlation_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        timed(query_name::GET_BY_CORRELATION_ID, async {
+            let logs = sqlx::query_as!(
+                Log,
+                r#"
+                SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                       attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                FROM logs
+                WHERE correlation_id = $1
+                ORDER BY created_at ASC
+                LIMIT $2 OFFSET $3
+                "#,
+                correlation_id,
+                limit,
+                offset,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(logs)
+        })
+        .await
+    }
+
+    async fn count_by_correlation_id(&self, correlation_id: &str) -> AppResult<i64> {
+        timed(query_name::COUNT_BY_CORRELATION_ID, async {
+            let count = sqlx::query_scalar!(
+                r#"SELECT COUNT(*) AS "count!" FROM logs WHERE correlation_id = $1"#,
+                correlation_id,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(count)
+        })
+        .await
     }
 
     async fn get_by_id(&self, id: i32) -> AppResult<Option<Log>> {
-        let log = sqlx::query_as::<_, Log>("SELECT * FROM logs WHERE id = $1")
-            .bind(id)
+        timed(query_name::GET_BY_ID, async {
+            let log = sqlx::query_as!(
+                Log,
+                r#"
+                SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                       attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                FROM logs
+                WHERE id = $1
+                "#,
+                id,
+            )
             .fetch_optional(&self.pool)
             .await?;
 
-        Ok(log)
+            Ok(log)
+        })
+        .await
     }
 
     async fn create(&self, log: &Log) -> AppResult<Log> {
-        let created_log = sqlx::query_as::<_, Log>(
-            r#"
-            INSERT INTO logs (schema_id, log_data, created_at)
-            VALUES ($1, $2, $3)
-            RETURNING *
-            "#,
-        )
-        .bind(log.schema_id)
-        .bind(&log.log_data)
-        .bind(log.created_at)
-        .fetch_one(&self.pool)
-        .await?;
+        timed(query_name::CREATE, async {
+            let created_log = sqlx::query_as!(
+                Log,
+                r#"
+                INSERT INTO logs (schema_id, log_data, source, correlation_id, severity, schema_version_at_creation, created_at, attachments)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                          attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                "#,
+                log.schema_id,
+                log.log_data,
+                log.source,
+                log.correlation_id,
+                log.severity,
+                log.schema_version_at_creation,
+                log.created_at,
+                log.attachments as _,
+            )
+            .fetch_one(&self.pool)
+            .await?;
 
-        Ok(created_log)
+            Ok(created_log)
+        })
+        .await
     }
 
-    async fn delete(&self, id: i32) -> AppResult<bool> {
-        let result = sqlx::query("DELETE FROM logs WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
+    /// Inserts all logs in one statement inside a transaction; either every log is
+    /// written or none is. Returned logs are in input order.
+    async fn create_many(&self, logs: &[Log]) -> AppResult<Vec<Log>> {
+        timed(query_name::CREATE_MANY, async {
+            if logs.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let schema_ids: Vec<Uuid> = logs.iter().map(|log| log.schema_id).collect();
+            let log_data: Vec<Value> = logs.iter().map(|log| log.log_data.clone()).collect();
+            let sources: Vec<Option<String>> = logs.iter().map(|log| log.source.clone()).collect();
+            let correlation_ids: Vec<Option<String>> =
+                logs.iter().map(|log| log.correlation_id.clone()).collect();
+            let severities: Vec<Option<i16>> = logs.iter().map(|log| log.severity).collect();
+            let schema_versions: Vec<Option<String>> = logs
+                .iter()
+                .map(|log| log.schema_version_at_creation.clone())
+                .collect();
+            let created_at: Vec<_> = logs.iter().map(|log| log.created_at).collect();
+            let attachments: Vec<Option<Json<Vec<AttachmentRef>>>> =
+                logs.iter().map(|log| log.attachments.clone()).collect();
+
+            let mut tx = self.pool.begin().await?;
+
+            let mut created_logs = sqlx::query_as!(
+                Log,
+                r#"
+                INSERT INTO logs (schema_id, log_data, source, correlation_id, severity, schema_version_at_creation, created_at, attachments)
+                SELECT * FROM UNNEST(
+                    $1::uuid[], $2::jsonb[], $3::text[], $4::text[], $5::smallint[], $6::text[], $7::timestamptz[],
+                    $8::jsonb[]
+                )
+                RETURNING id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                          attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                "#,
+                &schema_ids,
+                &log_data,
+                &sources as &[Option<String>],
+                &correlation_ids as &[Option<String>],
+                &severities as &[Option<i16>],
+                &schema_versions as &[Option<String>],
+                &created_at,
+                &attachments as &[Option<Json<Vec<AttachmentRef>>>],
+            )
+            .fetch_all(&mut *tx)
             .await?;
 
-        Ok(result.rows_affected() > 0)
+            tx.commit().await?;
+
+            // Serial ids follow insertion order
+            created_logs.sort_by_key(|log| log.id);
+
+            Ok(created_logs)
+        })
+        .await
+    }
+
+    async fn delete(&self, id: i32) -> AppResult<bool> {
+        timed(query_name::DELETE, async {
+            let result = sqlx::query!("DELETE FROM logs WHERE id = $1", id)
+                .execute(&self.pool)
+                .await?;
+
+            Ok(result.rows_affected() > 0)
+        })
+        .await
     }
 
     async fn count_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64> {
-        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM logs WHERE schema_id = $1")
-            .bind(schema_id)
+        timed(query_name::COUNT_BY_SCHEMA_ID, async {
+            let count = sqlx::query_scalar!(
+                r#"SELECT COUNT(*) AS "count!" FROM logs WHERE schema_id = $1"#,
+                schema_id,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(count)
+        })
+        .await
+    }
+
+    async fn count_all(&self) -> AppResult<i64> {
+        timed(query_name::COUNT_ALL, async {
+            let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM logs"#)
+                .fetch_one(&self.pool)
+                .await?;
+
+            Ok(count)
+        })
+        .await
+    }
+
+    async fn count_since(&self, since: DateTime<Utc>) -> AppResult<i64> {
+        timed(query_name::COUNT_SINCE, async {
+            let count = sqlx::query_scalar!(
+                r#"SELECT COUNT(*) AS "count!" FROM logs WHERE created_at >= $1"#,
+                since,
+            )
             .fetch_one(&self.pool)
             .await?;
 
-        Ok(count)
+            Ok(count)
+        })
+        .await
+    }
+
+    async fn created_at_range(&self) -> AppResult<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)> {
+        timed(query_name::CREATED_AT_RANGE, async {
+            let range = sqlx::query!(
+                "SELECT MIN(created_at) AS oldest, MAX(created_at) AS newest FROM logs"
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok((range.oldest, range.newest))
+        })
+        .await
+    }
+
+    /// Returns up to `limit` of the schema's most recent logs.
+    async fn sample_by_schema_id(&self, schema_id: Uuid, limit: i64) -> AppResult<Vec<Log>> {
+        timed(query_name::SAMPLE_BY_SCHEMA_ID, async {
+            let logs = sqlx::query_as!(
+                Log,
+                r#"
+                SELECT id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                       attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                FROM logs
+                WHERE schema_id = $1
+                ORDER BY created_at DESC
+                LIMIT $2
+                "#,
+                schema_id,
+                limit,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(logs)
+        })
+        .await
     }
 
     async fn delete_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64> {
-        let result = sqlx::query("DELETE FROM logs WHERE schema_id = $1")
-            .bind(schema_id)
-            .execute(&self.pool)
+        timed(query_name::DELETE_BY_SCHEMA_ID, async {
+            let result = sqlx::query!("DELETE FROM logs WHERE schema_id = $1", schema_id)
+                .execute(&self.pool)
+                .await?;
+
+            Ok(result.rows_affected() as i64)
+        })
+        .await
+    }
+
+    /// Deletes the schema's logs matching `filter` in a single statement, returning
+    /// how many were removed and the IDs of up to `id_limit` of them.
+    async fn delete_by_filter(
+        &self,
+        schema_id: Uuid,
+        filter: &LogDeleteFilter,
+        id_limit: i64,
+    ) -> AppResult<DeletedLogs> {
+        timed(query_name::DELETE_BY_FILTER, async {
+            let deleted = sqlx::query_as!(
+                DeletedLogs,
+                r#"
+                WITH deleted AS (
+                    DELETE FROM logs
+                    WHERE schema_id = $1
+                      AND ($2::timestamptz IS NULL OR created_at < $2)
+                      AND ($3::timestamptz IS NULL OR created_at > $3)
+                      AND ($4::text IS NULL OR source = $4)
+                    RETURNING id
+                )
+                SELECT (SELECT COUNT(*) FROM deleted) AS "count!",
+                       ARRAY(SELECT id FROM deleted ORDER BY id LIMIT $5) AS "ids!"
+                "#,
+                schema_id,
+                filter.before,
+                filter.after,
+                filter.source,
+                id_limit,
+            )
+            .fetch_one(&self.pool)
             .await?;
 
-        Ok(result.rows_affected() as i64)
+            Ok(deleted)
+        })
+        .await
+    }
+
+    /// Counts the schema's logs created between `from` and `to` (inclusive) per bucket.
+    /// Buckets without logs are omitted.
+    async fn get_grouped_by_time(
+        &self,
+        schema_id: Uuid,
+        bucket: TimeBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<Vec<TimeBucketedCount>> {
+        timed(query_name::GET_GROUPED_BY_TIME, async {
+            let buckets = sqlx::query_as!(
+                TimeBucketedCount,
+                r#"
+                SELECT date_trunc($1, created_at) AS "bucket!", COUNT(*) AS "count!"
+                FROM logs
+                WHERE schema_id = $2 AND created_at BETWEEN $3 AND $4
+                GROUP BY 1
+                ORDER BY 1
+                "#,
+                bucket.as_str(),
+                schema_id,
+                from,
+                to,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(buckets)
+        })
+        .await
+    }
+
+    /// Returns the log previously created under `key`, or creates one and records the key.
+    /// The boolean is `true` when a new log was created.
+    async fn get_or_create_idempotent(&self, key: &str, log: &Log) -> AppResult<(Log, bool)> {
+        timed(query_name::GET_OR_CREATE_IDEMPOTENT, async {
+            let mut tx = self.pool.begin().await?;
+
+            sqlx::query!(
+                "DELETE FROM idempotency_keys WHERE key = $1 AND expires_at <= NOW()",
+                key,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            // Concurrent requests with the same key block on the primary key until this commits
+            let claimed = sqlx::query!(
+                "INSERT INTO idempotency_keys (key, expires_at) VALUES ($1, $2) ON CONFLICT (key) DO NOTHING",
+                key,
+                Utc::now() + Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS),
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+                > 0;
+
+            if !claimed {
+                let existing = sqlx::query_as!(
+                    Log,
+                    r#"
+                    SELECT l.id, l.schema_id, l.log_data, l.source, l.correlation_id, l.severity,
+                           l.schema_version_at_creation,
+                           l.attachments AS "attachments: Json<Vec<AttachmentRef>>", l.created_at AS "created_at!"
+                    FROM logs l
+                    JOIN idempotency_keys k ON k.log_id = l.id
+                    WHERE k.key = $1
+                    "#,
+                    key,
+                )
+                .fetch_one(&mut *tx)
+                .await?;
+
+                tx.commit().await?;
+                return Ok((existing, false));
+            }
+
+            let created_log = sqlx::query_as!(
+                Log,
+                r#"
+                INSERT INTO logs (schema_id, log_data, source, correlation_id, severity, schema_version_at_creation, created_at, attachments)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, schema_id, log_data, source, correlation_id, severity, schema_version_at_creation,
+                          attachments AS "attachments: Json<Vec<AttachmentRef>>", created_at AS "created_at!"
+                "#,
+                log.schema_id,
+                log.log_data,
+                log.source,
+                log.correlation_id,
+                log.severity,
+                log.schema_version_at_creation,
+                log.created_at,
+                log.attachments as _,
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            sqlx::query!(
+                "UPDATE idempotency_keys SET log_id = $2 WHERE key = $1",
+                key,
+                created_log.id,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+
+            Ok((created_log, true))
+        })
+        .await
     }
 }
@@ -1,5 +1,14 @@
+pub mod api_key_repository;
+pub mod audit_repository;
+pub mod ingest_webhook_repository;
 pub mod log_repository;
 pub mod schema_repository;
+pub mod slow_query;
+pub mod webhook_repository;
 
+pub use api_key_repository::ApiKeyRepository;
+pub use audit_repository::AuditRepository;
+pub use ingest_webhook_repository::IngestWebhookRepository;
 pub use log_repository::LogRepository;
 pub use schema_repository::SchemaRepository;
+pub use webhook_repository::WebhookRepository;
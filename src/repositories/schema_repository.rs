@@ -1,24 +1,135 @@
 use crate::error::AppResult;
-use crate::models::Schema;
+use crate::models::{Schema, ValidationMode};
+use crate::repositories::slow_query::timed;
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use futures_util::stream::{BoxStream, StreamExt};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 
+/// `change_type` of the `schema_history` rows written by [`SchemaRepositoryTrait::update`]
+/// and [`SchemaRepositoryTrait::delete`].
+pub const CHANGE_TYPE_UPDATE: &str = "update";
+pub const CHANGE_TYPE_DELETE: &str = "delete";
+
+/// Rows fetched ahead of a slow consumer of [`SchemaRepositoryTrait::stream_all`].
+const STREAM_BUFFER_ROWS: usize = 64;
+
 #[derive(Debug, Clone, Default)]
 pub struct SchemaQueryParams {
     pub name: Option<String>,
     pub version: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// Only schemas with a greater `seq`, in `seq` order
+    pub after_seq: Option<i64>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct SchemaWithLogCount {
+    #[sqlx(flatten)]
+    pub schema: Schema,
+    pub log_count: i64,
+}
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const GET_ALL: &str = "schemas.get_all";
+    pub const GET_AFTER_SEQ: &str = "schemas.get_after_seq";
+    pub const GET_ALL_WITH_COUNTS: &str = "schemas.get_all_with_counts";
+    pub const COUNT: &str = "schemas.count";
+    pub const SEARCH: &str = "schemas.search";
+    pub const COUNT_SEARCH: &str = "schemas.count_search";
+    pub const GET_BY_ID: &str = "schemas.get_by_id";
+    pub const GET_BY_IDS: &str = "schemas.get_by_ids";
+    pub const GET_BY_NAME_AND_VERSION: &str = "schemas.get_by_name_and_version";
+    pub const GET_ALL_VERSIONS_BY_NAME: &str = "schemas.get_all_versions_by_name";
+    pub const GET_VERSIONS_SORTED_BY_SEMVER: &str = "schemas.get_versions_sorted_by_semver";
+    pub const GET_BY_NAME_CONTAINING: &str = "schemas.get_by_name_containing";
+    pub const GET_LATEST_VERSION_BY_NAME: &str = "schemas.get_latest_version_by_name";
+    pub const CREATE: &str = "schemas.create";
+    pub const UPDATE_WITH_HISTORY: &str = "schemas.update_with_history";
+    pub const DELETE_WITH_HISTORY: &str = "schemas.delete_with_history";
+    pub const SET_DEPRECATED: &str = "schemas.set_deprecated";
+    pub const SET_TAGS: &str = "schemas.set_tags";
+    pub const SET_DEFINITION: &str = "schemas.set_definition";
 }
 
 #[async_trait]
-pub trait SchemaRepositoryTrait {
+pub trait SchemaRepositoryTrait: Send + Sync {
     async fn get_all(&self, params: Option<SchemaQueryParams>) -> AppResult<Vec<Schema>>;
+    /// Like `get_all`, yielding schemas as they are read from the database cursor.
+    fn stream_all(&self, params: SchemaQueryParams) -> BoxStream<'static, AppResult<Schema>>;
+    /// Up to `limit` schemas with `seq` greater than `after_seq`, lowest `seq` first.
+    async fn get_after_seq(&self, after_seq: i64, limit: i64) -> AppResult<Vec<Schema>>;
+    async fn count(&self, params: &SchemaQueryParams) -> AppResult<i64>;
+    async fn get_all_with_counts(
+        &self,
+        params: Option<SchemaQueryParams>,
+    ) -> AppResult<Vec<SchemaWithLogCount>>;
+    /// Full-text search over name and description, best matches first.
+    async fn search(&self, query: &str, limit: i64, offset: i64) -> AppResult<Vec<Schema>>;
+    async fn count_search(&self, query: &str) -> AppResult<i64>;
     async fn get_by_id(&self, id: Uuid) -> AppResult<Option<Schema>>;
+    /// The existing schemas among `ids`, keyed by id.
+    async fn get_by_ids(&self, ids: &[Uuid]) -> AppResult<HashMap<Uuid, Schema>>;
     async fn get_by_name_and_version(&self, name: &str, version: &str)
         -> AppResult<Option<Schema>>;
+    /// Every version of the schema named `name`, highest version first.
+    async fn get_all_versions_by_name(&self, name: &str) -> AppResult<Vec<Schema>>;
+    /// Every version of the schema named `name`, highest semver first, followed by the
+    /// versions that are not valid semver in descending lexicographic order.
+    async fn get_versions_sorted_by_semver(&self, name: &str) -> AppResult<Vec<Schema>>;
+    /// Schemas whose name contains `substring`, ignoring case, ordered by name.
+    async fn get_by_name_containing(&self, substring: &str) -> AppResult<Vec<Schema>>;
+    /// Highest version of the schema named `name`.
+    async fn get_latest_version_by_name(&self, name: &str) -> AppResult<Option<Schema>>;
     async fn create(&self, schema: &Schema) -> AppResult<Schema>;
-    async fn update(&self, id: Uuid, schema: &Schema) -> AppResult<Option<Schema>>;
+    /// Same as `update_with_history` with change type `update`.
+    async fn update(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>>;
+    /// Records the schema's previous state in `schema_history` and updates it, in one
+    /// transaction. With `expected_updated_at`, only a schema last updated at exactly
+    /// that time is changed; `None` is returned otherwise.
+    async fn update_with_history(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        change_type: &str,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>>;
+    /// Sets the deprecation flag and timestamp and bumps `updated_at`.
+    async fn set_deprecated(
+        &self,
+        id: Uuid,
+        deprecated: bool,
+        deprecated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>>;
+    /// Replaces the tags and bumps `updated_at`. Nothing is written to `schema_history`,
+    /// which does not snapshot tags; tag changes are recorded in `schema_audit_log`.
+    async fn set_tags(&self, id: Uuid, tags: &[String]) -> AppResult<Option<Schema>>;
+    /// Replaces only the definition and `updated_at`, recording the previous state in
+    /// `schema_history` like `update`.
+    async fn set_definition(
+        &self,
+        id: Uuid,
+        schema_definition: &Value,
+        updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Schema>>;
+    /// Same as `delete_with_history` with change type `delete`.
     async fn delete(&self, id: Uuid) -> AppResult<bool>;
+    /// Records the schema's last state in `schema_history` and deletes it, in one
+    /// transaction.
+    async fn delete_with_history(&self, id: Uuid, change_type: &str) -> AppResult<bool>;
 }
 
 #[derive(Clone)]
@@ -35,61 +146,263 @@ impl SchemaRepository {
 #[async_trait]
 impl SchemaRepositoryTrait for SchemaRepository {
     async fn get_all(&self, params: Option<SchemaQueryParams>) -> AppResult<Vec<Schema>> {
-        let query_params = params.unwrap_or_default();
-
-        match (&query_params.name, &query_params.version) {
-            (Some(name), Some(version)) => {
-                tracing::debug!(
-                    "Querying schemas with name={} AND version={}",
-                    name,
-                    version
-                );
-                let schemas = sqlx::query_as::<_, Schema>(
-                    "SELECT * FROM schemas WHERE name = $1 AND version = $2 ORDER BY created_at DESC"
-                )
-                .bind(name)
-                .bind(version)
-                .fetch_all(&self.pool)
-                .await?;
-                Ok(schemas)
-            }
-            (Some(name), None) => {
-                tracing::debug!("Querying schemas with name={}", name);
-                let schemas = sqlx::query_as::<_, Schema>(
-                    "SELECT * FROM schemas WHERE name = $1 ORDER BY created_at DESC",
-                )
-                .bind(name)
-                .fetch_all(&self.pool)
-                .await?;
-                Ok(schemas)
-            }
-            (None, Some(version)) => {
-                tracing::debug!("Querying schemas with version={}", version);
-                let schemas = sqlx::query_as::<_, Schema>(
-                    "SELECT * FROM schemas WHERE version = $1 ORDER BY created_at DESC",
-                )
-                .bind(version)
-                .fetch_all(&self.pool)
-                .await?;
-                Ok(schemas)
-            }
-            (None, None) => {
-                tracing::debug!("Querying all schemas");
-                let schemas =
-                    sqlx::query_as::<_, Schema>("SELECT * FROM schemas ORDER BY created_at DESC")
-                        .fetch_all(&self.pool)
-                        .await?;
-                Ok(schemas)
+        timed(query_name::GET_ALL, async {
+            let query_params = params.unwrap_or_default();
+
+            tracing::debug!(
+                "Querying schemas with name={:?} version={:?} limit={:?} offset={:?}",
+                query_params.name,
+                query_params.version,
+                query_params.limit,
+                query_params.offset
+            );
+
+            // A NULL filter matches everything; LIMIT NULL means no limit
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE ($1::text IS NULL OR name = $1) AND ($2::text IS NULL OR version = $2)
+                ORDER BY created_at DESC
+                LIMIT $3 OFFSET $4
+                "#,
+                query_params.name,
+                query_params.version,
+                query_params.limit,
+                query_params.offset.unwrap_or(0),
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    async fn get_after_seq(&self, after_seq: i64, limit: i64) -> AppResult<Vec<Schema>> {
+        timed(query_name::GET_AFTER_SEQ, async {
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE seq > $1
+                ORDER BY seq
+                LIMIT $2
+                "#,
+                after_seq,
+                limit,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    // Not timed: how long the stream stays open depends on how fast the client reads
+    fn stream_all(&self, params: SchemaQueryParams) -> BoxStream<'static, AppResult<Schema>> {
+        let pool = self.pool.clone();
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_ROWS);
+
+        // The cursor borrows the pool, so it is driven by its own task
+        tokio::spawn(async move {
+            let mut rows = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE ($1::text IS NULL OR name = $1) AND ($2::text IS NULL OR version = $2)
+                ORDER BY created_at DESC
+                LIMIT $3 OFFSET $4
+                "#,
+                params.name,
+                params.version,
+                params.limit,
+                params.offset.unwrap_or(0),
+            )
+            .fetch(&pool);
+
+            while let Some(row) = rows.next().await {
+                // Stop reading once the receiver is gone, e.g. the client disconnected
+                if tx.send(row.map_err(Into::into)).await.is_err() {
+                    break;
+                }
             }
-        }
+        });
+
+        ReceiverStream::new(rx).boxed()
+    }
+
+    async fn get_all_with_counts(
+        &self,
+        params: Option<SchemaQueryParams>,
+    ) -> AppResult<Vec<SchemaWithLogCount>> {
+        timed(query_name::GET_ALL_WITH_COUNTS, async {
+            let query_params = params.unwrap_or_default();
+
+            let rows = sqlx::query!(
+                r#"
+                SELECT s.id, s.seq, s.name, s.version, s.description, s.schema_definition, s.deprecated,
+                       s.deprecated_at, s.tags,
+                       s.validation_mode AS "validation_mode: ValidationMode",
+                       s.created_at AS "created_at!", s.updated_at AS "updated_at!",
+                       COUNT(l.id) AS "log_count!"
+                FROM schemas s
+                LEFT JOIN logs l ON l.schema_id = s.id
+                WHERE ($1::text IS NULL OR s.name = $1) AND ($2::text IS NULL OR s.version = $2)
+                GROUP BY s.id
+                ORDER BY s.created_at DESC
+                LIMIT $3 OFFSET $4
+                "#,
+                query_params.name,
+                query_params.version,
+                query_params.limit,
+                query_params.offset.unwrap_or(0),
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| SchemaWithLogCount {
+                    schema: Schema {
+                        id: row.id,
+                        seq: row.seq,
+                        name: row.name,
+                        version: row.version,
+                        description: row.description,
+                        schema_definition: row.schema_definition,
+                        deprecated: row.deprecated,
+                        deprecated_at: row.deprecated_at,
+                        tags: row.tags,
+                        validation_mode: row.validation_mode,
+                        created_at: row.created_at,
+                        updated_at: row.updated_at,
+                    },
+                    log_count: row.log_count,
+                })
+                .collect())
+        })
+        .await
+    }
+
+    async fn count(&self, params: &SchemaQueryParams) -> AppResult<i64> {
+        timed(query_name::COUNT, async {
+            let count = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!" FROM schemas
+                WHERE ($1::text IS NULL OR name = $1) AND ($2::text IS NULL OR version = $2)
+                "#,
+                params.name,
+                params.version,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(count)
+        })
+        .await
+    }
+
+    // The tsvector expression must match idx_schemas_search for the index to be used
+    async fn search(&self, query: &str, limit: i64, offset: i64) -> AppResult<Vec<Schema>> {
+        timed(query_name::SEARCH, async {
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE to_tsvector('english', name || ' ' || COALESCE(description, ''))
+                      @@ plainto_tsquery('english', $1)
+                ORDER BY ts_rank(
+                             to_tsvector('english', name || ' ' || COALESCE(description, '')),
+                             plainto_tsquery('english', $1)
+                         ) DESC,
+                         created_at DESC
+                LIMIT $2 OFFSET $3
+                "#,
+                query,
+                limit,
+                offset,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    async fn count_search(&self, query: &str) -> AppResult<i64> {
+        timed(query_name::COUNT_SEARCH, async {
+            let count = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!" FROM schemas
+                WHERE to_tsvector('english', name || ' ' || COALESCE(description, ''))
+                      @@ plainto_tsquery('english', $1)
+                "#,
+                query,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(count)
+        })
+        .await
     }
 
     async fn get_by_id(&self, id: Uuid) -> AppResult<Option<Schema>> {
-        let schema = sqlx::query_as::<_, Schema>("SELECT * FROM schemas WHERE id = $1")
-            .bind(id)
+        timed(query_name::GET_BY_ID, async {
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE id = $1
+                "#,
+                id,
+            )
             .fetch_optional(&self.pool)
             .await?;
-        Ok(schema)
+            Ok(schema)
+        })
+        .await
+    }
+
+    async fn get_by_ids(&self, ids: &[Uuid]) -> AppResult<HashMap<Uuid, Schema>> {
+        timed(query_name::GET_BY_IDS, async {
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE id = ANY($1::uuid[])
+                "#,
+                ids,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+            Ok(schemas
+                .into_iter()
+                .map(|schema| (schema.id, schema))
+                .collect())
+        })
+        .await
     }
 
     async fn get_by_name_and_version(
@@ -97,63 +410,367 @@ impl SchemaRepositoryTrait for SchemaRepository {
         name: &str,
         version: &str,
     ) -> AppResult<Option<Schema>> {
-        let schema =
-            sqlx::query_as::<_, Schema>("SELECT * FROM schemas WHERE name = $1 AND version = $2")
-                .bind(name)
-                .bind(version)
-                .fetch_optional(&self.pool)
-                .await?;
-        Ok(schema)
+        timed(query_name::GET_BY_NAME_AND_VERSION, async {
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE name = $1 AND version = $2
+                "#,
+                name,
+                version,
+            )
+            .fetch_optional(&self.pool)
+            .await?;
+            Ok(schema)
+        })
+        .await
+    }
+
+    async fn get_all_versions_by_name(&self, name: &str) -> AppResult<Vec<Schema>> {
+        timed(query_name::GET_ALL_VERSIONS_BY_NAME, async {
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE name = $1
+                -- Numerically by the `major.minor.patch` core (so 1.10.0 sorts above 1.9.0),
+                -- releases above their pre-releases, then lexicographically for versions
+                -- without a numeric core
+                ORDER BY string_to_array(substring(version from '^[0-9]+(?:\.[0-9]+)*'), '.')::numeric[] DESC NULLS LAST,
+                         position('-' in version) = 0 DESC,
+                         version DESC
+                "#,
+                name,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    // Sorted here rather than in SQL so that pre-release and build identifiers follow
+    // semver precedence exactly
+    async fn get_versions_sorted_by_semver(&self, name: &str) -> AppResult<Vec<Schema>> {
+        timed(query_name::GET_VERSIONS_SORTED_BY_SEMVER, async {
+            let mut schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE name = $1
+                "#,
+                name,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            // `None` sorts below any parsed version, so non-semver versions come last
+            schemas.sort_by_cached_key(|schema| {
+                Reverse((
+                    semver::Version::parse(&schema.version).ok(),
+                    schema.version.clone(),
+                ))
+            });
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    async fn get_by_name_containing(&self, substring: &str) -> AppResult<Vec<Schema>> {
+        timed(query_name::GET_BY_NAME_CONTAINING, async {
+            // `%` and `_` in the substring match literally
+            let pattern = substring
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+
+            let schemas = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE name ILIKE '%' || $1 || '%'
+                ORDER BY name, created_at DESC
+                "#,
+                pattern,
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(schemas)
+        })
+        .await
+    }
+
+    async fn get_latest_version_by_name(&self, name: &str) -> AppResult<Option<Schema>> {
+        timed(query_name::GET_LATEST_VERSION_BY_NAME, async {
+            // Same order as `get_all_versions_by_name`
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                SELECT id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                       validation_mode AS "validation_mode: ValidationMode",
+                       created_at AS "created_at!", updated_at AS "updated_at!"
+                FROM schemas
+                WHERE name = $1
+                ORDER BY string_to_array(substring(version from '^[0-9]+(?:\.[0-9]+)*'), '.')::numeric[] DESC NULLS LAST,
+                         position('-' in version) = 0 DESC,
+                         version DESC
+                LIMIT 1
+                "#,
+                name,
+            )
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(schema)
+        })
+        .await
     }
 
     async fn create(&self, schema: &Schema) -> AppResult<Schema> {
-        let created_schema = sqlx::query_as::<_, Schema>(
-            r#"
-            INSERT INTO schemas (id, name, version, description, schema_definition, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING *
-            "#
-        )
-        .bind(schema.id)
-        .bind(&schema.name)
-        .bind(&schema.version)
-        .bind(&schema.description)
-        .bind(&schema.schema_definition)
-        .bind(schema.created_at)
-        .bind(schema.updated_at)
-        .fetch_one(&self.pool)
-        .await?;
-
-        Ok(created_schema)
-    }
-
-    async fn update(&self, id: Uuid, schema: &Schema) -> AppResult<Option<Schema>> {
-        let updated_schema = sqlx::query_as::<_, Schema>(
-            r#"
-            UPDATE schemas 
-            SET name = $2, version = $3, description = $4, schema_definition = $5, updated_at = $6
-            WHERE id = $1
-            RETURNING *
-            "#,
-        )
-        .bind(id)
-        .bind(&schema.name)
-        .bind(&schema.version)
-        .bind(&schema.description)
-        .bind(&schema.schema_definition)
-        .bind(schema.updated_at)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        Ok(updated_schema)
+        timed(query_name::CREATE, async {
+            let created_schema = sqlx::query_as!(
+                Schema,
+                r#"
+                INSERT INTO schemas (id, name, version, description, schema_definition, validation_mode, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                          validation_mode AS "validation_mode: ValidationMode",
+                          created_at AS "created_at!", updated_at AS "updated_at!"
+                "#,
+                schema.id,
+                schema.name,
+                schema.version,
+                schema.description,
+                schema.schema_definition,
+                schema.validation_mode as _,
+                schema.created_at,
+                schema.updated_at,
+            )
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(created_schema)
+        })
+        .await
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        self.update_with_history(id, schema, CHANGE_TYPE_UPDATE, expected_updated_at)
+            .await
+    }
+
+    async fn update_with_history(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        change_type: &str,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        timed(query_name::UPDATE_WITH_HISTORY, async {
+            let mut tx = self.pool.begin().await?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO schema_history (schema_id, name, version, description, schema_definition, change_type)
+                SELECT id, name, version, description, schema_definition, $2
+                FROM schemas
+                WHERE id = $1
+                FOR UPDATE
+                "#,
+                id,
+                change_type,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            let updated_schema = sqlx::query_as!(
+                Schema,
+                r#"
+                UPDATE schemas
+                SET name = $2, version = $3, description = $4, schema_definition = $5, validation_mode = $6, updated_at = $7
+                WHERE id = $1 AND ($8::timestamptz IS NULL OR updated_at = $8)
+                RETURNING id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                          validation_mode AS "validation_mode: ValidationMode",
+                          created_at AS "created_at!", updated_at AS "updated_at!"
+                "#,
+                id,
+                schema.name,
+                schema.version,
+                schema.description,
+                schema.schema_definition,
+                schema.validation_mode as _,
+                schema.updated_at,
+                expected_updated_at,
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            // Nothing to record when the schema does not exist or was modified since
+            // `expected_updated_at`; dropping rolls back
+            if updated_schema.is_some() {
+                tx.commit().await?;
+            }
+
+            Ok(updated_schema)
+        })
+        .await
+    }
+
+    async fn set_deprecated(
+        &self,
+        id: Uuid,
+        deprecated: bool,
+        deprecated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        timed(query_name::SET_DEPRECATED, async {
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                UPDATE schemas
+                SET deprecated = $2, deprecated_at = $3, updated_at = NOW()
+                WHERE id = $1
+                RETURNING id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                          validation_mode AS "validation_mode: ValidationMode",
+                          created_at AS "created_at!", updated_at AS "updated_at!"
+                "#,
+                id,
+                deprecated,
+                deprecated_at,
+            )
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(schema)
+        })
+        .await
+    }
+
+    async fn set_tags(&self, id: Uuid, tags: &[String]) -> AppResult<Option<Schema>> {
+        timed(query_name::SET_TAGS, async {
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                UPDATE schemas
+                SET tags = $2, updated_at = NOW()
+                WHERE id = $1
+                RETURNING id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                          validation_mode AS "validation_mode: ValidationMode",
+                          created_at AS "created_at!", updated_at AS "updated_at!"
+                "#,
+                id,
+                tags,
+            )
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(schema)
+        })
+        .await
+    }
+
+    async fn set_definition(
+        &self,
+        id: Uuid,
+        schema_definition: &Value,
+        updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Schema>> {
+        timed(query_name::SET_DEFINITION, async {
+            let mut tx = self.pool.begin().await?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO schema_history (schema_id, name, version, description, schema_definition, change_type)
+                SELECT id, name, version, description, schema_definition, $2
+                FROM schemas
+                WHERE id = $1
+                FOR UPDATE
+                "#,
+                id,
+                CHANGE_TYPE_UPDATE,
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            let schema = sqlx::query_as!(
+                Schema,
+                r#"
+                UPDATE schemas
+                SET schema_definition = $2, updated_at = $3
+                WHERE id = $1
+                RETURNING id, seq, name, version, description, schema_definition, deprecated, deprecated_at, tags,
+                          validation_mode AS "validation_mode: ValidationMode",
+                          created_at AS "created_at!", updated_at AS "updated_at!"
+                "#,
+                id,
+                schema_definition,
+                updated_at,
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            if schema.is_some() {
+                tx.commit().await?;
+            }
+
+            Ok(schema)
+        })
+        .await
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<bool> {
-        let result = sqlx::query("DELETE FROM schemas WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
+        self.delete_with_history(id, CHANGE_TYPE_DELETE).await
+    }
+
+    async fn delete_with_history(&self, id: Uuid, change_type: &str) -> AppResult<bool> {
+        timed(query_name::DELETE_WITH_HISTORY, async {
+            let mut tx = self.pool.begin().await?;
+
+            sqlx::query!(
+                r#"
+                INSERT INTO schema_history (schema_id, name, version, description, schema_definition, change_type)
+                SELECT id, name, version, description, schema_definition, $2
+                FROM schemas
+                WHERE id = $1
+                FOR UPDATE
+                "#,
+                id,
+                change_type,
+            )
+            .execute(&mut *tx)
             .await?;
 
-        Ok(result.rows_affected() > 0)
+            let result = sqlx::query!("DELETE FROM schemas WHERE id = $1", id)
+                .execute(&mut *tx)
+                .await?;
+
+            let deleted = result.rows_affected() > 0;
+            if deleted {
+                tx.commit().await?;
+            }
+
+            Ok(deleted)
+        })
+        .await
     }
 }
@@ -0,0 +1,36 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::error::AppResult;
+
+pub const SLOW_QUERY_THRESHOLD_MS_ENV: &str = "SLOW_QUERY_THRESHOLD_MS";
+pub const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 500;
+
+static THRESHOLD_MS: AtomicU64 = AtomicU64::new(DEFAULT_SLOW_QUERY_THRESHOLD_MS);
+
+/// Sets the duration above which repository queries are logged as slow.
+pub fn set_threshold(threshold: Duration) {
+    THRESHOLD_MS.store(threshold.as_millis() as u64, Ordering::Relaxed);
+}
+
+pub fn threshold() -> Duration {
+    Duration::from_millis(THRESHOLD_MS.load(Ordering::Relaxed))
+}
+
+/// Runs a repository query and warns when it takes longer than the threshold. The
+/// warning is emitted inside the caller's span, so it carries the `request_id`.
+pub async fn timed<T, F>(query_name: &'static str, query: F) -> AppResult<T>
+where
+    F: Future<Output = AppResult<T>>,
+{
+    let start = Instant::now();
+    let result = query.await;
+    let elapsed = start.elapsed();
+
+    if elapsed > threshold() {
+        tracing::warn!("Slow query: {} took {:?}", query_name, elapsed);
+    }
+
+    result
+}
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::Webhook;
+use crate::repositories::slow_query::timed;
+
+/// Names reported in slow query warnings
+mod query_name {
+    pub const GET_ALL: &str = "webhooks.get_all";
+    pub const GET_BY_ID: &str = "webhooks.get_by_id";
+    pub const GET_ACTIVE_FOR_EVENT: &str = "webhooks.get_active_for_event";
+    pub const CREATE: &str = "webhooks.create";
+    pub const UPDATE: &str = "webhooks.update";
+    pub const DELETE: &str = "webhooks.delete";
+}
+
+#[async_trait]
+pub trait WebhookRepositoryTrait {
+    async fn get_all(&self) -> AppResult<Vec<Webhook>>;
+    async fn get_by_id(&self, id: Uuid) -> AppResult<Option<Webhook>>;
+    async fn get_active_for_event(&self, event: &str) -> AppResult<Vec<Webhook>>;
+    async fn create(&self, webhook: &Webhook) -> AppResult<Webhook>;
+    async fn update(&self, id: Uuid, webhook: &Webhook) -> AppResult<Option<Webhook>>;
+    async fn delete(&self, id: Uuid) -> AppResult<bool>;
+}
+
+#[derive(Clone)]
+pub struct WebhookRepository {
+    pool: PgPool,
+}
+
+impl WebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepositoryTrait for WebhookRepository {
+    async fn get_all(&self) -> AppResult<Vec<Webhook>> {
+        timed(query_name::GET_ALL, async {
+            let webhooks =
+                sqlx::query_as::<_, Webhook>("SELECT * FROM webhooks ORDER BY created_at DESC")
+                    .fetch_all(&self.pool)
+                    .await?;
+
+            Ok(webhooks)
+        })
+        .await
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> AppResult<Option<Webhook>> {
+        timed(query_name::GET_BY_ID, async {
+            let webhook = sqlx::query_as::<_, Webhook>("SELECT * FROM webhooks WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+            Ok(webhook)
+        })
+        .await
+    }
+
+    async fn get_active_for_event(&self, event: &str) -> AppResult<Vec<Webhook>> {
+        timed(query_name::GET_ACTIVE_FOR_EVENT, async {
+            let webhooks = sqlx::query_as::<_, Webhook>(
+                "SELECT * FROM webhooks WHERE active = TRUE AND events @> ARRAY[$1]",
+            )
+            .bind(event)
+            .fetch_all(&self.pool)
+            .await?;
+
+            Ok(webhooks)
+        })
+        .await
+    }
+
+    async fn create(&self, webhook: &Webhook) -> AppResult<Webhook> {
+        timed(query_name::CREATE, async {
+            let created = sqlx::query_as::<_, Webhook>(
+                r#"
+                INSERT INTO webhooks (id, url, events, secret, active, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+                "#,
+            )
+            .bind(webhook.id)
+            .bind(&webhook.url)
+            .bind(&webhook.events)
+            .bind(&webhook.secret)
+            .bind(webhook.active)
+            .bind(webhook.created_at)
+            .fetch_one(&self.pool)
+            .await?;
+
+            Ok(created)
+        })
+        .await
+    }
+
+    async fn update(&self, id: Uuid, webhook: &Webhook) -> AppResult<Option<Webhook>> {
+        timed(query_name::UPDATE, async {
+            let updated = sqlx::query_as::<_, Webhook>(
+                r#"
+                UPDATE webhooks
+                SET url = $2, events = $3, secret = $4, active = $5
+                WHERE id = $1
+                RETURNING *
+                "#,
+            )
+            .bind(id)
+            .bind(&webhook.url)
+            .bind(&webhook.events)
+            .bind(&webhook.secret)
+            .bind(webhook.active)
+            .fetch_optional(&self.pool)
+            .await?;
+
+            Ok(updated)
+        })
+        .await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        timed(query_name::DELETE, async {
+            let result = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+                .bind(id)
+                .execute(&self.pool)
+                .await?;
+
+            Ok(result.rows_affected() > 0)
+        })
+        .await
+    }
+}
@@ -0,0 +1,79 @@
+use crate::error::AppResult;
+use crate::models::{AuditContext, AuditEvent, AuditLog};
+use crate::repositories::audit_repository::AuditRepositoryTrait;
+use chrono::Utc;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+#[derive(Clone)]
+pub struct AuditService {
+    repository: Arc<dyn AuditRepositoryTrait>,
+}
+
+impl AuditService {
+    pub fn new(repository: Arc<dyn AuditRepositoryTrait>) -> Self {
+        Self { repository }
+    }
+
+    /// Records a mutation. Failures are logged rather than returned because the
+    /// audited operation has already been committed by the time this runs.
+    pub async fn record(
+        &self,
+        ctx: &AuditContext,
+        entity_type: &str,
+        entity_id: String,
+        operation: &str,
+        old_data: Option<Value>,
+        new_data: Option<Value>,
+    ) {
+        let entry = AuditLog {
+            id: 0, // This will be set by the database
+            entity_type: entity_type.to_string(),
+            entity_id,
+            operation: operation.to_string(),
+            actor: ctx.actor.clone(),
+            old_data,
+            new_data,
+            request_id: ctx.request_id.clone(),
+            ip_address: ctx.ip_address.clone(),
+            occurred_at: Utc::now(),
+        };
+
+        if let Err(e) = self.repository.record(&entry).await {
+            tracing::error!(
+                "Failed to record audit entry for {} {} ({}): {}",
+                entry.entity_type,
+                entry.entity_id,
+                entry.operation,
+                e
+            );
+        }
+    }
+
+    /// Writes schema audit events to `schema_audit_log` until every sender is dropped.
+    /// Like `record`, failures are logged and the event is dropped.
+    pub async fn write_schema_events(self: Arc<Self>, mut events: mpsc::Receiver<AuditEvent>) {
+        while let Some(event) = events.recv().await {
+            if let Err(e) = self.repository.record_schema_event(&event).await {
+                tracing::error!(
+                    "Failed to record schema audit event {} for {:?}: {}",
+                    event.operation,
+                    event.schema_id,
+                    e
+                );
+            }
+        }
+    }
+
+    pub async fn get_entries(
+        &self,
+        entity_type: Option<&str>,
+        entity_id: Option<&str>,
+        limit: i64,
+    ) -> AppResult<Vec<AuditLog>> {
+        self.repository
+            .get_by_entity(entity_type, entity_id, limit)
+            .await
+    }
+}
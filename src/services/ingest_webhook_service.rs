@@ -0,0 +1,154 @@
+use chrono::Utc;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::IngestWebhook;
+use crate::repositories::ingest_webhook_repository::{
+    IngestWebhookRepository, IngestWebhookRepositoryTrait,
+};
+use crate::repositories::schema_repository::{SchemaRepository, SchemaRepositoryTrait};
+
+#[derive(Clone)]
+pub struct IngestWebhookService {
+    repository: Arc<IngestWebhookRepository>,
+    schema_repository: Arc<SchemaRepository>,
+}
+
+impl IngestWebhookService {
+    pub fn new(
+        repository: Arc<IngestWebhookRepository>,
+        schema_repository: Arc<SchemaRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            schema_repository,
+        }
+    }
+
+    pub async fn get_ingest_webhooks(&self) -> AppResult<Vec<IngestWebhook>> {
+        self.repository.get_all().await
+    }
+
+    pub async fn get_ingest_webhook(&self, id: Uuid) -> AppResult<Option<IngestWebhook>> {
+        self.repository.get_by_id(id).await
+    }
+
+    /// Registers a webhook for `schema_id` with a newly generated token.
+    pub async fn create_ingest_webhook(
+        &self,
+        schema_id: Uuid,
+        transform: Option<Value>,
+    ) -> AppResult<IngestWebhook> {
+        self.validate_ingest_webhook(schema_id, transform.as_ref())
+            .await?;
+
+        let webhook = IngestWebhook {
+            id: Uuid::new_v4(),
+            token: generate_token(),
+            schema_id,
+            transform,
+            created_at: Utc::now(),
+        };
+
+        self.repository.create(&webhook).await
+    }
+
+    /// Replaces the schema and transform; the token stays the same.
+    pub async fn update_ingest_webhook(
+        &self,
+        id: Uuid,
+        schema_id: Uuid,
+        transform: Option<Value>,
+    ) -> AppResult<Option<IngestWebhook>> {
+        let existing = match self.repository.get_by_id(id).await? {
+            Some(webhook) => webhook,
+            None => return Ok(None),
+        };
+
+        self.validate_ingest_webhook(schema_id, transform.as_ref())
+            .await?;
+
+        let webhook = IngestWebhook {
+            schema_id,
+            transform,
+            ..existing
+        };
+
+        self.repository.update(id, &webhook).await
+    }
+
+    pub async fn delete_ingest_webhook(&self, id: Uuid) -> AppResult<bool> {
+        self.repository.delete(id).await
+    }
+
+    /// Resolves `token` and applies its transform to `body`, returning the schema to
+    /// validate against and the log data. `None` when no webhook has the token.
+    pub async fn prepare_log(&self, token: &str, body: Value) -> AppResult<Option<(Uuid, Value)>> {
+        let Some(webhook) = self.repository.get_by_token(token).await? else {
+            return Ok(None);
+        };
+
+        let log_data = match &webhook.transform {
+            Some(transform) => apply_transform(transform, &body),
+            None => body,
+        };
+
+        Ok(Some((webhook.schema_id, log_data)))
+    }
+
+    async fn validate_ingest_webhook(
+        &self,
+        schema_id: Uuid,
+        transform: Option<&Value>,
+    ) -> AppResult<()> {
+        if let Some(transform) = transform {
+            let is_valid = transform.as_object().is_some_and(|fields| {
+                fields.values().all(|pointer| {
+                    pointer
+                        .as_str()
+                        .is_some_and(|pointer| pointer.is_empty() || pointer.starts_with('/'))
+                })
+            });
+            if !is_valid {
+                return Err(AppError::ValidationError(
+                    "transform must be an object mapping field names to JSON Pointers such as \"/message\"".to_string(),
+                ));
+            }
+        }
+
+        if self.schema_repository.get_by_id(schema_id).await?.is_none() {
+            return Err(AppError::not_found_resource(
+                "Schema",
+                "id",
+                &schema_id.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds an object with each field of `transform` set to the value its JSON Pointer
+/// selects in `body`. Fields whose pointer matches nothing are left out.
+fn apply_transform(transform: &Value, body: &Value) -> Value {
+    let Some(fields) = transform.as_object() else {
+        return body.clone();
+    };
+
+    let log_data: Map<String, Value> = fields
+        .iter()
+        .filter_map(|(field, pointer)| {
+            let value = body.pointer(pointer.as_str()?)?;
+            Some((field.clone(), value.clone()))
+        })
+        .collect();
+
+    Value::Object(log_data)
+}
+
+/// 64 hex characters from two random UUIDs.
+fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
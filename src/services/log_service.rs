@@ -1,105 +1,1007 @@
+use crate::dto::{LogEvent, PaginationQuery, ValidationError};
 use crate::error::{AppError, AppResult};
-use crate::models::Log;
-use crate::repositories::log_repository::{LogRepository, LogRepositoryTrait};
-use crate::repositories::schema_repository::{SchemaRepository, SchemaRepositoryTrait};
-use chrono::Utc;
+use crate::models::audit_model::{
+    ENTITY_LOG, OPERATION_BULK_DELETE, OPERATION_CREATE, OPERATION_DELETE,
+};
+use crate::models::log_model::{
+    MAX_ATTACHMENTS, MAX_ATTACHMENT_NAME_LENGTH, MAX_SEVERITY, MIN_SEVERITY,
+};
+use crate::models::{AttachmentRef, AuditContext, Log, Schema, ValidationMode};
+use crate::repositories::log_repository::{
+    LogDeleteFilter, LogRepositoryTrait, LogSort, SeverityRange, TimeBucket, TimeBucketedCount,
+};
+use crate::repositories::schema_repository::{SchemaQueryParams, SchemaRepositoryTrait};
+use crate::services::{AuditService, SchemaCache};
+use crate::utils::remote_refs::is_private_ip;
+use chrono::{DateTime, Duration, Utc};
+use jsonschema::error::ValidationErrorKind;
+use reqwest::Url;
 use serde_json::Value;
+use sqlx::types::Json;
+use std::borrow::Cow;
+use std::collections::{hash_map::Entry, HashMap};
+use std::future::Future;
+use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::broadcast;
+use tracing::Instrument;
 use uuid::Uuid;
 
+/// Histogram of the schema lookup step of [`LogService::validate_and_create`], in seconds.
+pub const LOG_SCHEMA_LOOKUP_DURATION_SECONDS: &str = "log_schema_lookup_duration_seconds";
+
+/// Histogram of the validation step of [`LogService::validate_and_create`], in seconds.
+pub const LOG_VALIDATION_DURATION_SECONDS: &str = "log_validation_duration_seconds";
+
+/// Histogram of the database insert of [`LogService::validate_and_create`], in seconds.
+pub const LOG_DB_WRITE_DURATION_SECONDS: &str = "log_db_write_duration_seconds";
+
+/// Outcome of [`LogService::bulk_create_logs`].
+#[derive(Debug)]
+pub struct BulkCreateResult {
+    pub created: Vec<Log>,
+    pub failed: Vec<BulkCreateFailure>,
+}
+
+/// An entry of a bulk create that was not written; `index` is its position in the input.
+#[derive(Debug)]
+pub struct BulkCreateFailure {
+    pub index: usize,
+    pub schema_id: Uuid,
+    pub error: AppError,
+}
+
+/// Server-wide totals returned by [`LogService::get_log_stats_global`].
+#[derive(Debug)]
+pub struct GlobalLogStats {
+    pub total_logs: i64,
+    pub total_schemas: i64,
+    pub logs_last_hour: i64,
+    pub logs_last_24h: i64,
+    pub oldest_log: Option<DateTime<Utc>>,
+    pub newest_log: Option<DateTime<Utc>>,
+}
+
+pub const ENRICH_LOGS_ENV: &str = "ENRICH_LOGS";
+pub const SERVER_VERSION_ENV: &str = "SERVER_VERSION";
+pub const MAX_LOG_DATA_BYTES_ENV: &str = "MAX_LOG_DATA_BYTES";
+pub const DEFAULT_MAX_LOG_DATA_BYTES: usize = 64 * 1024;
+
+/// Longest accepted `source` of a log; matches the column size.
+pub const MAX_SOURCE_LENGTH: usize = 255;
+
+/// Longest accepted `correlation_id` of a log; matches the column size.
+pub const MAX_CORRELATION_ID_LENGTH: usize = 255;
+
+/// Optional details stored alongside a log's data.
+#[derive(Debug, Clone, Default)]
+pub struct LogMetadata {
+    pub source: Option<String>,
+    pub correlation_id: Option<String>,
+    pub severity: Option<i16>,
+    pub attachments: Option<Vec<AttachmentRef>>,
+}
+
+impl LogMetadata {
+    fn validate(&self) -> AppResult<()> {
+        LogService::validate_source(self.source.as_deref())?;
+        LogService::validate_attachments(self.attachments.as_deref())?;
+
+        if let Some(correlation_id) = &self.correlation_id {
+            if correlation_id.trim().is_empty() || correlation_id.len() > MAX_CORRELATION_ID_LENGTH
+            {
+                return Err(AppError::BadRequest(format!(
+                    "correlation_id must be 1 to {} characters",
+                    MAX_CORRELATION_ID_LENGTH
+                )));
+            }
+        }
+
+        if let Some(severity) = self.severity {
+            if !(MIN_SEVERITY..=MAX_SEVERITY).contains(&severity) {
+                return Err(AppError::BadRequest(format!(
+                    "severity must be between {} (TRACE) and {} (FATAL)",
+                    MIN_SEVERITY, MAX_SEVERITY
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Bulk deletions of more logs than this skip per-log `LogEvent::Deleted` broadcasts.
+const MAX_BROADCAST_DELETIONS: i64 = 100;
+
 #[derive(Clone)]
 pub struct LogService {
-    log_repository: Arc<LogRepository>,
-    schema_repository: Arc<SchemaRepository>,
+    log_repository: Arc<dyn LogRepositoryTrait>,
+    schema_repository: Arc<dyn SchemaRepositoryTrait>,
+    schema_cache: Arc<SchemaCache>,
+    audit_service: Arc<AuditService>,
+    log_broadcast: broadcast::Sender<LogEvent>,
+    enrich_logs: bool,
+    server_version: Option<String>,
+    max_log_data_bytes: usize,
 }
 
 impl LogService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        log_repository: Arc<LogRepository>,
-        schema_repository: Arc<SchemaRepository>,
+        log_repository: Arc<dyn LogRepositoryTrait>,
+        schema_repository: Arc<dyn SchemaRepositoryTrait>,
+        schema_cache: Arc<SchemaCache>,
+        audit_service: Arc<AuditService>,
+        log_broadcast: broadcast::Sender<LogEvent>,
+        enrich_logs: bool,
+        server_version: Option<String>,
+        max_log_data_bytes: usize,
     ) -> Self {
         Self {
             log_repository,
             schema_repository,
+            schema_cache,
+            audit_service,
+            log_broadcast,
+            enrich_logs,
+            server_version,
+            max_log_data_bytes,
         }
     }
 
+    /// Returns one page of the schema's logs, plus the total number of matching logs.
+    /// Both queries run concurrently.
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_logs_by_schema_name_and_id(
         &self,
         name: &str,
         version: &str,
         filters: Option<Value>,
-    ) -> AppResult<Vec<Log>> {
+        source: Option<&str>,
+        severity: &SeverityRange,
+        sort: &LogSort,
+        pagination: &PaginationQuery,
+    ) -> AppResult<(Vec<Log>, i64)> {
         let schema = self
             .schema_repository
             .get_by_name_and_version(name, version)
             .await?;
         if schema.is_none() {
-            return Err(AppError::NotFound(format!(
-                "Schema with name:version '{}:{}' not found",
-                name, version
-            )));
+            return Err(AppError::not_found_resource(
+                "Schema",
+                "name:version",
+                &format!("{}:{}", name, version),
+            ));
+        }
+
+        let schema_id = schema.unwrap().id;
+
+        tokio::try_join!(
+            self.log_repository.get_by_schema_id(
+                schema_id,
+                filters.clone(),
+                source,
+                severity,
+                sort,
+                Some(pagination.limit()),
+                pagination.offset(),
+            ),
+            self.log_repository.count_by_schema_id_with_filter(
+                schema_id,
+                filters.as_ref(),
+                source,
+                severity,
+            ),
+        )
+    }
+
+    /// Returns one page of the logs of every version of the schema, the versions that
+    /// were searched (lowest first) and the total number of matching logs.
+    pub async fn get_logs_across_versions(
+        &self,
+        name: &str,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        pagination: &PaginationQuery,
+    ) -> AppResult<(Vec<Log>, Vec<String>, i64)> {
+        let schemas = self
+            .schema_repository
+            .get_all_versions_by_name(name)
+            .await?;
+        if schemas.is_empty() {
+            return Err(AppError::not_found_resource("Schema", "name", name));
         }
 
+        let schema_ids: Vec<Uuid> = schemas.iter().map(|schema| schema.id).collect();
+        let (logs, total) = tokio::try_join!(
+            self.log_repository.get_by_schema_ids(
+                &schema_ids,
+                from,
+                to,
+                pagination.limit(),
+                pagination.offset(),
+            ),
+            self.log_repository
+                .count_by_schema_ids(&schema_ids, from, to),
+        )?;
+
+        let versions = schemas
+            .into_iter()
+            .rev()
+            .map(|schema| schema.version)
+            .collect();
+
+        Ok((logs, versions, total))
+    }
+
+    /// Historical logs of a schema for a WebSocket replay, oldest first.
+    pub async fn get_logs_for_replay(
+        &self,
+        schema_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> AppResult<Vec<Log>> {
+        self.log_repository
+            .get_for_replay(schema_id, from, limit)
+            .await
+    }
+
+    /// Counts the logs of a schema version per time bucket between `from` and `to`.
+    pub async fn get_logs_grouped_by_time(
+        &self,
+        name: &str,
+        version: &str,
+        bucket: TimeBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> AppResult<Vec<TimeBucketedCount>> {
+        let schema = self
+            .schema_repository
+            .get_by_name_and_version(name, version)
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found_resource(
+                    "Schema",
+                    "name:version",
+                    &format!("{}:{}", name, version),
+                )
+            })?;
+
         self.log_repository
-            .get_by_schema_id(schema.unwrap().id, filters)
+            .get_grouped_by_time(schema.id, bucket, from, to)
             .await
     }
 
+    /// Counts logs and schemas across the whole server, running the queries concurrently.
+    pub async fn get_log_stats_global(&self) -> AppResult<GlobalLogStats> {
+        let now = Utc::now();
+        let schema_params = SchemaQueryParams::default();
+
+        let (total_logs, total_schemas, logs_last_hour, logs_last_24h, (oldest_log, newest_log)) =
+            tokio::try_join!(
+                self.log_repository.count_all(),
+                self.schema_repository.count(&schema_params),
+                self.log_repository.count_since(now - Duration::hours(1)),
+                self.log_repository.count_since(now - Duration::hours(24)),
+                self.log_repository.created_at_range(),
+            )?;
+
+        Ok(GlobalLogStats {
+            total_logs,
+            total_schemas,
+            logs_last_hour,
+            logs_last_24h,
+            oldest_log,
+            newest_log,
+        })
+    }
+
+    /// Looks up the schema of every distinct `schema_id` in `logs` in a single query.
+    pub async fn schemas_for_logs(&self, logs: &[Log]) -> AppResult<HashMap<Uuid, Schema>> {
+        let mut schema_ids: Vec<Uuid> = logs.iter().map(|log| log.schema_id).collect();
+        schema_ids.sort_unstable();
+        schema_ids.dedup();
+
+        self.schema_repository.get_by_ids(&schema_ids).await
+    }
+
     pub async fn get_log_by_id(&self, id: i32) -> AppResult<Option<Log>> {
         self.log_repository.get_by_id(id).await
     }
 
-    pub async fn create_log(&self, schema_id: Uuid, log_data: Value) -> AppResult<Log> {
-        let schema = self.schema_repository.get_by_id(schema_id).await?;
-        let schema = match schema {
-            Some(s) => s,
-            None => {
-                return Err(AppError::NotFound(format!(
-                    "Schema with id '{}' not found",
-                    schema_id
-                )))
+    /// Returns up to `limit` of the schema's most recent logs.
+    pub async fn sample_logs_for_schema(&self, schema_id: Uuid, limit: i64) -> AppResult<Vec<Log>> {
+        self.log_repository
+            .sample_by_schema_id(schema_id, limit)
+            .await
+    }
+
+    /// Validates `log_data` against the schema and stores it, recording the validated
+    /// schema version on the log. Returns the log along with the schema it was validated against.
+    ///
+    /// The schema lookup, validation and database write are each timed into a histogram
+    /// labelled with `schema_id`.
+    pub async fn validate_and_create(
+        &self,
+        schema_id: Uuid,
+        log_data: Value,
+        metadata: LogMetadata,
+        ctx: &AuditContext,
+    ) -> AppResult<(Log, Schema)> {
+        let span = tracing::info_span!("log_service.validate_and_create", schema_id = %schema_id);
+
+        async {
+            let (log, schema) = self.prepare_log(schema_id, log_data, metadata).await?;
+
+            let created = self
+                .timed_db_write(schema_id, self.log_repository.create(&log))
+                .await?;
+
+            self.audit_log_created(&created, ctx).await;
+
+            Ok((created, schema))
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Creates a log unless one was already created under `idempotency_key`, in which case
+    /// the original is returned. The boolean is `true` when a new log was created.
+    ///
+    /// Validation and timing are the same as for `validate_and_create`; only the insert
+    /// runs in the idempotency transaction.
+    pub async fn create_log_idempotent(
+        &self,
+        idempotency_key: &str,
+        schema_id: Uuid,
+        log_data: Value,
+        metadata: LogMetadata,
+        ctx: &AuditContext,
+    ) -> AppResult<(Log, Schema, bool)> {
+        let span = tracing::info_span!("log_service.create_log_idempotent", schema_id = %schema_id);
+
+        async {
+            let (log, schema) = self.prepare_log(schema_id, log_data, metadata).await?;
+
+            let (log, created) = self
+                .timed_db_write(
+                    schema_id,
+                    self.log_repository
+                        .get_or_create_idempotent(idempotency_key, &log),
+                )
+                .await?;
+
+            if created {
+                self.audit_log_created(&log, ctx).await;
             }
+
+            Ok((log, schema, created))
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Validates the metadata, looks up the schema and validates `log_data` against it,
+    /// returning the log to insert and its schema. The lookup and validation are timed
+    /// into histograms labelled with `schema_id`.
+    async fn prepare_log(
+        &self,
+        schema_id: Uuid,
+        log_data: Value,
+        metadata: LogMetadata,
+    ) -> AppResult<(Log, Schema)> {
+        metadata.validate()?;
+        let schema_label = schema_id.to_string();
+
+        let start = Instant::now();
+        let schema = self.lookup_schema_for_log(schema_id, &log_data).await;
+        metrics::histogram!(LOG_SCHEMA_LOOKUP_DURATION_SECONDS, "schema_id" => schema_label.clone())
+            .record(start.elapsed().as_secs_f64());
+        let schema = schema?;
+
+        let start = Instant::now();
+        let validated = self.validate_log_for_loaded_schema(&schema, &log_data);
+        metrics::histogram!(LOG_VALIDATION_DURATION_SECONDS, "schema_id" => schema_label)
+            .record(start.elapsed().as_secs_f64());
+        validated?;
+
+        let log = self.new_log(&schema, log_data, metadata);
+        Ok((log, schema))
+    }
+
+    /// Awaits `write`, timing it into the database write histogram.
+    async fn timed_db_write<T>(
+        &self,
+        schema_id: Uuid,
+        write: impl Future<Output = AppResult<T>>,
+    ) -> AppResult<T> {
+        let start = Instant::now();
+        let written = write.await;
+        metrics::histogram!(LOG_DB_WRITE_DURATION_SECONDS, "schema_id" => schema_id.to_string())
+            .record(start.elapsed().as_secs_f64());
+        written
+    }
+
+    /// Creates many logs at once.
+    ///
+    /// With `fail_fast` every entry is validated before anything is written and the
+    /// first invalid entry aborts the whole batch, which is then inserted in a single
+    /// transaction. Without it each entry is validated and inserted on its own, so
+    /// valid entries are kept even when others fail. `LogEvent::Created` is broadcast
+    /// for each log once it has been committed.
+    pub async fn bulk_create_logs(
+        &self,
+        entries: Vec<(Uuid, Value, LogMetadata)>,
+        fail_fast: bool,
+        ctx: &AuditContext,
+    ) -> AppResult<BulkCreateResult> {
+        let mut result = BulkCreateResult {
+            created: Vec::new(),
+            failed: Vec::new(),
         };
+        let mut schemas: HashMap<Uuid, Schema> = HashMap::new();
+
+        if fail_fast {
+            let mut logs = Vec::with_capacity(entries.len());
+
+            for (index, (schema_id, log_data, metadata)) in entries.into_iter().enumerate() {
+                let validated = match metadata.validate() {
+                    Ok(()) => {
+                        self.validate_log_cached(schema_id, &log_data, &mut schemas)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                if let Err(error) = validated {
+                    result.failed.push(BulkCreateFailure {
+                        index,
+                        schema_id,
+                        error,
+                    });
+                    return Ok(result);
+                }
+
+                logs.push(self.new_log(&schemas[&schema_id], log_data, metadata));
+            }
+
+            result.created = self.log_repository.create_many(&logs).await?;
+        } else {
+            for (index, (schema_id, log_data, metadata)) in entries.into_iter().enumerate() {
+                let validated = match metadata.validate() {
+                    Ok(()) => {
+                        self.validate_log_cached(schema_id, &log_data, &mut schemas)
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
 
-        self.validate_log_against_schema(&log_data, &schema.schema_definition)?;
+                let created = match validated {
+                    Ok(()) => {
+                        self.log_repository
+                            .create(&self.new_log(&schemas[&schema_id], log_data, metadata))
+                            .await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                match created {
+                    Ok(log) => result.created.push(log),
+                    Err(error) => result.failed.push(BulkCreateFailure {
+                        index,
+                        schema_id,
+                        error,
+                    }),
+                }
+            }
+        }
+
+        for log in &result.created {
+            self.audit_log_created(log, ctx).await;
+            let schema = &schemas[&log.schema_id];
+            let _ = self.log_broadcast.send(LogEvent::created_from(
+                log.clone(),
+                schema.name.clone(),
+                schema.version.clone(),
+            ));
+        }
+
+        Ok(result)
+    }
 
-        let log = Log {
+    /// An unsaved log with enriched `log_data`.
+    fn new_log(&self, schema: &Schema, log_data: Value, metadata: LogMetadata) -> Log {
+        Log {
             id: 0, // This will be set by the database
-            schema_id,
-            log_data,
+            schema_id: schema.id,
+            log_data: self.enrich_log_data(log_data, schema.id),
+            source: metadata.source,
+            correlation_id: metadata.correlation_id,
+            severity: metadata.severity,
+            schema_version_at_creation: Some(schema.version.clone()),
+            attachments: metadata.attachments.map(Json),
             created_at: Utc::now(),
+        }
+    }
+
+    /// Logs sharing `correlation_id` across all schemas, oldest first, with the total
+    /// number of such logs.
+    pub async fn get_logs_by_correlation_id(
+        &self,
+        correlation_id: &str,
+        pagination: &PaginationQuery,
+    ) -> AppResult<(Vec<Log>, i64)> {
+        tokio::try_join!(
+            self.log_repository.get_by_correlation_id(
+                correlation_id,
+                pagination.limit(),
+                pagination.offset(),
+            ),
+            self.log_repository.count_by_correlation_id(correlation_id),
+        )
+    }
+
+    /// A log's `source` is at most 255 characters of letters, digits, `.`, `_` and `-`.
+    pub fn validate_source(source: Option<&str>) -> AppResult<()> {
+        let Some(source) = source else {
+            return Ok(());
+        };
+
+        let valid = !source.is_empty()
+            && source.len() <= MAX_SOURCE_LENGTH
+            && source
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+
+        if !valid {
+            return Err(AppError::BadRequest(format!(
+                "source must be 1 to {} characters of letters, digits, '.', '_' and '-'",
+                MAX_SOURCE_LENGTH
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// A log has at most `MAX_ATTACHMENTS` attachments, each with a name of 1 to
+    /// `MAX_ATTACHMENT_NAME_LENGTH` characters and an `https://` URL whose host is not
+    /// `localhost` or a private IP address. Host names are not resolved.
+    pub fn validate_attachments(attachments: Option<&[AttachmentRef]>) -> AppResult<()> {
+        let Some(attachments) = attachments else {
+            return Ok(());
+        };
+
+        if attachments.len() > MAX_ATTACHMENTS {
+            return Err(AppError::BadRequest(format!(
+                "A log can have at most {} attachments",
+                MAX_ATTACHMENTS
+            )));
+        }
+
+        for attachment in attachments {
+            if attachment.name.trim().is_empty()
+                || attachment.name.chars().count() > MAX_ATTACHMENT_NAME_LENGTH
+            {
+                return Err(AppError::BadRequest(format!(
+                    "Attachment names must be 1 to {} characters",
+                    MAX_ATTACHMENT_NAME_LENGTH
+                )));
+            }
+
+            if !Self::is_public_https_url(&attachment.url) {
+                return Err(AppError::BadRequest(format!(
+                    "Attachment URL '{}' must be an https:// URL on a public host",
+                    attachment.url
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_public_https_url(url: &str) -> bool {
+        let Ok(url) = Url::parse(url) else {
+            return false;
+        };
+        let Some(host) = url.host_str().filter(|_| url.scheme() == "https") else {
+            return false;
+        };
+
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        match host.parse::<IpAddr>() {
+            Ok(ip) => !is_private_ip(ip),
+            Err(_) => host != "localhost" && !host.ends_with(".localhost"),
+        }
+    }
+
+    /// Adds `_ingested_at`, `_schema_id` and, when `SERVER_VERSION` is set,
+    /// `_server_version` to the log data object if `ENRICH_LOGS` is enabled. Runs after
+    /// validation so schemas never need to declare these fields.
+    pub fn enrich_log_data(&self, mut data: Value, schema_id: Uuid) -> Value {
+        if !self.enrich_logs {
+            return data;
+        }
+
+        if let Some(object) = data.as_object_mut() {
+            object.insert(
+                "_ingested_at".to_string(),
+                Value::String(Utc::now().to_rfc3339()),
+            );
+            object.insert(
+                "_schema_id".to_string(),
+                Value::String(schema_id.to_string()),
+            );
+            if let Some(version) = &self.server_version {
+                object.insert(
+                    "_server_version".to_string(),
+                    Value::String(version.clone()),
+                );
+            }
+        }
+
+        data
+    }
+
+    /// Deletes a log, returning the deleted entry or `None` if it did not exist.
+    pub async fn delete_log(&self, id: i32, ctx: &AuditContext) -> AppResult<Option<Log>> {
+        let log = match self.log_repository.get_by_id(id).await? {
+            Some(log) => log,
+            None => return Ok(None),
+        };
+
+        if !self.log_repository.delete(id).await? {
+            return Ok(None);
+        }
+
+        self.audit_service
+            .record(
+                ctx,
+                ENTITY_LOG,
+                id.to_string(),
+                OPERATION_DELETE,
+                serde_json::to_value(&log).ok(),
+                None,
+            )
+            .await;
+
+        Ok(Some(log))
+    }
+
+    /// Deletes all of the schema's logs created before `before`, returning how many
+    /// were removed.
+    pub async fn delete_logs_before(
+        &self,
+        schema_id: Uuid,
+        before: DateTime<Utc>,
+        ctx: &AuditContext,
+    ) -> AppResult<i64> {
+        let filter = LogDeleteFilter {
+            before: Some(before),
+            ..Default::default()
         };
 
-        self.log_repository.create(&log).await
+        self.delete_matching(schema_id, &filter, ctx).await
+    }
+
+    /// Deletes the schema's logs, or only those created before `before`, returning how
+    /// many were removed. The schema itself is kept.
+    pub async fn delete_schema_logs(
+        &self,
+        schema_id: Uuid,
+        before: Option<DateTime<Utc>>,
+        ctx: &AuditContext,
+    ) -> AppResult<i64> {
+        let filter = LogDeleteFilter {
+            before,
+            ..Default::default()
+        };
+
+        self.delete_matching(schema_id, &filter, ctx).await
+    }
+
+    /// Deletes the schema's logs matching `filter`, which must set at least one criterion.
+    pub async fn delete_logs_by_filter(
+        &self,
+        schema_id: Uuid,
+        filter: &LogDeleteFilter,
+        ctx: &AuditContext,
+    ) -> AppResult<i64> {
+        if filter.is_empty() {
+            return Err(AppError::BadRequest(
+                "at least one of before, after or source is required".to_string(),
+            ));
+        }
+
+        self.delete_matching(schema_id, filter, ctx).await
     }
 
-    pub async fn delete_log(&self, id: i32) -> AppResult<bool> {
-        self.log_repository.delete(id).await
+    /// Bulk-deletes the schema's logs matching `filter` and records a summary audit
+    /// entry. `LogEvent::Deleted` is broadcast per log only for small deletions so
+    /// retention cleanups don't flood WebSocket subscribers.
+    async fn delete_matching(
+        &self,
+        schema_id: Uuid,
+        filter: &LogDeleteFilter,
+        ctx: &AuditContext,
+    ) -> AppResult<i64> {
+        let deleted = self
+            .log_repository
+            .delete_by_filter(schema_id, filter, MAX_BROADCAST_DELETIONS)
+            .await?;
+
+        tracing::info!(
+            "Deleted {} log(s) of schema {} matching {:?}",
+            deleted.count,
+            schema_id,
+            filter
+        );
+
+        if deleted.count < MAX_BROADCAST_DELETIONS {
+            for id in deleted.ids {
+                let _ = self.log_broadcast.send(LogEvent::Deleted { id, schema_id });
+            }
+        }
+
+        self.audit_logs_deleted(
+            schema_id,
+            deleted.count,
+            serde_json::json!({
+                "before": filter.before,
+                "after": filter.after,
+                "source": filter.source,
+            }),
+            ctx,
+        )
+        .await;
+
+        Ok(deleted.count)
     }
 
-    fn validate_log_against_schema(
+    /// Records one summary entry for a bulk deletion of `deleted` logs, with the
+    /// deletion criteria merged into `old_data`. Nothing is recorded if no log matched.
+    async fn audit_logs_deleted(
+        &self,
+        schema_id: Uuid,
+        deleted: i64,
+        mut criteria: Value,
+        ctx: &AuditContext,
+    ) {
+        if deleted == 0 {
+            return;
+        }
+
+        if let Value::Object(fields) = &mut criteria {
+            fields.insert(
+                "schema_id".to_string(),
+                Value::String(schema_id.to_string()),
+            );
+            fields.insert("deleted_count".to_string(), Value::from(deleted));
+        }
+
+        self.audit_service
+            .record(
+                ctx,
+                ENTITY_LOG,
+                schema_id.to_string(),
+                OPERATION_BULK_DELETE,
+                Some(criteria),
+                None,
+            )
+            .await;
+    }
+
+    async fn audit_log_created(&self, log: &Log, ctx: &AuditContext) {
+        self.audit_service
+            .record(
+                ctx,
+                ENTITY_LOG,
+                log.id.to_string(),
+                OPERATION_CREATE,
+                None,
+                serde_json::to_value(log).ok(),
+            )
+            .await;
+    }
+
+    /// Rejects log data whose serialized JSON exceeds `MAX_LOG_DATA_BYTES`.
+    fn check_log_data_size(&self, schema_id: Uuid, log_data: &Value) -> AppResult<()> {
+        let size = serde_json::to_vec(log_data)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize log data: {}", e)))?
+            .len();
+
+        if size > self.max_log_data_bytes {
+            tracing::warn!(
+                "Rejected log for schema {}: log_data is {} bytes (limit {})",
+                schema_id,
+                size,
+                self.max_log_data_bytes
+            );
+            return Err(AppError::PayloadTooLarge(format!(
+                "log_data exceeds maximum size of {} bytes",
+                self.max_log_data_bytes
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the size of `log_data` and fetches the schema it targets.
+    async fn lookup_schema_for_log(&self, schema_id: Uuid, log_data: &Value) -> AppResult<Schema> {
+        self.check_log_data_size(schema_id, log_data)?;
+
+        self.get_schema(schema_id)
+            .await?
+            .ok_or_else(|| AppError::not_found_resource("Schema", "id", &schema_id.to_string()))
+    }
+
+    /// Validates `log_data` against an already fetched schema.
+    fn validate_log_for_loaded_schema(&self, schema: &Schema, log_data: &Value) -> AppResult<()> {
+        self.validate_log_against_schema(
+            log_data,
+            &schema.schema_definition,
+            schema.validation_mode,
+        )?;
+        Self::warn_if_deprecated(schema);
+        Ok(())
+    }
+
+    /// Like `lookup_schema_for_log` followed by `validate_log_for_loaded_schema`, but
+    /// remembers schemas across a batch.
+    async fn validate_log_cached(
+        &self,
+        schema_id: Uuid,
+        log_data: &Value,
+        schemas: &mut HashMap<Uuid, Schema>,
+    ) -> AppResult<()> {
+        self.check_log_data_size(schema_id, log_data)?;
+
+        if let Entry::Vacant(entry) = schemas.entry(schema_id) {
+            let schema = self.get_schema(schema_id).await?.ok_or_else(|| {
+                AppError::not_found_resource("Schema", "id", &schema_id.to_string())
+            })?;
+            Self::warn_if_deprecated(&schema);
+            entry.insert(schema);
+        }
+
+        let schema = &schemas[&schema_id];
+        self.validate_log_against_schema(
+            log_data,
+            &schema.schema_definition,
+            schema.validation_mode,
+        )
+    }
+
+    async fn get_schema(&self, schema_id: Uuid) -> AppResult<Option<Schema>> {
+        self.schema_cache
+            .get_or_fetch(schema_id, || self.schema_repository.get_by_id(schema_id))
+            .await
+    }
+
+    /// Logs for deprecated schemas are accepted, but leave a trace for operators.
+    fn warn_if_deprecated(schema: &Schema) {
+        if schema.deprecated {
+            tracing::warn!(
+                schema_id = %schema.id,
+                "Log created for deprecated schema {}:{}",
+                schema.name,
+                schema.version
+            );
+        }
+    }
+
+    /// Every way `log_data` violates `schema_definition`; empty when it is valid.
+    pub fn validate_with_details(
+        &self,
+        log_data: &Value,
+        schema_definition: &Value,
+        validation_mode: ValidationMode,
+    ) -> AppResult<Vec<ValidationError>> {
+        Ok(
+            Self::collect_validation_errors(log_data, schema_definition, validation_mode)?
+                .into_iter()
+                .map(|(_, error)| error)
+                .collect(),
+        )
+    }
+
+    pub(crate) fn validate_log_against_schema(
         &self,
         log_data: &Value,
         schema_definition: &Value,
+        validation_mode: ValidationMode,
     ) -> AppResult<()> {
+        let errors = Self::collect_validation_errors(log_data, schema_definition, validation_mode)?;
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        let mut messages = Vec::new();
+        let mut field_errors: HashMap<String, Vec<String>> = HashMap::new();
+        for (field, error) in &errors {
+            messages.push(format!(
+                "Validation error at '{}': {}",
+                error.path, error.message
+            ));
+            field_errors
+                .entry(field.clone())
+                .or_default()
+                .push(error.message.clone());
+        }
+
+        Err(AppError::FieldValidationError {
+            message: messages.join("; "),
+            field_errors,
+            errors: errors.into_iter().map(|(_, error)| error).collect(),
+        })
+    }
+
+    /// Validation errors paired with the `field_errors` key they are grouped under.
+    fn collect_validation_errors(
+        log_data: &Value,
+        schema_definition: &Value,
+        validation_mode: ValidationMode,
+    ) -> AppResult<Vec<(String, ValidationError)>> {
+        let schema_definition = match validation_mode {
+            ValidationMode::Strict => Cow::Borrowed(schema_definition),
+            ValidationMode::Lenient => Cow::Owned(allow_additional_properties(schema_definition)),
+        };
         let validator = jsonschema::ValidationOptions::default()
             .with_draft(jsonschema::Draft::Draft7)
-            .build(schema_definition)
+            .build(&schema_definition)
             .map_err(|e| AppError::InternalError(format!("Invalid JSON schema: {}", e)))?;
 
-        let errors: Vec<_> = validator
-            .iter_errors(log_data)
-            .map(|e| format!("Validation error at '{}': {}", e.instance_path, e))
-            .collect();
+        let mut errors = Vec::new();
+        for e in validator.iter_errors(log_data) {
+            let mut field = field_path(e.instance_path.as_str());
+            // Missing properties are reported against their parent; point at the property itself
+            if let ValidationErrorKind::Required {
+                property: Value::String(property),
+            } = &e.kind
+            {
+                field = if field == ROOT_FIELD {
+                    property.clone()
+                } else {
+                    format!("{}.{}", field, property)
+                };
+            }
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(AppError::SchemaValidationError(format!(
-                "Schema validation failed: {}",
-                errors.join("; ")
-            )))
+            errors.push((
+                field,
+                ValidationError {
+                    path: e.instance_path.to_string(),
+                    message: e.to_string(),
+                    schema_path: e.schema_path.to_string(),
+                },
+            ));
         }
+
+        Ok(errors)
+    }
+}
+
+/// Copy of `schema_definition` that accepts extra properties at the root.
+fn allow_additional_properties(schema_definition: &Value) -> Value {
+    let mut schema_definition = schema_definition.clone();
+    if let Some(object) = schema_definition.as_object_mut() {
+        object.insert("additionalProperties".to_string(), Value::Bool(true));
     }
+    schema_definition
+}
+
+/// Key used in `field_errors` for errors on the log data object itself.
+const ROOT_FIELD: &str = "$";
+
+/// Converts a JSON Pointer such as `/user/tags/0` into a dotted path (`user.tags.0`).
+fn field_path(pointer: &str) -> String {
+    if pointer.is_empty() {
+        return ROOT_FIELD.to_string();
+    }
+
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>()
+        .join(".")
 }
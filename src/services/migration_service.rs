@@ -0,0 +1,58 @@
+use sqlx::{
+    migrate::{Migrate, Migrator},
+    PgPool,
+};
+use std::collections::HashSet;
+
+use crate::error::{AppError, AppResult};
+
+/// Migrations under `migrations/`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+#[derive(Debug, Clone)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+}
+
+#[derive(Clone)]
+pub struct MigrationService {
+    pool: PgPool,
+}
+
+impl MigrationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Applies every pending migration, returning the ones that were applied in order.
+    pub async fn run_pending(&self) -> AppResult<Vec<AppliedMigration>> {
+        let applied: HashSet<i64> = {
+            let mut conn = self.pool.acquire().await?;
+            conn.ensure_migrations_table()
+                .await
+                .map_err(migrate_error)?;
+            conn.list_applied_migrations()
+                .await
+                .map_err(migrate_error)?
+                .into_iter()
+                .map(|migration| migration.version)
+                .collect()
+        };
+
+        MIGRATOR.run(&self.pool).await.map_err(migrate_error)?;
+
+        Ok(MIGRATOR
+            .iter()
+            .filter(|migration| !applied.contains(&migration.version))
+            .map(|migration| AppliedMigration {
+                version: migration.version,
+                description: migration.description.to_string(),
+            })
+            .collect())
+    }
+}
+
+fn migrate_error(err: sqlx::migrate::MigrateError) -> AppError {
+    AppError::DatabaseError(format!("Migration failed: {}", err))
+}
@@ -1,5 +1,15 @@
+pub mod audit_service;
+pub mod ingest_webhook_service;
 pub mod log_service;
+pub mod migration_service;
+pub mod schema_cache;
 pub mod schema_service;
+pub mod webhook_service;
 
-pub use log_service::LogService;
+pub use audit_service::AuditService;
+pub use ingest_webhook_service::IngestWebhookService;
+pub use log_service::{GlobalLogStats, LogMetadata, LogService};
+pub use migration_service::MigrationService;
+pub use schema_cache::SchemaCache;
 pub use schema_service::SchemaService;
+pub use webhook_service::WebhookService;
@@ -0,0 +1,86 @@
+use lru::LruCache;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use crate::models::Schema;
+
+pub const SCHEMA_CACHE_SIZE_ENV: &str = "SCHEMA_CACHE_SIZE";
+pub const DEFAULT_SCHEMA_CACHE_SIZE: usize = 200;
+
+/// Recently used schemas by id, shared by the schema and log services so log validation
+/// skips the database for hot schemas. A capacity of `0` disables caching.
+///
+/// Every schema write must call [`invalidate`](Self::invalidate) after it is committed.
+/// Lookups that raced with a write never store what they read, so a cached schema is
+/// never older than the last invalidation.
+pub struct SchemaCache {
+    entries: Option<Mutex<LruCache<Uuid, Schema>>>,
+    /// Bumped by every invalidation
+    generation: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SchemaCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: NonZeroUsize::new(capacity)
+                .map(|capacity| Mutex::new(LruCache::new(capacity))),
+            generation: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the cached schema, or loads it with `fetch` and caches it.
+    pub async fn get_or_fetch<F, Fut>(&self, id: Uuid, fetch: F) -> AppResult<Option<Schema>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = AppResult<Option<Schema>>>,
+    {
+        let Some(entries) = &self.entries else {
+            return fetch().await;
+        };
+
+        if let Some(schema) = entries.lock().await.get(&id) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(Some(schema.clone()));
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let schema = fetch().await?;
+
+        if let Some(schema) = &schema {
+            let mut entries = entries.lock().await;
+            if self.generation.load(Ordering::Acquire) == generation {
+                entries.put(id, schema.clone());
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Drops the schema so the next lookup reads it from the database.
+    pub async fn invalidate(&self, id: Uuid) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        if let Some(entries) = &self.entries {
+            entries.lock().await.pop(&id);
+        }
+    }
+
+    /// Share of lookups served from the cache since startup; `0` before any lookup.
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let lookups = hits + self.misses.load(Ordering::Relaxed);
+        if lookups == 0 {
+            return 0.0;
+        }
+
+        hits as f64 / lookups as f64
+    }
+}
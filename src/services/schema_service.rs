@@ -1,25 +1,106 @@
+use crate::dto::{
+    common::DEFAULT_PAGE_LIMIT, CreateSchemaRequest, FieldInfo, PaginationQuery, SchemaDraft,
+    SchemaVersionSort, ValidationReport, ValidationReportError,
+};
 use crate::error::{AppError, AppResult};
-use crate::models::Schema;
-use crate::repositories::log_repository::{LogRepository, LogRepositoryTrait};
-use crate::repositories::schema_repository::{
-    SchemaQueryParams, SchemaRepository, SchemaRepositoryTrait,
+use crate::models::audit_model::{
+    ENTITY_SCHEMA, OPERATION_CREATE, OPERATION_DELETE, OPERATION_UPDATE,
 };
-use chrono::Utc;
+use crate::models::{AuditContext, Schema, ValidationMode};
+use crate::repositories::log_repository::LogRepositoryTrait;
+use crate::repositories::schema_repository::{SchemaQueryParams, SchemaRepositoryTrait};
+use crate::services::{AuditService, LogService, SchemaCache};
+use crate::utils::{openapi_import, remote_refs};
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tracing::Instrument;
 use uuid::Uuid;
 
+/// Matches the `VARCHAR(255)` `schemas.name` column.
+pub const MAX_SCHEMA_NAME_LENGTH: usize = 255;
+
+/// Number of recent logs checked against a new definition in strict compatibility mode.
+const COMPATIBILITY_SAMPLE_SIZE: i64 = 100;
+
+/// Most tags a schema can carry.
+pub const MAX_SCHEMA_TAGS: usize = 20;
+
+/// Longest accepted tag.
+pub const MAX_TAG_LENGTH: usize = 50;
+
+pub const VERSION_FORMAT_ENV: &str = "VERSION_FORMAT";
+
+/// Static path segments that follow a schema name or id in the routes. A free-form
+/// version equal to one of them would be shadowed by that route.
+const RESERVED_VERSION_SEGMENTS: &[&str] = &[
+    "across-versions",
+    "before",
+    "bulk",
+    "deprecate",
+    "export.json",
+    "fields",
+    "logs",
+    "schema_definition",
+    "tags",
+    "undeprecate",
+    "validate-example",
+    "versions",
+];
+
+/// Accepted shape of schema versions, chosen with `VERSION_FORMAT`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VersionFormat {
+    /// Semantic versions such as `1.0.0` or `2.1.0-beta.1`
+    #[default]
+    Semver,
+    /// Release dates such as `2024.01.15`
+    Date,
+    /// Any string starting with a letter or digit, other than a reserved route segment
+    Free,
+}
+
+impl std::str::FromStr for VersionFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "semver" => Ok(VersionFormat::Semver),
+            "date" => Ok(VersionFormat::Date),
+            "free" => Ok(VersionFormat::Free),
+            _ => Err("expected 'semver', 'date' or 'free'".to_string()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct SchemaService {
-    repository: Arc<SchemaRepository>,
-    log_repository: Arc<LogRepository>,
+    repository: Arc<dyn SchemaRepositoryTrait>,
+    log_repository: Arc<dyn LogRepositoryTrait>,
+    log_service: Arc<LogService>,
+    audit_service: Arc<AuditService>,
+    schema_cache: Arc<SchemaCache>,
+    version_format: VersionFormat,
 }
 
 impl SchemaService {
-    pub fn new(repository: Arc<SchemaRepository>, log_repository: Arc<LogRepository>) -> Self {
+    pub fn new(
+        repository: Arc<dyn SchemaRepositoryTrait>,
+        log_repository: Arc<dyn LogRepositoryTrait>,
+        log_service: Arc<LogService>,
+        audit_service: Arc<AuditService>,
+        schema_cache: Arc<SchemaCache>,
+        version_format: VersionFormat,
+    ) -> Self {
         Self {
             repository,
             log_repository,
+            log_service,
+            audit_service,
+            schema_cache,
+            version_format,
         }
     }
 
@@ -27,11 +108,98 @@ impl SchemaService {
         &self,
         params: Option<SchemaQueryParams>,
     ) -> AppResult<Vec<Schema>> {
-        self.repository.get_all(params).await
+        match params {
+            Some(SchemaQueryParams {
+                after_seq: Some(after_seq),
+                limit,
+                ..
+            }) => {
+                self.repository
+                    .get_after_seq(after_seq, limit.unwrap_or(DEFAULT_PAGE_LIMIT))
+                    .await
+            }
+            params => self.repository.get_all(params).await,
+        }
+    }
+
+    /// Streams the matching schemas; unlike `get_all_schemas`, no limit applies unless
+    /// one is set in `params`.
+    pub fn stream_schemas(
+        &self,
+        params: SchemaQueryParams,
+    ) -> BoxStream<'static, AppResult<Schema>> {
+        self.repository.stream_all(params)
+    }
+
+    /// Like `get_all_schemas`, with each schema's log count from a single grouped query.
+    pub async fn get_all_schemas_with_counts(
+        &self,
+        params: Option<SchemaQueryParams>,
+    ) -> AppResult<Vec<(Schema, i64)>> {
+        let schemas = self.repository.get_all_with_counts(params).await?;
+
+        Ok(schemas
+            .into_iter()
+            .map(|row| (row.schema, row.log_count))
+            .collect())
+    }
+
+    pub async fn get_log_count(&self, schema_id: Uuid) -> AppResult<i64> {
+        self.log_repository.count_by_schema_id(schema_id).await
+    }
+
+    /// Number of schemas matching the name/version filters, ignoring limit and offset.
+    pub async fn count_schemas(&self, params: &SchemaQueryParams) -> AppResult<i64> {
+        self.repository.count(params).await
+    }
+
+    /// Returns one page of schemas whose name or description matches the search terms,
+    /// along with the total number of matches.
+    pub async fn search_schemas(
+        &self,
+        query: &str,
+        pagination: &PaginationQuery,
+    ) -> AppResult<(Vec<Schema>, i64)> {
+        tokio::try_join!(
+            self.repository
+                .search(query, pagination.limit(), pagination.offset()),
+            self.repository.count_search(query),
+        )
+    }
+
+    /// Returns every version of the schema named `name` in the given order.
+    pub async fn get_schema_versions(
+        &self,
+        name: &str,
+        sort_by: SchemaVersionSort,
+    ) -> AppResult<Vec<Schema>> {
+        match sort_by {
+            SchemaVersionSort::Version => self.repository.get_versions_sorted_by_semver(name).await,
+            SchemaVersionSort::CreatedAt => {
+                self.repository
+                    .get_all(Some(SchemaQueryParams {
+                        name: Some(name.to_string()),
+                        ..Default::default()
+                    }))
+                    .await
+            }
+        }
+    }
+
+    /// Returns every schema whose name contains `substring`, ignoring case.
+    pub async fn get_schemas_by_name_containing(&self, substring: &str) -> AppResult<Vec<Schema>> {
+        self.repository.get_by_name_containing(substring).await
     }
 
     pub async fn get_schema_by_id(&self, id: Uuid) -> AppResult<Option<Schema>> {
-        self.repository.get_by_id(id).await
+        self.schema_cache
+            .get_or_fetch(id, || self.repository.get_by_id(id))
+            .await
+    }
+
+    /// Share of schema lookups by id served from the cache since startup.
+    pub fn cache_hit_rate(&self) -> f64 {
+        self.schema_cache.hit_rate()
     }
 
     pub async fn get_by_name_and_version(
@@ -42,40 +210,80 @@ impl SchemaService {
         self.repository.get_by_name_and_version(name, version).await
     }
 
+    /// Resolves the highest version of the schema named `name`.
+    pub async fn get_latest_by_name(&self, name: &str) -> AppResult<Option<Schema>> {
+        self.repository.get_latest_version_by_name(name).await
+    }
+
     pub async fn create_schema(
         &self,
         name: String,
         version: String,
         description: Option<String>,
         schema_definition: Value,
+        validation_mode: ValidationMode,
+        ctx: &AuditContext,
     ) -> AppResult<Schema> {
-        self.validate_schema_definition(&schema_definition)?;
+        let span = tracing::info_span!(
+            "schema_service.create",
+            schema_id = tracing::field::Empty,
+            schema_name = %name,
+            schema_version = %version
+        );
 
-        let existing = self
-            .repository
-            .get_by_name_and_version(&name, &version)
-            .await?;
-        if existing.is_some() {
-            return Err(AppError::Conflict(format!(
-                "Schema with name '{}' and version '{}' already exists",
-                name, version
-            )));
-        }
+        async {
+            Self::validate_schema_name(&name)?;
+            self.validate_version(&version)?;
+            self.validate_schema_definition(&schema_definition)?;
 
-        let now = Utc::now();
-        let schema = Schema {
-            id: Uuid::new_v4(),
-            name,
-            version,
-            description,
-            schema_definition,
-            created_at: now,
-            updated_at: now,
-        };
+            let existing = self
+                .repository
+                .get_by_name_and_version(&name, &version)
+                .await?;
+            if existing.is_some() {
+                return Err(AppError::Conflict(format!(
+                    "Schema with name '{}' and version '{}' already exists",
+                    name, version
+                )));
+            }
+
+            let now = Utc::now();
+            let schema = Schema {
+                id: Uuid::new_v4(),
+                seq: 0, // assigned on insert
+                name,
+                version,
+                description,
+                schema_definition,
+                deprecated: false,
+                deprecated_at: None,
+                tags: Vec::new(),
+                validation_mode,
+                created_at: now,
+                updated_at: now,
+            };
 
-        self.repository.create(&schema).await
+            let created = self.repository.create(&schema).await?;
+            tracing::Span::current().record("schema_id", tracing::field::display(created.id));
+
+            self.audit_service
+                .record(
+                    ctx,
+                    ENTITY_SCHEMA,
+                    created.id.to_string(),
+                    OPERATION_CREATE,
+                    None,
+                    serde_json::to_value(&created).ok(),
+                )
+                .await;
+
+            Ok(created)
+        }
+        .instrument(span)
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_schema(
         &self,
         id: Uuid,
@@ -83,61 +291,631 @@ impl SchemaService {
         version: String,
         description: Option<String>,
         schema_definition: Value,
+        validation_mode: Option<ValidationMode>,
+        expected_updated_at: Option<DateTime<Utc>>,
+        strict: bool,
+        ctx: &AuditContext,
     ) -> AppResult<Option<Schema>> {
-        self.validate_schema_definition(&schema_definition)?;
+        let span = tracing::info_span!(
+            "schema_service.update",
+            schema_id = %id,
+            schema_name = %name,
+            schema_version = %version
+        );
+
+        async {
+            Self::validate_schema_name(&name)?;
+            self.validate_version(&version)?;
+            self.validate_schema_definition(&schema_definition)?;
+
+            let existing_schema = match self.repository.get_by_id(id).await? {
+                Some(schema) => schema,
+                None => return Ok(None),
+            };
+
+            let validation_mode = validation_mode.unwrap_or(existing_schema.validation_mode);
+
+            if strict {
+                self.check_backward_compatibility(id, &schema_definition, validation_mode)
+                    .await?;
+            }
+
+            let new_schema = self
+                .repository
+                .get_by_name_and_version(&name, &version)
+                .await?;
+            if let Some(existing) = new_schema {
+                if existing.id != id {
+                    return Err(AppError::Conflict(format!(
+                        "Schema with name '{}' and version '{}' already exists with id '{}'",
+                        name, version, existing.id
+                    )));
+                }
+            }
+
+            let updated_schema = Schema {
+                id,
+                seq: existing_schema.seq,
+                name,
+                version,
+                description,
+                schema_definition,
+                deprecated: existing_schema.deprecated,
+                deprecated_at: existing_schema.deprecated_at,
+                tags: existing_schema.tags.clone(),
+                validation_mode,
+                created_at: existing_schema.created_at, // keep original creation time
+                updated_at: Utc::now(),
+            };
+
+            let updated = self
+                .repository
+                .update(id, &updated_schema, expected_updated_at)
+                .await?;
+            self.schema_cache.invalidate(id).await;
+
+            // The schema existed above, so no row means `updated_at` no longer matched
+            if updated.is_none() && expected_updated_at.is_some() {
+                return Err(AppError::Conflict(
+                    "Schema was modified by another request; retry with the current version"
+                        .to_string(),
+                ));
+            }
 
-        let existing_schema = self.repository.get_by_id(id).await?;
-        if existing_schema.is_none() {
-            return Ok(None);
+            if let Some(updated) = &updated {
+                self.audit_service
+                    .record(
+                        ctx,
+                        ENTITY_SCHEMA,
+                        id.to_string(),
+                        OPERATION_UPDATE,
+                        serde_json::to_value(&existing_schema).ok(),
+                        serde_json::to_value(updated).ok(),
+                    )
+                    .await;
+            }
+
+            Ok(updated)
         }
+        .instrument(span)
+        .await
+    }
 
-        let new_schema = self
-            .repository
-            .get_by_name_and_version(&name, &version)
+    /// Replaces the schema's definition, keeping its name, version, description and
+    /// other settings.
+    pub async fn update_schema_definition_only(
+        &self,
+        id: Uuid,
+        new_definition: Value,
+        strict: bool,
+        ctx: &AuditContext,
+    ) -> AppResult<Option<Schema>> {
+        let span = tracing::info_span!("schema_service.update_definition", schema_id = %id);
+
+        async {
+            self.validate_schema_definition(&new_definition)?;
+
+            let existing_schema = match self.repository.get_by_id(id).await? {
+                Some(schema) => schema,
+                None => return Ok(None),
+            };
+
+            if strict {
+                self.check_backward_compatibility(
+                    id,
+                    &new_definition,
+                    existing_schema.validation_mode,
+                )
+                .await?;
+            }
+
+            let updated = self
+                .repository
+                .set_definition(id, &new_definition, Utc::now())
+                .await?;
+            self.schema_cache.invalidate(id).await;
+
+            if let Some(updated) = &updated {
+                self.audit_service
+                    .record(
+                        ctx,
+                        ENTITY_SCHEMA,
+                        id.to_string(),
+                        OPERATION_UPDATE,
+                        serde_json::to_value(&existing_schema).ok(),
+                        serde_json::to_value(updated).ok(),
+                    )
+                    .await;
+            }
+
+            Ok(updated)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Marks the schema as deprecated. Logs can still be created for it.
+    pub async fn deprecate_schema(
+        &self,
+        id: Uuid,
+        ctx: &AuditContext,
+    ) -> AppResult<Option<Schema>> {
+        self.set_deprecated(id, true, ctx).await
+    }
+
+    pub async fn undeprecate_schema(
+        &self,
+        id: Uuid,
+        ctx: &AuditContext,
+    ) -> AppResult<Option<Schema>> {
+        self.set_deprecated(id, false, ctx).await
+    }
+
+    async fn set_deprecated(
+        &self,
+        id: Uuid,
+        deprecated: bool,
+        ctx: &AuditContext,
+    ) -> AppResult<Option<Schema>> {
+        let span =
+            tracing::info_span!("schema_service.set_deprecated", schema_id = %id, deprecated);
+
+        async {
+            let existing_schema = match self.repository.get_by_id(id).await? {
+                Some(schema) => schema,
+                None => return Ok(None),
+            };
+
+            // Re-deprecating keeps the original timestamp
+            let deprecated_at = match (deprecated, existing_schema.deprecated_at) {
+                (true, Some(at)) => Some(at),
+                (true, None) => Some(Utc::now()),
+                (false, _) => None,
+            };
+
+            let updated = self
+                .repository
+                .set_deprecated(id, deprecated, deprecated_at)
+                .await?;
+            self.schema_cache.invalidate(id).await;
+
+            if let Some(updated) = &updated {
+                self.audit_service
+                    .record(
+                        ctx,
+                        ENTITY_SCHEMA,
+                        id.to_string(),
+                        OPERATION_UPDATE,
+                        serde_json::to_value(&existing_schema).ok(),
+                        serde_json::to_value(updated).ok(),
+                    )
+                    .await;
+            }
+
+            Ok(updated)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Removes the `remove` tags and appends the `add` tags not yet present, keeping the
+    /// order of the remaining tags. A tag in both lists ends up present.
+    pub async fn patch_tags(
+        &self,
+        id: Uuid,
+        add: Vec<String>,
+        remove: Vec<String>,
+        ctx: &AuditContext,
+    ) -> AppResult<Option<Schema>> {
+        let span = tracing::info_span!("schema_service.patch_tags", schema_id = %id);
+
+        async {
+            let existing_schema = match self.repository.get_by_id(id).await? {
+                Some(schema) => schema,
+                None => return Ok(None),
+            };
+
+            let mut tags: Vec<String> = existing_schema
+                .tags
+                .iter()
+                .filter(|tag| !remove.contains(tag))
+                .cloned()
+                .collect();
+            for tag in add {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            Self::validate_tags(&tags)?;
+
+            let updated = self.repository.set_tags(id, &tags).await?;
+            self.schema_cache.invalidate(id).await;
+
+            if let Some(updated) = &updated {
+                self.audit_service
+                    .record(
+                        ctx,
+                        ENTITY_SCHEMA,
+                        id.to_string(),
+                        OPERATION_UPDATE,
+                        serde_json::to_value(&existing_schema).ok(),
+                        serde_json::to_value(updated).ok(),
+                    )
+                    .await;
+            }
+
+            Ok(updated)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// At most `MAX_SCHEMA_TAGS` tags of 1 to `MAX_TAG_LENGTH` letters, digits, '_' or '-'.
+    fn validate_tags(tags: &[String]) -> AppResult<()> {
+        if tags.len() > MAX_SCHEMA_TAGS {
+            return Err(AppError::ValidationError(format!(
+                "A schema can have at most {} tags; the result would have {}",
+                MAX_SCHEMA_TAGS,
+                tags.len()
+            )));
+        }
+
+        for tag in tags {
+            let is_valid = !tag.is_empty()
+                && tag.len() <= MAX_TAG_LENGTH
+                && tag
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            if !is_valid {
+                return Err(AppError::ValidationError(format!(
+                    "Tag '{}' must be 1 to {} letters, digits, '_' or '-'",
+                    tag, MAX_TAG_LENGTH
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates a sample of the schema's existing logs against `schema_definition`,
+    /// failing with `AppError::Conflict` if any of them would no longer be valid.
+    pub async fn check_backward_compatibility(
+        &self,
+        id: Uuid,
+        schema_definition: &Value,
+        validation_mode: ValidationMode,
+    ) -> AppResult<()> {
+        let logs = self
+            .log_service
+            .sample_logs_for_schema(id, COMPATIBILITY_SAMPLE_SIZE)
             .await?;
-        if let Some(existing) = new_schema {
-            if existing.id != id {
+
+        let failures: Vec<(i32, AppError)> = logs
+            .iter()
+            .filter_map(|log| {
+                self.log_service
+                    .validate_log_against_schema(&log.log_data, schema_definition, validation_mode)
+                    .err()
+                    .map(|e| (log.id, e))
+            })
+            .collect();
+
+        match failures.first() {
+            None => Ok(()),
+            Some((log_id, error)) => Err(AppError::Conflict(format!(
+                "Definition of schema with id '{}' is incompatible with {} of {} sampled existing log(s); log {}: {}",
+                id,
+                failures.len(),
+                logs.len(),
+                log_id,
+                error
+            ))),
+        }
+    }
+
+    /// Lists the properties declared in the schema definition, depth first. Properties of
+    /// nested objects follow their parent, named with dot notation (`address.city`).
+    pub fn extract_fields(schema: &Schema) -> Vec<FieldInfo> {
+        let mut fields = Vec::new();
+        collect_fields(&schema.schema_definition, "", &mut fields);
+        fields
+    }
+
+    /// Returns the schema's definition as a standalone JSON Schema document: `$schema`
+    /// (from `draft`, else the definition's own, else 2020-12) and `title` (the schema
+    /// name, unless one is set) are added and local `$ref`s are inlined. If any reference
+    /// is circular or external, the `$ref`s are kept as they are instead.
+    pub async fn export_as_json_schema(
+        &self,
+        id: Uuid,
+        draft: Option<SchemaDraft>,
+    ) -> AppResult<Value> {
+        let schema = self
+            .repository
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found_resource("Schema", "id", &id.to_string()))?;
+
+        let definition = &schema.schema_definition;
+        let mut document = match openapi_import::inline_refs(definition, definition) {
+            Ok(inlined) => inlined,
+            Err(e) => {
+                tracing::debug!("Exporting schema {} without inlining $refs: {}", id, e);
+                definition.clone()
+            }
+        };
+
+        if let Some(object) = document.as_object_mut() {
+            let dialect = Value::String(draft.unwrap_or_default().uri().to_string());
+            if draft.is_some() {
+                object.insert("$schema".to_string(), dialect);
+            } else {
+                object.entry("$schema").or_insert(dialect);
+            }
+            object
+                .entry("title")
+                .or_insert_with(|| Value::String(schema.name.clone()));
+        }
+
+        Ok(document)
+    }
+
+    /// Stores `examples` under the definition's `examples` keyword, replacing any
+    /// examples already there. Non-object definitions are returned unchanged.
+    pub fn with_examples(mut schema_definition: Value, examples: Vec<Value>) -> Value {
+        if let Some(object) = schema_definition.as_object_mut() {
+            object.insert("examples".to_string(), Value::Array(examples));
+        }
+
+        schema_definition
+    }
+
+    /// Inlines every remote `$ref` in `definition` so the stored schema validates
+    /// without network access. References to private hosts are left in place.
+    pub async fn resolve_refs(&self, definition: Value) -> AppResult<Value> {
+        remote_refs::inline_remote_refs(&definition, &remote_refs::FetchOptions::default())
+            .await
+            .map_err(AppError::ValidationError)
+    }
+
+    /// Runs the schema's example at `index` through the log validator, returning the
+    /// validation errors grouped by field; an empty map means the example is valid.
+    pub async fn validate_example(
+        &self,
+        id: Uuid,
+        index: usize,
+    ) -> AppResult<HashMap<String, Vec<String>>> {
+        let span = tracing::info_span!("schema_service.validate", schema_id = %id, index);
+
+        async {
+            let schema = self
+                .repository
+                .get_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::not_found_resource("Schema", "id", &id.to_string()))?;
+
+            let example = schema
+                .schema_definition
+                .get("examples")
+                .and_then(|examples| examples.get(index))
+                .ok_or_else(|| {
+                    AppError::NotFound(format!(
+                        "Schema with id '{}' has no example at index {}",
+                        id, index
+                    ))
+                })?;
+
+            match self.log_service.validate_log_against_schema(
+                example,
+                &schema.schema_definition,
+                schema.validation_mode,
+            ) {
+                Ok(()) => Ok(HashMap::new()),
+                Err(AppError::FieldValidationError { field_errors, .. }) => Ok(field_errors),
+                Err(e) => Err(e),
+            }
+        }
+        .instrument(span)
+        .await
+    }
+
+    pub async fn delete_schema(
+        &self,
+        id: Uuid,
+        force: bool,
+        ctx: &AuditContext,
+    ) -> AppResult<bool> {
+        let span = tracing::info_span!("schema_service.delete", schema_id = %id, force);
+
+        async {
+            let schema = match self.repository.get_by_id(id).await? {
+                Some(schema) => schema,
+                None => return Ok(false),
+            };
+
+            let log_count = self.log_repository.count_by_schema_id(id).await?;
+
+            if log_count > 0 && !force {
                 return Err(AppError::Conflict(format!(
-                    "Schema with name '{}' and version '{}' already exists with a different ID",
-                    name, version
+                    "Cannot delete schema with id '{}': {} log(s) are associated with this schema. Use force=true to delete schema and all associated logs.",
+                    id, log_count
                 )));
             }
+
+            if force && log_count > 0 {
+                let deleted_logs = self.log_repository.delete_by_schema_id(id).await?;
+                tracing::info!("Deleted {} logs for schema {}", deleted_logs, id);
+            }
+
+            let deleted = self.repository.delete(id).await?;
+            self.schema_cache.invalidate(id).await;
+
+            if deleted {
+                self.audit_service
+                    .record(
+                        ctx,
+                        ENTITY_SCHEMA,
+                        id.to_string(),
+                        OPERATION_DELETE,
+                        serde_json::to_value(&schema).ok(),
+                        None,
+                    )
+                    .await;
+            }
+
+            Ok(deleted)
         }
+        .instrument(span)
+        .await
+    }
 
-        let updated_schema = Schema {
-            id,
-            name,
-            version,
-            description,
-            schema_definition,
-            created_at: existing_schema.unwrap().created_at, // keep original creation time
-            updated_at: Utc::now(),
-        };
+    /// Creates one schema (version `1.0.0`) per entry of an OpenAPI `components/schemas`
+    /// object, inlining local `$ref`s first. Each entry succeeds or fails independently.
+    pub async fn import_openapi_schemas(
+        &self,
+        body: &Value,
+        ctx: &AuditContext,
+    ) -> AppResult<Vec<(String, AppResult<Schema>)>> {
+        let schemas = openapi_import::component_schemas(body).ok_or_else(|| {
+            AppError::ValidationError(
+                "Body must be an OpenAPI document or its components/schemas object".to_string(),
+            )
+        })?;
+        let document = openapi_import::as_document(body);
 
-        self.repository.update(id, &updated_schema).await
+        let mut results = Vec::with_capacity(schemas.len());
+        for (name, schema) in schemas {
+            let result = match openapi_import::inline_refs(schema, &document) {
+                Ok(schema_definition) => {
+                    let description = schema_definition
+                        .get("description")
+                        .and_then(Value::as_str)
+                        .map(str::to_string);
+
+                    self.create_schema(
+                        name.clone(),
+                        "1.0.0".to_string(),
+                        description,
+                        schema_definition,
+                        ValidationMode::default(),
+                        ctx,
+                    )
+                    .await
+                }
+                Err(e) => Err(AppError::ValidationError(e)),
+            };
+
+            results.push((name.clone(), result));
+        }
+
+        Ok(results)
     }
 
-    pub async fn delete_schema(&self, id: Uuid, force: bool) -> AppResult<bool> {
-        let schema = self.repository.get_by_id(id).await?;
-        if schema.is_none() {
-            return Ok(false);
+    /// Checks each schema the way `create_schema` would, plus name and version
+    /// uniqueness within the batch, without querying the database.
+    pub fn validate_schemas_dry_run(&self, schemas: Vec<CreateSchemaRequest>) -> ValidationReport {
+        let mut seen = HashSet::new();
+        let mut errors = Vec::new();
+
+        for (index, schema) in schemas.into_iter().enumerate() {
+            let is_duplicate = !seen.insert((schema.name.clone(), schema.version.clone()));
+            let schema_definition = match schema.examples {
+                Some(examples) => Self::with_examples(schema.schema_definition, examples),
+                None => schema.schema_definition,
+            };
+
+            let result = Self::validate_schema_name(&schema.name)
+                .and_then(|_| self.validate_version(&schema.version))
+                .and_then(|_| self.validate_schema_definition(&schema_definition))
+                .and_then(|_| match is_duplicate {
+                    true => Err(AppError::Conflict(format!(
+                        "Schema with name '{}' and version '{}' appears earlier in the batch",
+                        schema.name, schema.version
+                    ))),
+                    false => Ok(()),
+                });
+
+            if let Err(e) = result {
+                errors.push(ValidationReportError {
+                    index,
+                    name: schema.name,
+                    version: schema.version,
+                    error: e.to_string(),
+                });
+            }
         }
 
-        let log_count = self.log_repository.count_by_schema_id(id).await?;
+        ValidationReport::new(errors)
+    }
 
-        if log_count > 0 && !force {
-            return Err(AppError::Conflict(format!(
-                "Cannot delete schema: {} log(s) are associated with this schema. Use force=true to delete schema and all associated logs.",
-                log_count
+    /// Schema names are at most 255 characters, start with a letter or digit and
+    /// otherwise contain only letters, digits, `_`, `.` and `-`.
+    pub fn validate_schema_name(name: &str) -> AppResult<()> {
+        if name.len() > MAX_SCHEMA_NAME_LENGTH {
+            return Err(AppError::ValidationError(format!(
+                "Schema name length must not exceed {} characters (got {})",
+                MAX_SCHEMA_NAME_LENGTH,
+                name.len()
             )));
         }
 
-        if force && log_count > 0 {
-            let deleted_logs = self.log_repository.delete_by_schema_id(id).await?;
-            tracing::info!("Deleted {} logs for schema {}", deleted_logs, id);
+        let mut chars = name.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+
+        if !valid {
+            return Err(AppError::ValidationError(format!(
+                "Schema name '{}' must start with a letter or digit and contain only letters, digits, '_', '.' and '-'",
+                name
+            )));
         }
 
-        self.repository.delete(id).await
+        Ok(())
+    }
+
+    /// Schema versions must match the configured `VERSION_FORMAT`.
+    pub fn validate_version(&self, version: &str) -> AppResult<()> {
+        match self.version_format {
+            VersionFormat::Semver => semver::Version::parse(version).map(|_| ()).map_err(|e| {
+                AppError::ValidationError(format!(
+                    "Version must be a valid semver string (MAJOR.MINOR.PATCH, e.g. 1.0.0 or 2.1.0-beta.1); '{}' is not: {}",
+                    version, e
+                ))
+            }),
+            VersionFormat::Date => {
+                let is_date = version.len() == 10
+                    && chrono::NaiveDate::parse_from_str(version, "%Y.%m.%d").is_ok();
+                if is_date {
+                    Ok(())
+                } else {
+                    Err(AppError::ValidationError(format!(
+                        "Version must be a date in YYYY.MM.DD format (e.g. 2024.01.15); '{}' is not",
+                        version
+                    )))
+                }
+            }
+            VersionFormat::Free => {
+                if version.trim().is_empty() {
+                    Err(AppError::ValidationError(
+                        "Version must not be empty".to_string(),
+                    ))
+                } else if !version.starts_with(|c: char| c.is_ascii_alphanumeric())
+                    || version.contains('/')
+                {
+                    Err(AppError::ValidationError(format!(
+                        "Version must start with a letter or digit and must not contain '/'; '{}' does not",
+                        version
+                    )))
+                } else if RESERVED_VERSION_SEGMENTS.contains(&version) {
+                    Err(AppError::ValidationError(format!(
+                        "Version '{}' is reserved: it is a path segment of the schema and log routes",
+                        version
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+        }
     }
 
     // Business logic: validate schema definition against JSON Schema meta-schema
@@ -151,6 +929,23 @@ impl SchemaService {
         let _compiled = jsonschema::validator_for(schema_definition)
             .map_err(|e| AppError::SchemaValidationError(format!("Invalid JSON Schema: {}", e)))?;
 
+        // Allowed by JSON Schema, but usually a typo that only surfaces once logs are rejected
+        if let (Some(Value::Array(required)), Some(Value::Object(properties))) = (
+            schema_definition.get("required"),
+            schema_definition.get("properties"),
+        ) {
+            if let Some(field) = required
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|field| !properties.contains_key(*field))
+            {
+                return Err(AppError::ValidationError(format!(
+                    "Required field '{}' is not defined in properties",
+                    field
+                )));
+            }
+        }
+
         Ok(())
 
         /*
@@ -181,3 +976,47 @@ impl SchemaService {
         */
     }
 }
+
+fn collect_fields(definition: &Value, prefix: &str, fields: &mut Vec<FieldInfo>) {
+    let Some(properties) = definition.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+
+    let required: Vec<&str> = definition
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    for (key, property) in properties {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+
+        let type_ = match property.get("type") {
+            Some(Value::String(type_)) => Some(type_.clone()),
+            Some(Value::Array(types)) => Some(
+                types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join("|"),
+            ),
+            _ => None,
+        };
+
+        fields.push(FieldInfo {
+            name: name.clone(),
+            required: required.contains(&key.as_str()),
+            type_,
+            description: property
+                .get("description")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        });
+
+        collect_fields(property, &name, fields);
+    }
+}
@@ -0,0 +1,228 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::models::webhook_model::WEBHOOK_EVENTS;
+use crate::models::Webhook;
+use crate::repositories::webhook_repository::{WebhookRepository, WebhookRepositoryTrait};
+
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Webhook-Event";
+
+const MAX_DELIVERY_ATTEMPTS: u32 = 3;
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub struct WebhookService {
+    repository: Arc<WebhookRepository>,
+    client: reqwest::Client,
+}
+
+impl WebhookService {
+    pub fn new(repository: Arc<WebhookRepository>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        Self { repository, client }
+    }
+
+    pub async fn get_webhooks(&self) -> AppResult<Vec<Webhook>> {
+        self.repository.get_all().await
+    }
+
+    pub async fn get_webhook(&self, id: Uuid) -> AppResult<Option<Webhook>> {
+        self.repository.get_by_id(id).await
+    }
+
+    pub async fn create_webhook(
+        &self,
+        url: String,
+        events: Vec<String>,
+        secret: String,
+        active: bool,
+    ) -> AppResult<Webhook> {
+        Self::validate_webhook(&url, &events, &secret)?;
+
+        let webhook = Webhook {
+            id: Uuid::new_v4(),
+            url,
+            events,
+            secret,
+            active,
+            created_at: Utc::now(),
+        };
+
+        self.repository.create(&webhook).await
+    }
+
+    /// Replaces the webhook's settings; a missing `secret` keeps the current one.
+    pub async fn update_webhook(
+        &self,
+        id: Uuid,
+        url: String,
+        events: Vec<String>,
+        secret: Option<String>,
+        active: bool,
+    ) -> AppResult<Option<Webhook>> {
+        let existing = match self.repository.get_by_id(id).await? {
+            Some(webhook) => webhook,
+            None => return Ok(None),
+        };
+
+        let secret = secret.unwrap_or(existing.secret);
+        Self::validate_webhook(&url, &events, &secret)?;
+
+        let webhook = Webhook {
+            id,
+            url,
+            events,
+            secret,
+            active,
+            created_at: existing.created_at,
+        };
+
+        self.repository.update(id, &webhook).await
+    }
+
+    pub async fn delete_webhook(&self, id: Uuid) -> AppResult<bool> {
+        self.repository.delete(id).await
+    }
+
+    /// Delivers `event` to every active webhook subscribed to it. Returns immediately;
+    /// delivery and retries happen in background tasks.
+    pub fn dispatch(&self, event: &'static str, data: Value) {
+        let service = self.clone();
+        tokio::spawn(async move {
+            let webhooks = match service.repository.get_active_for_event(event).await {
+                Ok(webhooks) => webhooks,
+                Err(e) => {
+                    tracing::error!("Failed to load webhooks for {}: {}", event, e);
+                    return;
+                }
+            };
+
+            if webhooks.is_empty() {
+                return;
+            }
+
+            let body = json!({
+                "event": event,
+                "timestamp": Utc::now().to_rfc3339(),
+                "data": data,
+            })
+            .to_string();
+
+            for webhook in webhooks {
+                let service = service.clone();
+                let body = body.clone();
+                tokio::spawn(async move { service.deliver(&webhook, event, body).await });
+            }
+        });
+    }
+
+    /// POSTs the payload, retrying with exponential backoff on errors and non-2xx responses.
+    async fn deliver(&self, webhook: &Webhook, event: &str, body: String) {
+        let signature = sign_payload(&webhook.secret, body.as_bytes());
+        let mut delay = INITIAL_RETRY_DELAY;
+
+        for attempt in 1..=MAX_DELIVERY_ATTEMPTS {
+            let result = self
+                .client
+                .post(&webhook.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(WEBHOOK_EVENT_HEADER, event)
+                .header(WEBHOOK_SIGNATURE_HEADER, &signature)
+                .body(body.clone())
+                .send()
+                .await;
+
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    tracing::debug!("Delivered {} to webhook {}", event, webhook.id);
+                    return;
+                }
+                Ok(response) => tracing::warn!(
+                    "Webhook {} responded {} to {} (attempt {}/{})",
+                    webhook.id,
+                    response.status(),
+                    event,
+                    attempt,
+                    MAX_DELIVERY_ATTEMPTS
+                ),
+                Err(e) => tracing::warn!(
+                    "Webhook {} delivery of {} failed (attempt {}/{}): {}",
+                    webhook.id,
+                    event,
+                    attempt,
+                    MAX_DELIVERY_ATTEMPTS,
+                    e
+                ),
+            }
+
+            if attempt < MAX_DELIVERY_ATTEMPTS {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+        }
+
+        tracing::error!(
+            "Giving up on delivering {} to webhook {} after {} attempts",
+            event,
+            webhook.id,
+            MAX_DELIVERY_ATTEMPTS
+        );
+    }
+
+    fn validate_webhook(url: &str, events: &[String], secret: &str) -> AppResult<()> {
+        match reqwest::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {}
+            _ => {
+                return Err(AppError::ValidationError(format!(
+                    "Webhook URL '{}' must be an absolute http(s) URL",
+                    url
+                )))
+            }
+        }
+
+        if events.is_empty() {
+            return Err(AppError::ValidationError(
+                "Webhook must subscribe to at least one event".to_string(),
+            ));
+        }
+
+        if let Some(unknown) = events
+            .iter()
+            .find(|event| !WEBHOOK_EVENTS.contains(&event.as_str()))
+        {
+            return Err(AppError::ValidationError(format!(
+                "Unknown webhook event '{}'; expected one of: {}",
+                unknown,
+                WEBHOOK_EVENTS.join(", ")
+            )));
+        }
+
+        if secret.trim().is_empty() {
+            return Err(AppError::ValidationError(
+                "Webhook secret cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// `sha256=` followed by the hex HMAC-SHA256 of the body, as in GitHub webhooks.
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
@@ -0,0 +1,52 @@
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+use std::time::Duration;
+
+pub const OTEL_EXPORTER_OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+pub const SERVICE_NAME: &str = "log-server";
+
+/// How often histogram buckets of the metrics recorder are compacted.
+const METRICS_UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Builds an OTLP gRPC tracer provider exporting to `endpoint` and installs it as the
+/// global provider. Returns `None` when export is not configured.
+///
+/// Other `OTEL_EXPORTER_OTLP_*` settings are read from the environment by the exporter.
+pub fn init_tracer_provider(endpoint: Option<&str>) -> anyhow::Result<Option<SdkTracerProvider>> {
+    let Some(endpoint) = endpoint else {
+        return Ok(None);
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .build();
+
+    opentelemetry::global::set_tracer_provider(provider.clone());
+
+    Ok(Some(provider))
+}
+
+/// Installs the global Prometheus recorder behind `GET /metrics` and keeps it compacted.
+/// Must be called at most once, from within the Tokio runtime.
+pub fn init_metrics_recorder() -> anyhow::Result<PrometheusHandle> {
+    let handle = PrometheusBuilder::new().install_recorder()?;
+
+    let upkeep_handle = handle.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(METRICS_UPKEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            upkeep_handle.run_upkeep();
+        }
+    });
+
+    Ok(handle)
+}
@@ -0,0 +1,24 @@
+use axum::http::{header, HeaderMap};
+use sha2::{Digest, Sha256};
+
+use crate::dto::SchemaResponse;
+
+/// Strong ETag derived from the SHA-256 digest of the serialized response, so it
+/// changes whenever any returned field (tags, deprecation, `updated_at`, ...) does.
+pub fn compute_schema_etag(schema: &SchemaResponse) -> String {
+    let body = serde_json::to_vec(schema).expect("SchemaResponse is always serializable");
+
+    format!("\"{}\"", hex::encode(Sha256::digest(body)))
+}
+
+/// Returns true when the request's `If-None-Match` header lists `etag` (or `*`),
+/// meaning the client's cached copy is still current.
+pub fn if_none_match_satisfied(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|candidate| candidate.trim().trim_start_matches("W/"))
+        .any(|candidate| candidate == "*" || candidate == etag)
+}
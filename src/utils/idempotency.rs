@@ -0,0 +1,18 @@
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Maximum accepted length of a client-supplied idempotency key.
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// How long a stored idempotency key is honoured.
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// Scopes a client-supplied key to a schema so the same key may be reused across schemas.
+pub fn hash_idempotency_key(key: &str, schema_id: Uuid) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(schema_id.as_bytes());
+    hasher.update(key.as_bytes());
+    hex::encode(hasher.finalize())
+}
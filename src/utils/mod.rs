@@ -0,0 +1,7 @@
+pub mod etag;
+pub mod idempotency;
+pub mod openapi_import;
+pub mod remote_refs;
+
+pub use etag::{compute_schema_etag, if_none_match_satisfied};
+pub use idempotency::hash_idempotency_key;
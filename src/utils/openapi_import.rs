@@ -0,0 +1,69 @@
+use serde_json::{json, Map, Value};
+
+/// Returns the `components/schemas` map of an OpenAPI document. A bare
+/// `components/schemas` object is accepted as well, so both a full `openapi.json`
+/// and just its schemas section can be imported.
+pub fn component_schemas(body: &Value) -> Option<&Map<String, Value>> {
+    match body.pointer("/components/schemas") {
+        Some(schemas) => schemas.as_object(),
+        None if body.get("openapi").is_none() => body.as_object(),
+        None => None,
+    }
+}
+
+/// Replaces every local `$ref` in `schema` with the value it points to in `document`,
+/// recursively. External references and reference cycles are rejected since the
+/// stored schema must be self-contained.
+pub fn inline_refs(schema: &Value, document: &Value) -> Result<Value, String> {
+    inline(schema, document, &mut Vec::new())
+}
+
+/// Wraps a bare `components/schemas` map so `#/components/schemas/...` pointers resolve.
+pub fn as_document(body: &Value) -> Value {
+    if body.pointer("/components/schemas").is_some() {
+        body.clone()
+    } else {
+        json!({ "components": { "schemas": body } })
+    }
+}
+
+fn inline(value: &Value, document: &Value, stack: &mut Vec<String>) -> Result<Value, String> {
+    match value {
+        Value::Object(object) => {
+            if let Some(reference) = object.get("$ref") {
+                let reference = reference
+                    .as_str()
+                    .ok_or_else(|| "$ref must be a string".to_string())?;
+
+                let Some(pointer) = reference.strip_prefix('#') else {
+                    return Err(format!("External $ref '{}' is not supported", reference));
+                };
+
+                if stack.iter().any(|r| r == reference) {
+                    return Err(format!("Circular $ref '{}' cannot be inlined", reference));
+                }
+
+                let target = document
+                    .pointer(pointer)
+                    .ok_or_else(|| format!("$ref '{}' does not resolve", reference))?;
+
+                stack.push(reference.to_string());
+                let inlined = inline(target, document, stack);
+                stack.pop();
+                return inlined;
+            }
+
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), inline(value, document, stack)?)))
+                .collect::<Result<Map<_, _>, String>>()
+                .map(Value::Object)
+        }
+        Value::Array(items) => items
+            .iter()
+            .map(|item| inline(item, document, stack))
+            .collect::<Result<Vec<_>, String>>()
+            .map(Value::Array),
+        _ => Ok(value.clone()),
+    }
+}
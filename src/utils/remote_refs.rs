@@ -0,0 +1,272 @@
+use futures_util::future::BoxFuture;
+use reqwest::{redirect, Url};
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+/// Most documents fetched while resolving one schema.
+pub const MAX_REMOTE_DOCUMENTS: usize = 20;
+
+/// Largest remote document accepted (1MB).
+pub const MAX_REMOTE_DOCUMENT_BYTES: usize = 1024 * 1024;
+
+/// How long fetching one remote document may take.
+pub const REMOTE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Settings for [`inline_remote_refs`].
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    pub timeout: Duration,
+    /// Documents with a larger body are rejected
+    pub max_document_bytes: usize,
+    /// Also fetch from loopback and private addresses; only meant for tests
+    pub allow_private_hosts: bool,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            timeout: REMOTE_FETCH_TIMEOUT,
+            max_document_bytes: MAX_REMOTE_DOCUMENT_BYTES,
+            allow_private_hosts: false,
+        }
+    }
+}
+
+/// Replaces every remote (`http`/`https`) `$ref` in `definition` with the schema it
+/// points to, fetching each document once. References inside fetched documents are
+/// resolved against that document; local references in `definition` itself are left
+/// for the validator. References to hosts on private networks are kept as-is with a
+/// warning.
+///
+/// Each document is fetched from the addresses that passed the private network check,
+/// so DNS cannot be rebound in between, and redirects are not followed.
+pub async fn inline_remote_refs(
+    definition: &Value,
+    options: &FetchOptions,
+) -> Result<Value, String> {
+    let mut resolver = Resolver {
+        options,
+        documents: HashMap::new(),
+        stack: Vec::new(),
+    };
+    resolver.resolve(definition, None).await
+}
+
+/// Whether `ip` belongs to a loopback, private, link-local or otherwise non-public range.
+pub fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_private_ipv4(ip),
+        IpAddr::V6(ip) => {
+            let first_segment = ip.segments()[0];
+            ip.is_loopback()
+                || ip.is_unspecified()
+                // Unique local (fc00::/7) and link-local (fe80::/10) addresses
+                || (first_segment & 0xfe00) == 0xfc00
+                || (first_segment & 0xffc0) == 0xfe80
+                || ip.to_ipv4_mapped().is_some_and(is_private_ipv4)
+        }
+    }
+}
+
+fn is_private_ipv4(ip: Ipv4Addr) -> bool {
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Carrier-grade NAT (100.64.0.0/10)
+        || (ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64)
+}
+
+struct Resolver<'a> {
+    options: &'a FetchOptions,
+    /// Fetched documents by URL without fragment
+    documents: HashMap<Url, Value>,
+    /// References being inlined, to detect cycles
+    stack: Vec<String>,
+}
+
+impl<'a> Resolver<'a> {
+    /// `base` is the document `value` came from; `None` for the submitted definition.
+    fn resolve<'b>(
+        &'b mut self,
+        value: &'b Value,
+        base: Option<&'b Url>,
+    ) -> BoxFuture<'b, Result<Value, String>> {
+        Box::pin(async move {
+            match value {
+                Value::Object(object) => {
+                    if let Some(Value::String(reference)) = object.get("$ref") {
+                        if let Some(resolved) = self.resolve_ref(reference, base).await? {
+                            return Ok(resolved);
+                        }
+                        return Ok(value.clone());
+                    }
+
+                    let mut resolved = Map::with_capacity(object.len());
+                    for (key, value) in object {
+                        resolved.insert(key.clone(), self.resolve(value, base).await?);
+                    }
+                    Ok(Value::Object(resolved))
+                }
+                Value::Array(items) => {
+                    let mut resolved = Vec::with_capacity(items.len());
+                    for item in items {
+                        resolved.push(self.resolve(item, base).await?);
+                    }
+                    Ok(Value::Array(resolved))
+                }
+                _ => Ok(value.clone()),
+            }
+        })
+    }
+
+    /// The inlined target of `reference`, or `None` if it should stay a `$ref`.
+    async fn resolve_ref(
+        &mut self,
+        reference: &str,
+        base: Option<&Url>,
+    ) -> Result<Option<Value>, String> {
+        let url = match base {
+            Some(base) => base.join(reference),
+            // Local and relative references in the submitted definition are the validator's job
+            None if reference.starts_with('#') => return Ok(None),
+            None => Url::parse(reference),
+        };
+        let Ok(mut url) = url else {
+            return Ok(None);
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Ok(None);
+        }
+
+        let addrs = resolve_host(&url).await?;
+        if !self.options.allow_private_hosts && addrs.iter().any(|addr| is_private_ip(addr.ip())) {
+            tracing::warn!("Skipping $ref '{}': host is on a private network", url);
+            return Ok(None);
+        }
+
+        let absolute = url.to_string();
+        if self.stack.contains(&absolute) {
+            return Err(format!("Circular $ref '{}' cannot be inlined", absolute));
+        }
+
+        let fragment = url.fragment().unwrap_or_default().to_string();
+        url.set_fragment(None);
+
+        let target = self
+            .document(&url, &addrs)
+            .await?
+            .pointer(&fragment)
+            .cloned()
+            .ok_or_else(|| format!("$ref '{}' does not resolve", absolute))?;
+
+        self.stack.push(absolute);
+        let resolved = self.resolve(&target, Some(&url)).await;
+        self.stack.pop();
+        resolved.map(Some)
+    }
+
+    async fn document(&mut self, url: &Url, addrs: &[SocketAddr]) -> Result<&Value, String> {
+        if !self.documents.contains_key(url) {
+            if self.documents.len() >= MAX_REMOTE_DOCUMENTS {
+                return Err(format!(
+                    "Schema references more than {} remote documents",
+                    MAX_REMOTE_DOCUMENTS
+                ));
+            }
+
+            let document = fetch_document(url, addrs, self.options).await?;
+            self.documents.insert(url.clone(), document);
+        }
+
+        Ok(&self.documents[url])
+    }
+}
+
+/// Every address `url`'s host resolves to.
+async fn resolve_host(url: &Url) -> Result<Vec<SocketAddr>, String> {
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return Err(format!("$ref '{}' has no host", url));
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| format!("Failed to resolve host of $ref '{}': {}", url, e))?
+        .collect();
+    if addrs.is_empty() {
+        return Err(format!("Host of $ref '{}' has no addresses", url));
+    }
+    Ok(addrs)
+}
+
+/// Fetches `url` from `addrs` only, without following redirects, reading at most
+/// `options.max_document_bytes` of the body.
+async fn fetch_document(
+    url: &Url,
+    addrs: &[SocketAddr],
+    options: &FetchOptions,
+) -> Result<Value, String> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(options.timeout)
+        .redirect(redirect::Policy::none());
+    if let Some(domain) = url.domain() {
+        builder = builder.resolve_to_addrs(domain, addrs);
+    }
+    let client = builder
+        .build()
+        .map_err(|e| format!("Failed to fetch $ref '{}': {}", url, e))?;
+
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch $ref '{}': {}", url, e))?;
+
+    if response.status().is_redirection() {
+        return Err(format!(
+            "Failed to fetch $ref '{}': redirects are not followed",
+            url
+        ));
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to fetch $ref '{}': status {}",
+            url,
+            response.status()
+        ));
+    }
+
+    let too_large = || {
+        format!(
+            "$ref '{}' exceeds the maximum document size of {} bytes",
+            url, options.max_document_bytes
+        )
+    };
+    if response
+        .content_length()
+        .is_some_and(|length| length > options.max_document_bytes as u64)
+    {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to fetch $ref '{}': {}", url, e))?
+    {
+        if body.len() + chunk.len() > options.max_document_bytes {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    serde_json::from_slice(&body)
+        .map_err(|e| format!("$ref '{}' is not a JSON document: {}", url, e))
+}
@@ -0,0 +1,148 @@
+use log_server::{ErrorResponse, Schema};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+use crate::common::{valid_schema_payload, TestContext};
+
+#[tokio::test]
+async fn records_schema_create_and_delete() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("audit-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    ctx.client
+        .delete(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to delete schema");
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/audit?entity_type=schema&entity_id={}",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to get audit log");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data: Value = response.json().await.unwrap();
+    let entries = data["audit_logs"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0]["operation"], "delete");
+    assert_eq!(entries[0]["old_data"]["name"], "audit-test");
+    assert!(entries[0]["new_data"].is_null());
+
+    assert_eq!(entries[1]["operation"], "create");
+    assert!(entries[1]["old_data"].is_null());
+    assert_eq!(entries[1]["new_data"]["id"], schema.id.to_string());
+    assert!(entries[1]["request_id"].is_string());
+}
+
+#[tokio::test]
+async fn records_bulk_log_deletion_summary() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("audit-bulk-delete").await;
+    ctx.create_log(schema.id).await;
+    ctx.create_log(schema.id).await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/{}/before?timestamp=2100-01-01T00:00:00Z",
+            ctx.base_url, schema.name
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/audit?entity_type=log&entity_id={}",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to get audit log");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data: Value = response.json().await.unwrap();
+    let entries = data["audit_logs"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+
+    assert_eq!(entries[0]["operation"], "bulk_delete");
+    assert_eq!(entries[0]["old_data"]["schema_id"], schema.id.to_string());
+    assert_eq!(entries[0]["old_data"]["deleted_count"], 2);
+    assert_eq!(entries[0]["old_data"]["before"], "2100-01-01T00:00:00Z");
+    assert!(entries[0]["new_data"].is_null());
+}
+
+#[tokio::test]
+async fn records_filtered_log_deletion_summary() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("audit-filter-delete").await;
+    ctx.create_log(schema.id).await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/{}/bulk?after=2000-01-01T00:00:00Z",
+            ctx.base_url, schema.name
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/audit?entity_type=log&entity_id={}",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to get audit log");
+
+    let data: Value = response.json().await.unwrap();
+    let entries = data["audit_logs"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+
+    assert_eq!(entries[0]["operation"], "bulk_delete");
+    assert_eq!(entries[0]["old_data"]["deleted_count"], 1);
+    assert_eq!(entries[0]["old_data"]["after"], "2000-01-01T00:00:00Z");
+    assert!(entries[0]["old_data"]["before"].is_null());
+}
+
+#[tokio::test]
+async fn rejects_out_of_range_limit() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .get(format!("{}/audit?limit=0", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
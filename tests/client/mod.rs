@@ -0,0 +1 @@
+mod round_trip;
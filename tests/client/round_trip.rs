@@ -0,0 +1,63 @@
+use crate::common::TestContext;
+use futures_util::StreamExt;
+use log_server::{AppError, LogEvent};
+use tokio::time::{timeout, Duration};
+
+#[tokio::test]
+async fn creates_reads_lists_and_deletes_through_typed_client() {
+    let ctx = TestContext::new().await;
+
+    let schema = ctx.create_schema("client-round-trip-test").await;
+    assert_eq!(ctx.api.get_schema(schema.id).await.unwrap().id, schema.id);
+
+    let listed = ctx
+        .api
+        .list_schemas(Some("client-round-trip-test"), None)
+        .await
+        .unwrap();
+    assert!(listed.items.iter().any(|s| s.id == schema.id));
+
+    let log = ctx.create_log(schema.id).await;
+    let fetched = ctx.api.get_log(log.id).await.unwrap();
+    assert_eq!(fetched.log_data["message"], "Test log message");
+
+    let logs = ctx
+        .api
+        .list_logs("client-round-trip-test", None)
+        .await
+        .unwrap();
+    assert_eq!(logs.items.len(), 1);
+
+    ctx.api.delete_log(log.id).await.unwrap();
+    assert!(matches!(
+        ctx.api.get_log(log.id).await,
+        Err(AppError::NotFound(_))
+    ));
+
+    ctx.api.delete_schema(schema.id, true).await.unwrap();
+    assert!(matches!(
+        ctx.api.get_schema(schema.id).await,
+        Err(AppError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn subscription_yields_created_events() {
+    let ctx = TestContext::new().await;
+
+    let schema = ctx.create_schema("client-subscribe-test").await;
+    let mut events = ctx.api.subscribe_ws(Some(schema.id)).await.unwrap();
+
+    let log = ctx.create_log(schema.id).await;
+
+    let event = timeout(Duration::from_secs(5), events.next())
+        .await
+        .expect("Timeout waiting for WebSocket message")
+        .expect("WebSocket stream ended")
+        .expect("Failed to receive event");
+
+    match event {
+        LogEvent::Created { id, .. } => assert_eq!(id, log.id),
+        _ => panic!("Expected Created event"),
+    }
+}
@@ -1,3 +1,4 @@
+use log_server::{dto::LogResponse, LogServerClient, SchemaResponse};
 use reqwest::Client;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -5,6 +6,7 @@ use tokio::time::sleep;
 pub struct TestContext {
     pub client: Client,
     pub base_url: String,
+    pub api: LogServerClient,
 }
 
 impl TestContext {
@@ -15,9 +17,28 @@ impl TestContext {
 
         Self {
             client: Client::new(),
+            api: LogServerClient::new(&base_url),
             base_url,
         }
     }
+
+    /// Creates a schema from [`valid_schema_payload`](super::valid_schema_payload).
+    pub async fn create_schema(&self, name: &str) -> SchemaResponse {
+        let request = serde_json::from_value(super::valid_schema_payload(name)).unwrap();
+        self.api
+            .create_schema(&request)
+            .await
+            .expect("Failed to create schema")
+    }
+
+    /// Creates a log from [`valid_log_payload`](super::valid_log_payload).
+    pub async fn create_log(&self, schema_id: uuid::Uuid) -> LogResponse {
+        let request = serde_json::from_value(super::valid_log_payload(schema_id)).unwrap();
+        self.api
+            .create_log(&request)
+            .await
+            .expect("Failed to create log")
+    }
 }
 
 fn get_test_base_url() -> String {
@@ -30,7 +51,7 @@ async fn wait_for_service() -> Result<(), Box<dyn std::error::Error>> {
     let base_url = get_test_base_url();
 
     while retries > 0 {
-        match client.get(&format!("{}/health", base_url)).send().await {
+        match client.get(format!("{}/health", base_url)).send().await {
             Ok(response) if response.status().is_success() => {
                 return Ok(());
             }
@@ -25,3 +25,98 @@ pub fn valid_log_payload(schema_id: uuid::Uuid) -> serde_json::Value {
         }
     })
 }
+
+fn schema_payload(name: &str, schema_definition: serde_json::Value) -> serde_json::Value {
+    json!({
+        "name": name,
+        "version": "1.0.0",
+        "schema_definition": schema_definition
+    })
+}
+
+/// A schema whose `required` string properties are exactly `required`.
+pub fn schema_with_required_fields(name: &str, required: &[&str]) -> serde_json::Value {
+    let properties: serde_json::Map<String, serde_json::Value> = required
+        .iter()
+        .map(|field| (field.to_string(), json!({ "type": "string" })))
+        .collect();
+
+    schema_payload(
+        name,
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required
+        }),
+    )
+}
+
+/// A schema with a required string `field` limited to `values`.
+pub fn schema_with_enum_field(name: &str, field: &str, values: &[&str]) -> serde_json::Value {
+    schema_payload(
+        name,
+        json!({
+            "type": "object",
+            "properties": {
+                field: { "type": "string", "enum": values }
+            },
+            "required": [ field ]
+        }),
+    )
+}
+
+/// A schema with a required `user` object that itself requires an integer `id`.
+pub fn schema_with_nested_object(name: &str) -> serde_json::Value {
+    schema_payload(
+        name,
+        json!({
+            "type": "object",
+            "properties": {
+                "user": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "integer" },
+                        "email": { "type": "string" }
+                    },
+                    "required": [ "id" ]
+                }
+            },
+            "required": [ "user" ]
+        }),
+    )
+}
+
+/// Like [`valid_schema_payload`], but rejecting properties other than `message`.
+pub fn schema_with_additional_properties_false(name: &str) -> serde_json::Value {
+    schema_payload(
+        name,
+        json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" }
+            },
+            "required": [ "message" ],
+            "additionalProperties": false
+        }),
+    )
+}
+
+/// A schema with one required property of every JSON type, named after the type.
+pub fn schema_with_all_types(name: &str) -> serde_json::Value {
+    schema_payload(
+        name,
+        json!({
+            "type": "object",
+            "properties": {
+                "string": { "type": "string" },
+                "integer": { "type": "integer" },
+                "number": { "type": "number" },
+                "boolean": { "type": "boolean" },
+                "array": { "type": "array" },
+                "object": { "type": "object" },
+                "null": { "type": "null" }
+            },
+            "required": [ "string", "integer", "number", "boolean", "array", "object", "null" ]
+        }),
+    )
+}
@@ -1,5 +1,7 @@
 pub mod context;
 pub mod fixtures;
+pub mod ws_client;
 
 pub use context::TestContext;
 pub use fixtures::*;
+pub use ws_client::WebSocketTestClient;
@@ -0,0 +1,55 @@
+use futures_util::StreamExt;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::time::{sleep, timeout_at, Duration, Instant};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+/// WebSocket connection that can be dropped and re-established mid-test.
+pub struct WebSocketTestClient {
+    stream: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+}
+
+impl WebSocketTestClient {
+    pub async fn connect(url: &str) -> Self {
+        let (stream, _) = connect_async(url)
+            .await
+            .expect("Failed to connect to WebSocket");
+
+        Self {
+            stream: Some(stream),
+        }
+    }
+
+    pub async fn disconnect(&mut self) {
+        if let Some(mut stream) = self.stream.take() {
+            stream.close(None).await.ok();
+        }
+    }
+
+    /// Waits out `gap` while disconnected, then connects to `url`.
+    pub async fn reconnect_after(&mut self, gap: Duration, url: &str) {
+        self.disconnect().await;
+        sleep(gap).await;
+        *self = Self::connect(url).await;
+    }
+
+    /// Returns up to `n` JSON messages, stopping early once `timeout` has passed.
+    pub async fn collect_events(&mut self, n: usize, timeout: Duration) -> Vec<Value> {
+        let stream = self.stream.as_mut().expect("WebSocket is disconnected");
+        let deadline = Instant::now() + timeout;
+        let mut events = Vec::new();
+
+        while events.len() < n {
+            match timeout_at(deadline, stream.next()).await {
+                Ok(Some(Ok(Message::Text(text)))) => {
+                    events.push(serde_json::from_str(&text).expect("Failed to parse message"))
+                }
+                Ok(Some(Ok(_))) => continue,
+                Ok(Some(Err(e))) => panic!("Failed to receive message: {}", e),
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        events
+    }
+}
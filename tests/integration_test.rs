@@ -1,12 +1,16 @@
 #[path = "common/mod.rs"]
 mod common;
 
+mod audit;
+mod client;
 mod logs;
 mod schemas;
+mod unit;
+mod webhooks;
 mod websockets;
 
 mod health {
-    use crate::common::TestContext;
+    use crate::common::{valid_log_payload, TestContext};
     use reqwest::StatusCode;
 
     #[tokio::test]
@@ -15,7 +19,7 @@ mod health {
 
         let response = ctx
             .client
-            .get(&format!("{}/health", ctx.base_url))
+            .get(format!("{}/health", ctx.base_url))
             .send()
             .await
             .expect("Failed to send request");
@@ -26,6 +30,10 @@ mod health {
 
         assert_eq!(body["status"], "healthy");
         assert_eq!(body["service"], "log-server");
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+        assert!(body["build_date"].is_string());
+        assert!(body["git_commit"].is_string());
+        assert!(body["uptime_seconds"].as_f64().unwrap() >= 0.0);
         assert!(body["timestamp"].is_string());
     }
 
@@ -46,4 +54,425 @@ mod health {
 
         assert_eq!(body["status"], "healthy");
     }
+
+    #[tokio::test]
+    async fn metrics_endpoint_reports_schema_cache_hit_rate() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/metrics", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.text().await.unwrap();
+        assert!(body.contains("schema_cache_hit_rate"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn metrics_endpoint_reports_log_creation_timings() {
+        let ctx = TestContext::new().await;
+        let schema = ctx.create_schema("metrics-timings").await;
+        ctx.create_log(schema.id).await;
+
+        let body = ctx
+            .client
+            .get(format!("{}/metrics", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request")
+            .text()
+            .await
+            .unwrap();
+
+        for name in [
+            "log_schema_lookup_duration_seconds",
+            "log_validation_duration_seconds",
+            "log_db_write_duration_seconds",
+        ] {
+            assert!(body.contains(name), "missing {}: {}", name, body);
+        }
+        assert!(
+            body.contains(&format!("schema_id=\"{}\"", schema.id)),
+            "{}",
+            body
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics_endpoint_reports_keyed_log_creation_timings() {
+        let ctx = TestContext::new().await;
+        let schema = ctx.create_schema("metrics-keyed-timings").await;
+
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .header("Idempotency-Key", uuid::Uuid::new_v4().to_string())
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = ctx
+            .client
+            .get(format!("{}/metrics", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request")
+            .text()
+            .await
+            .unwrap();
+
+        let label = format!("schema_id=\"{}\"", schema.id);
+        for name in [
+            "log_schema_lookup_duration_seconds",
+            "log_validation_duration_seconds",
+            "log_db_write_duration_seconds",
+        ] {
+            assert!(
+                body.lines()
+                    .any(|line| line.starts_with(name) && line.contains(&label)),
+                "missing {} for {}: {}",
+                name,
+                schema.id,
+                body
+            );
+        }
+    }
+}
+
+mod fallback {
+    use crate::common::TestContext;
+    use log_server::ErrorResponse;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn unknown_route_returns_json_404() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/no-such-endpoint", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(response.headers().contains_key("x-request-id"));
+
+        let error: ErrorResponse = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(error.error, "NOT_FOUND");
+        assert_eq!(error.message, "The requested endpoint does not exist");
+    }
+
+    #[tokio::test]
+    async fn unsupported_method_returns_json_405() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .patch(format!("{}/health", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert!(response.headers().contains_key("x-request-id"));
+
+        let error: ErrorResponse = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(error.error, "METHOD_NOT_ALLOWED");
+    }
+}
+
+mod problem_details {
+    use crate::common::TestContext;
+    use log_server::ErrorResponse;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn error_responses_are_problem_details() {
+        let ctx = TestContext::new().await;
+
+        let path = format!("/schemas/{}", uuid::Uuid::new_v4());
+        let response = ctx
+            .client
+            .get(format!("{}{}", ctx.base_url, path))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/problem+json"
+        );
+
+        let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(body["type"], "about:blank");
+        assert_eq!(body["title"], "Not Found");
+        assert_eq!(body["status"], 404);
+        assert_eq!(body["instance"], path);
+        assert_eq!(body["detail"], body["message"]);
+
+        let error: ErrorResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(error.error, "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn successful_responses_stay_plain_json() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/health", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.headers()["content-type"], "application/json");
+    }
+}
+
+mod request_id {
+    use crate::common::TestContext;
+    use axum::{routing::get, Router};
+    use log_server::middleware::request_id::{parse_traceparent, trace_requests};
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    async fn echoed_request_id(ctx: &TestContext, request_id: &str) -> String {
+        let response = ctx
+            .client
+            .get(format!("{}/health", ctx.base_url))
+            .header("X-Request-ID", request_id)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        response
+            .headers()
+            .get("X-Request-ID")
+            .expect("Missing X-Request-ID header")
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn valid_request_id_is_preserved() {
+        let ctx = TestContext::new().await;
+
+        assert_eq!(
+            echoed_request_id(&ctx, "client-trace_42").await,
+            "client-trace_42"
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_request_id_is_replaced() {
+        let ctx = TestContext::new().await;
+
+        for invalid in ["has spaces", "semi;colon", &"a".repeat(129)] {
+            let echoed = echoed_request_id(&ctx, invalid).await;
+            assert_ne!(echoed, invalid);
+            assert!(uuid::Uuid::parse_str(&echoed).is_ok());
+        }
+    }
+
+    #[test]
+    fn parses_w3c_traceparent() {
+        assert_eq!(
+            parse_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+            Some(("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7"))
+        );
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(parse_traceparent(invalid), None, "{}", invalid);
+        }
+    }
+
+    /// Collects everything the subscriber writes.
+    #[derive(Clone, Default)]
+    struct CapturedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn request_id_is_recorded_on_request_span() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = trace_requests(Router::new().route(
+            "/",
+            get(|| async {
+                tracing::info!("handled");
+            }),
+        ));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let response = reqwest::Client::new()
+            .get(format!("http://{}/", addr))
+            .header("X-Request-ID", "span-check-42")
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.headers()["X-Request-ID"], "span-check-42");
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let handled = output
+            .lines()
+            .find(|line| line.contains("handled"))
+            .expect("handler event was not logged");
+        assert!(handled.contains("request_id=span-check-42"), "{}", handled);
+    }
+
+    #[tokio::test]
+    async fn requests_with_traceparent_succeed() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/health", ctx.base_url))
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .header("tracestate", "vendor=value")
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert!(response.status().is_success());
+    }
+}
+
+mod admin {
+    use crate::common::TestContext;
+    use reqwest::StatusCode;
+
+    #[tokio::test]
+    async fn stats_count_logs_across_the_server() {
+        let ctx = TestContext::new().await;
+
+        let schema = ctx.create_schema("admin-stats-test").await;
+        ctx.create_log(schema.id).await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/admin/stats", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        assert!(body["total_schemas"].as_i64().unwrap() >= 1);
+        assert!(body["total_logs"].as_i64().unwrap() >= 1);
+        assert!(body["logs_last_hour"].as_i64().unwrap() >= 1);
+        assert!(
+            body["logs_last_24h"].as_i64().unwrap() >= body["logs_last_hour"].as_i64().unwrap()
+        );
+        assert!(body["oldest_log"].is_string());
+        assert!(body["newest_log"].is_string());
+    }
+
+    #[tokio::test]
+    async fn migrate_reports_nothing_pending_after_startup() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .post(format!("{}/admin/migrate", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+        assert_eq!(body["applied"], serde_json::json!([]));
+    }
+}
+
+mod openapi {
+    use crate::common::TestContext;
+    use reqwest::StatusCode;
+    use utoipa::OpenApi;
+
+    #[tokio::test]
+    async fn openapi_spec_documents_all_endpoints() {
+        let ctx = TestContext::new().await;
+
+        let response = ctx
+            .client
+            .get(format!("{}/openapi.json", ctx.base_url))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let spec: serde_json::Value = response.json().await.expect("Failed to parse JSON");
+
+        for path in [
+            "/schemas",
+            "/schemas/{id}",
+            "/logs",
+            "/logs/{id}",
+            "/ws/logs",
+        ] {
+            assert!(spec["paths"][path].is_object(), "missing path {}", path);
+        }
+        assert!(spec["components"]["schemas"]["ErrorResponse"].is_object());
+    }
+
+    #[test]
+    fn startup_route_list_covers_documented_routes() {
+        let routes = log_server::openapi::routes(&log_server::ApiDoc::openapi());
+
+        assert!(routes
+            .iter()
+            .any(|(method, path, _)| *method == "DELETE" && path == "/logs/{id}"));
+        for (method, path, summary) in &routes {
+            assert!(!summary.is_empty(), "{} {} has no summary", method, path);
+        }
+    }
+}
+
+mod app_state {
+    use log_server::AppStateBuilder;
+
+    #[test]
+    fn builder_reports_missing_fields() {
+        let error = AppStateBuilder::new().build().err().unwrap();
+        assert!(error.to_string().contains("config"), "{}", error);
+    }
 }
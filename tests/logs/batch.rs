@@ -0,0 +1,185 @@
+use log_server::Schema;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::common::{valid_log_payload, valid_schema_payload, TestContext};
+
+async fn create_schema(ctx: &TestContext, name: &str) -> Schema {
+    ctx.client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload(name))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn creates_all_logs_in_batch() {
+    let ctx = TestContext::new().await;
+    let schema = create_schema(&ctx, "batch-all-test").await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .json(&json!({
+            "logs": [valid_log_payload(schema.id), valid_log_payload(schema.id)]
+        }))
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["total_submitted"], 2);
+    assert_eq!(body["total_created"], 2);
+    assert_eq!(body["total_failed"], 0);
+    assert_eq!(body["created"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn reports_partial_success_with_multi_status() {
+    let ctx = TestContext::new().await;
+    let schema = create_schema(&ctx, "batch-partial-test").await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .json(&json!({
+            "logs": [
+                valid_log_payload(schema.id),
+                { "schema_id": schema.id, "log_data": { "level": "INFO" } },
+                { "schema_id": Uuid::new_v4(), "log_data": { "message": "unknown schema" } }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["total_created"], 1);
+    assert_eq!(body["total_failed"], 2);
+    assert_eq!(body["failed"][0]["index"], 1);
+    assert_eq!(body["failed"][0]["error_code"], "VALIDATION_FAILED");
+    assert_eq!(body["failed"][1]["index"], 2);
+    assert_eq!(body["failed"][1]["error_code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn fail_fast_writes_nothing_when_any_entry_is_invalid() {
+    let ctx = TestContext::new().await;
+    let schema = create_schema(&ctx, "batch-fail-fast-test").await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .json(&json!({
+            "fail_fast": true,
+            "logs": [
+                valid_log_payload(schema.id),
+                { "schema_id": schema.id, "log_data": { "level": "INFO" } }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["total_created"], 0);
+    assert_eq!(body["failed"][0]["index"], 1);
+
+    let logs: Value = ctx
+        .client
+        .get(format!("{}/logs/schema/batch-fail-fast-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert!(logs["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn rejects_empty_batch() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .json(&json!({ "logs": [] }))
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn accepts_ndjson_body() {
+    let ctx = TestContext::new().await;
+    let schema = create_schema(&ctx, "batch-ndjson-test").await;
+
+    let body = format!(
+        "{}\n\n{}\n",
+        valid_log_payload(schema.id),
+        json!({ "schema_id": schema.id, "log_data": { "level": "INFO" } })
+    );
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch?fail_fast=true", ctx.base_url))
+        .header("Content-Type", "application/x-ndjson")
+        .body(body.clone())
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let result: Value = response.json().await.unwrap();
+    assert_eq!(result["total_submitted"], 2);
+    assert_eq!(result["total_created"], 0);
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .header("Content-Type", "application/x-ndjson")
+        .body(body)
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+    let result: Value = response.json().await.unwrap();
+    assert_eq!(result["total_created"], 1);
+    assert_eq!(result["failed"][0]["index"], 1);
+}
+
+#[tokio::test]
+async fn rejects_malformed_ndjson_line() {
+    let ctx = TestContext::new().await;
+    let schema = create_schema(&ctx, "batch-ndjson-invalid-test").await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .header("Content-Type", "application/x-ndjson")
+        .body(format!("{}\nnot json\n", valid_log_payload(schema.id)))
+        .send()
+        .await
+        .expect("Failed to send batch");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["error"], "INVALID_INPUT");
+    assert!(error["message"].as_str().unwrap().contains("line 2"));
+}
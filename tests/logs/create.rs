@@ -3,7 +3,11 @@ use reqwest::StatusCode;
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::common::{valid_log_payload, valid_schema_payload, TestContext};
+use crate::common::{
+    schema_with_additional_properties_false, schema_with_all_types, schema_with_enum_field,
+    schema_with_nested_object, schema_with_required_fields, valid_log_payload,
+    valid_schema_payload, TestContext,
+};
 
 #[tokio::test]
 async fn creates_log_with_valid_data() {
@@ -11,7 +15,7 @@ async fn creates_log_with_valid_data() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("log-create-test"))
         .send()
         .await
@@ -21,7 +25,7 @@ async fn creates_log_with_valid_data() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -32,10 +36,50 @@ async fn creates_log_with_valid_data() {
     let log: Log = response.json().await.unwrap();
     assert_eq!(log.schema_id, schema.id);
     assert_eq!(log.log_data["message"], "Test log message");
+    assert_eq!(
+        log.schema_version_at_creation.as_deref(),
+        Some(schema.version.as_str())
+    );
     assert!(log.id > 0);
     assert!(log.created_at.timestamp() > 0);
 }
 
+#[tokio::test]
+async fn returns_201_with_location_header() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("log-location-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let location = response
+        .headers()
+        .get("Location")
+        .expect("Location header should be present")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let log: Log = response.json().await.unwrap();
+    assert_eq!(location, format!("/logs/{}", log.id));
+}
+
 #[tokio::test]
 async fn rejects_nonexistent_schema_id() {
     let ctx = TestContext::new().await;
@@ -50,7 +94,7 @@ async fn rejects_nonexistent_schema_id() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&log_payload)
         .send()
         .await
@@ -76,7 +120,7 @@ async fn rejects_nil_schema_id() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&log_payload)
         .send()
         .await
@@ -100,7 +144,7 @@ async fn rejects_missing_required_fields() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&invalid_payload)
         .send()
         .await
@@ -115,7 +159,7 @@ async fn validates_log_data_against_schema() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("validation-test"))
         .send()
         .await
@@ -132,7 +176,7 @@ async fn validates_log_data_against_schema() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&invalid_log_payload)
         .send()
         .await
@@ -144,13 +188,125 @@ async fn validates_log_data_against_schema() {
     assert_eq!(error.error, "VALIDATION_FAILED");
 }
 
+#[tokio::test]
+async fn reports_field_errors_for_invalid_log() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&json!({
+            "name": "field-errors-test",
+            "version": "1.0.0",
+            "schema_definition": {
+                "type": "object",
+                "properties": {
+                    "message": { "type": "string" },
+                    "context": {
+                        "type": "object",
+                        "properties": {
+                            "status": { "type": "integer" }
+                        }
+                    }
+                },
+                "required": [ "message" ]
+            }
+        }))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": {
+                "context": { "status": "not-a-number" }
+            }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "VALIDATION_FAILED");
+
+    let field_errors = error
+        .field_errors
+        .expect("field_errors should be populated");
+    assert!(field_errors.contains_key("message"));
+    assert!(field_errors.contains_key("context.status"));
+}
+
+#[tokio::test]
+async fn reports_json_pointer_paths_for_validation_errors() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&json!({
+            "name": "pointer-errors-test",
+            "version": "1.0.0",
+            "schema_definition": {
+                "type": "object",
+                "properties": {
+                    "message": { "type": "string" },
+                    "status": { "type": "integer" }
+                },
+                "required": [ "message" ]
+            }
+        }))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "status": "not-a-number" }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    let errors = error
+        .validation_errors
+        .expect("validation_errors should be populated");
+    assert_eq!(errors.len(), 2);
+
+    let missing = errors
+        .iter()
+        .find(|error| error.schema_path == "/required")
+        .expect("missing required field should be reported");
+    assert_eq!(missing.path, "");
+
+    let mismatch = errors
+        .iter()
+        .find(|error| error.schema_path == "/properties/status/type")
+        .expect("type mismatch should be reported");
+    assert_eq!(mismatch.path, "/status");
+}
+
 #[tokio::test]
 async fn accepts_additional_properties() {
     let ctx = TestContext::new().await;
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("additional-props-test"))
         .send()
         .await
@@ -172,7 +328,7 @@ async fn accepts_additional_properties() {
 
     let response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&log_payload)
         .send()
         .await
@@ -185,3 +341,525 @@ async fn accepts_additional_properties() {
     assert_eq!(log.log_data["level"], "INFO");
     assert_eq!(log.log_data["extra_data"]["nested"], "value");
 }
+
+#[tokio::test]
+async fn rejects_additional_properties_when_schema_forbids_them() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_additional_properties_false(
+            "closed-props-test",
+        ))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "message": "Required field", "level": "INFO" }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "VALIDATION_FAILED");
+}
+
+#[tokio::test]
+async fn rejects_value_outside_enum() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_enum_field(
+            "enum-field-test",
+            "level",
+            &["INFO", "WARN", "ERROR"],
+        ))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for (level, expected_status) in [
+        ("WARN", StatusCode::CREATED),
+        ("DEBUG", StatusCode::BAD_REQUEST),
+        ("warn", StatusCode::BAD_REQUEST),
+    ] {
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&json!({
+                "schema_id": schema.id,
+                "log_data": { "level": level }
+            }))
+            .send()
+            .await
+            .expect("Failed to send create log request");
+
+        assert_eq!(response.status(), expected_status, "level {}", level);
+    }
+}
+
+#[tokio::test]
+async fn reports_nested_paths_for_invalid_nested_object() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_nested_object("nested-object-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "user": { "id": "not-a-number" } }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    let errors = error
+        .validation_errors
+        .expect("validation_errors should be populated");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "/user/id");
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "user": { "email": "user@example.com" } }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    let errors = error
+        .validation_errors
+        .expect("validation_errors should be populated");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "/user");
+}
+
+#[tokio::test]
+async fn validates_every_json_type() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_all_types("all-types-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let valid = json!({
+        "string": "text",
+        "integer": 42,
+        "number": 1.5,
+        "boolean": true,
+        "array": [1, "two"],
+        "object": { "key": "value" },
+        "null": null
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({ "schema_id": schema.id, "log_data": valid }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    for (field, wrong_value) in [
+        ("string", json!(1)),
+        ("integer", json!(1.5)),
+        ("number", json!("1.5")),
+        ("boolean", json!("true")),
+        ("array", json!({})),
+        ("object", json!([])),
+        ("null", json!(0)),
+    ] {
+        let mut log_data = valid.clone();
+        log_data[field] = wrong_value;
+
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&json!({ "schema_id": schema.id, "log_data": log_data }))
+            .send()
+            .await
+            .expect("Failed to send create log request");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::BAD_REQUEST,
+            "field {}",
+            field
+        );
+
+        let error: ErrorResponse = response.json().await.unwrap();
+        let errors = error
+            .validation_errors
+            .expect("validation_errors should be populated");
+        assert_eq!(errors[0].path, format!("/{}", field));
+    }
+}
+
+#[tokio::test]
+async fn validates_against_the_targeted_schema_version() {
+    let ctx = TestContext::new().await;
+
+    let v1: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_required_fields(
+            "version-migration-test",
+            &["message"],
+        ))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let mut v2_payload =
+        schema_with_required_fields("version-migration-test", &["message", "level"]);
+    v2_payload["version"] = json!("2.0.0");
+    let v2: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&v2_payload)
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let log_data = json!({ "message": "written for 1.0.0" });
+
+    for (schema, expected_status) in [(&v1, StatusCode::CREATED), (&v2, StatusCode::BAD_REQUEST)] {
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&json!({ "schema_id": schema.id, "log_data": log_data }))
+            .send()
+            .await
+            .expect("Failed to send create log request");
+
+        assert_eq!(
+            response.status(),
+            expected_status,
+            "version {}",
+            schema.version
+        );
+    }
+}
+
+#[tokio::test]
+async fn rejects_request_body_exceeding_limit() {
+    let ctx = TestContext::new().await;
+
+    let log_payload = json!({
+        "schema_id": Uuid::new_v4(),
+        "log_data": {
+            "message": "x".repeat(2 * 1024 * 1024)
+        }
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_payload)
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn rejects_log_data_exceeding_size_limit() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("log-data-size-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    // Within the 1MB body limit but above the default 64KB log_data limit
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "message": "x".repeat(100 * 1024) }
+        }))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "PAYLOAD_TOO_LARGE");
+    assert!(error.message.contains("65536 bytes"));
+}
+
+#[tokio::test]
+async fn returns_original_log_for_repeated_idempotency_key() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("idempotency-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+    let idempotency_key = Uuid::new_v4().to_string();
+
+    let first_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(first_response.status(), StatusCode::CREATED);
+    let first_log: Log = first_response.json().await.unwrap();
+
+    let retry_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(retry_response.status(), StatusCode::OK);
+    let retried_log: Log = retry_response.json().await.unwrap();
+    assert_eq!(retried_log.id, first_log.id);
+
+    let other_key_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("Idempotency-Key", Uuid::new_v4().to_string())
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(other_key_response.status(), StatusCode::CREATED);
+    let other_log: Log = other_key_response.json().await.unwrap();
+    assert_ne!(other_log.id, first_log.id);
+}
+
+#[tokio::test]
+async fn rejects_empty_idempotency_key() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("Idempotency-Key", "")
+        .json(&valid_log_payload(Uuid::new_v4()))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn records_source_from_body_or_user_agent() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("log-source-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let mut payload = valid_log_payload(schema.id);
+    payload["source"] = json!("billing-service");
+
+    let explicit_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("User-Agent", "ignored-agent/1.0")
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(explicit_response.status(), StatusCode::CREATED);
+    let explicit_log: Log = explicit_response.json().await.unwrap();
+    assert_eq!(explicit_log.source.as_deref(), Some("billing-service"));
+
+    let user_agent_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .header("User-Agent", "checkout.worker/2.1 (linux)")
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to send create log request");
+
+    assert_eq!(user_agent_response.status(), StatusCode::CREATED);
+    let user_agent_log: Log = user_agent_response.json().await.unwrap();
+    assert_eq!(user_agent_log.source.as_deref(), Some("checkout.worker"));
+}
+
+#[tokio::test]
+async fn rejects_invalid_source() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("log-invalid-source-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    for source in ["has spaces", &"a".repeat(256)] {
+        let mut payload = valid_log_payload(schema.id);
+        payload["source"] = json!(source);
+
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .expect("Failed to send create log request");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let error: ErrorResponse = response.json().await.unwrap();
+        assert_eq!(error.error, "INVALID_INPUT");
+    }
+}
+
+#[tokio::test]
+async fn stores_attachments_with_the_log() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("attachments-create-test").await;
+
+    let mut payload = valid_log_payload(schema.id);
+    payload["attachments"] = json!([{
+        "url": "https://artifacts.example.com/dumps/heap-42.hprof",
+        "name": "heap-42.hprof",
+        "content_type": "application/octet-stream"
+    }]);
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["attachments"], payload["attachments"]);
+}
+
+#[tokio::test]
+async fn rejects_invalid_attachments() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("attachments-invalid-test").await;
+
+    let attachment =
+        |url: &str, name: &str| json!({ "url": url, "name": name, "content_type": "image/png" });
+    let too_many: Vec<_> = (0..11)
+        .map(|i| attachment("https://example.com/a.png", &format!("a{}.png", i)))
+        .collect();
+
+    for attachments in [
+        json!([attachment("http://example.com/a.png", "a.png")]),
+        json!([attachment("https://10.0.0.5/a.png", "a.png")]),
+        json!([attachment("https://[::1]/a.png", "a.png")]),
+        json!([attachment("https://localhost/a.png", "a.png")]),
+        json!([attachment("not a url", "a.png")]),
+        json!([attachment("https://example.com/a.png", &"a".repeat(256))]),
+        json!(too_many),
+    ] {
+        let mut payload = valid_log_payload(schema.id);
+        payload["attachments"] = attachments.clone();
+
+        let response = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(
+            response.status(),
+            StatusCode::BAD_REQUEST,
+            "{}",
+            attachments
+        );
+    }
+}
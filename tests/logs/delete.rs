@@ -9,7 +9,7 @@ async fn deletes_existing_log_successfully() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("delete-test"))
         .send()
         .await
@@ -19,7 +19,7 @@ async fn deletes_existing_log_successfully() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -29,7 +29,7 @@ async fn deletes_existing_log_successfully() {
 
     let delete_response = ctx
         .client
-        .delete(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .delete(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .expect("Failed to delete log");
@@ -44,7 +44,7 @@ async fn returns_404_for_nonexistent_log() {
 
     let response = ctx
         .client
-        .delete(&format!("{}/logs/{}", ctx.base_url, 99999))
+        .delete(format!("{}/logs/{}", ctx.base_url, 99999))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -61,7 +61,7 @@ async fn rejects_invalid_log_id_format() {
 
     let response = ctx
         .client
-        .delete(&format!("{}/logs/invalid", ctx.base_url))
+        .delete(format!("{}/logs/invalid", ctx.base_url))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -75,7 +75,7 @@ async fn log_not_accessible_after_deletion() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("access-after-delete"))
         .send()
         .await
@@ -85,7 +85,7 @@ async fn log_not_accessible_after_deletion() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -95,7 +95,7 @@ async fn log_not_accessible_after_deletion() {
 
     let get_response = ctx
         .client
-        .get(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .get(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .unwrap();
@@ -103,7 +103,7 @@ async fn log_not_accessible_after_deletion() {
 
     let delete_response = ctx
         .client
-        .delete(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .delete(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .unwrap();
@@ -111,7 +111,7 @@ async fn log_not_accessible_after_deletion() {
 
     let get_after_delete = ctx
         .client
-        .get(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .get(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .unwrap();
@@ -124,7 +124,7 @@ async fn double_delete_returns_404() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("double-delete"))
         .send()
         .await
@@ -134,7 +134,7 @@ async fn double_delete_returns_404() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -144,7 +144,7 @@ async fn double_delete_returns_404() {
 
     let first_delete = ctx
         .client
-        .delete(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .delete(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .unwrap();
@@ -152,7 +152,7 @@ async fn double_delete_returns_404() {
 
     let second_delete = ctx
         .client
-        .delete(&format!("{}/logs/{}", ctx.base_url, log.id))
+        .delete(format!("{}/logs/{}", ctx.base_url, log.id))
         .send()
         .await
         .unwrap();
@@ -161,3 +161,288 @@ async fn double_delete_returns_404() {
     let error: ErrorResponse = second_delete.json().await.unwrap();
     assert_eq!(error.error, "NOT_FOUND");
 }
+
+#[tokio::test]
+async fn deletes_logs_created_before_timestamp() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("delete-before-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let cutoff = (chrono::Utc::now() + chrono::Duration::seconds(1))
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/delete-before-test/before?timestamp={}",
+            ctx.base_url, cutoff
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted_count"], 2);
+
+    let logs: serde_json::Value = ctx
+        .client
+        .get(format!("{}/logs/schema/delete-before-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert!(logs["items"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn delete_before_defaults_to_highest_version() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("delete-before-v2-test");
+    payload["version"] = serde_json::json!("2.0.0");
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+    ctx.create_log(schema.id).await;
+
+    let cutoff = (chrono::Utc::now() + chrono::Duration::seconds(1))
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/delete-before-v2-test/before?timestamp={}",
+            ctx.base_url, cutoff
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted_count"], 1);
+}
+
+#[tokio::test]
+async fn rejects_invalid_delete_before_timestamp() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/any-schema/before?timestamp=yesterday",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn deletes_logs_matching_filter() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("delete-filter-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for source in ["batch-job", "api", "batch-job"] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["source"] = serde_json::json!(source);
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/delete-filter-test/bulk",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/delete-filter-test/bulk?source=batch-job",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted_count"], 2);
+
+    let logs: serde_json::Value = ctx
+        .client
+        .get(format!("{}/logs/schema/delete-filter-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    let logs = logs["items"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0]["source"], "api");
+}
+
+#[tokio::test]
+async fn delete_by_filter_defaults_to_highest_version() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("delete-filter-v2-test");
+    payload["version"] = serde_json::json!("2.0.0");
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+    ctx.create_log(schema.id).await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/logs/schema/delete-filter-v2-test/bulk?before=2999-01-01T00:00:00Z",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted_count"], 1);
+}
+
+#[tokio::test]
+async fn deletes_schema_logs_but_keeps_schema() {
+    let ctx = TestContext::new().await;
+
+    let schema = ctx.create_schema("delete-schema-logs-test").await;
+    ctx.create_log(schema.id).await;
+    ctx.create_log(schema.id).await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/schemas/{}/logs?before=2000-01-01T00:00:00Z",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = ctx
+        .client
+        .delete(format!("{}/schemas/{}/logs", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["deleted_count"], 2);
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to get schema");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .delete(format!("{}/schemas/{}/logs", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to delete logs");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn delete_schema_logs_returns_404_for_nonexistent_schema() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/schemas/{}/logs",
+            ctx.base_url,
+            uuid::Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "NOT_FOUND");
+}
@@ -10,7 +10,7 @@ async fn retrieves_log_by_id() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("read-test"))
         .send()
         .await
@@ -20,7 +20,7 @@ async fn retrieves_log_by_id() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -30,7 +30,7 @@ async fn retrieves_log_by_id() {
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/{}", ctx.base_url, created_log.id))
+        .get(format!("{}/logs/{}", ctx.base_url, created_log.id))
         .send()
         .await
         .expect("Failed to retrieve log");
@@ -43,13 +43,68 @@ async fn retrieves_log_by_id() {
     assert_eq!(retrieved_log.log_data["message"], "Test log message");
 }
 
+#[tokio::test]
+async fn includes_schema_in_single_log_when_requested() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("single-include-schema-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let created_log: Log = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to create log")
+        .json()
+        .await
+        .unwrap();
+
+    let log: Value = ctx
+        .client
+        .get(format!(
+            "{}/logs/{}?include_schema=true",
+            ctx.base_url, created_log.id
+        ))
+        .send()
+        .await
+        .expect("Failed to retrieve log")
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(log["schema_name"], "single-include-schema-test");
+    assert_eq!(log["schema_version"], "1.0.0");
+
+    let log: Value = ctx
+        .client
+        .get(format!("{}/logs/{}", ctx.base_url, created_log.id))
+        .send()
+        .await
+        .expect("Failed to retrieve log")
+        .json()
+        .await
+        .unwrap();
+
+    assert!(log.get("schema_name").is_none());
+}
+
 #[tokio::test]
 async fn returns_404_for_nonexistent_log() {
     let ctx = TestContext::new().await;
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/{}", ctx.base_url, 99999))
+        .get(format!("{}/logs/{}", ctx.base_url, 99999))
         .send()
         .await
         .expect("Failed to send request");
@@ -58,6 +113,7 @@ async fn returns_404_for_nonexistent_log() {
 
     let error: ErrorResponse = response.json().await.unwrap();
     assert_eq!(error.error, "NOT_FOUND");
+    assert_eq!(error.resource_type.as_deref(), Some("log"));
 }
 
 #[tokio::test]
@@ -66,7 +122,7 @@ async fn rejects_invalid_log_id_format() {
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/invalid", ctx.base_url))
+        .get(format!("{}/logs/invalid", ctx.base_url))
         .send()
         .await
         .expect("Failed to send request");
@@ -80,7 +136,7 @@ async fn gets_logs_by_schema_name() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("logs-by-name"))
         .send()
         .await
@@ -97,7 +153,7 @@ async fn gets_logs_by_schema_name() {
         });
 
         ctx.client
-            .post(&format!("{}/logs", ctx.base_url))
+            .post(format!("{}/logs", ctx.base_url))
             .json(&log_payload)
             .send()
             .await
@@ -106,7 +162,7 @@ async fn gets_logs_by_schema_name() {
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/schema/{}", ctx.base_url, "logs-by-name"))
+        .get(format!("{}/logs/schema/{}", ctx.base_url, "logs-by-name"))
         .send()
         .await
         .expect("Failed to get logs");
@@ -114,7 +170,7 @@ async fn gets_logs_by_schema_name() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let data: Value = response.json().await.unwrap();
-    let logs = data["logs"].as_array().unwrap();
+    let logs = data["items"].as_array().unwrap();
     assert_eq!(logs.len(), 3);
 }
 
@@ -124,7 +180,7 @@ async fn gets_logs_by_schema_name_and_version() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("logs-by-name-version"))
         .send()
         .await
@@ -133,7 +189,7 @@ async fn gets_logs_by_schema_name_and_version() {
     let schema: Schema = schema_response.json().await.unwrap();
 
     ctx.client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -141,7 +197,7 @@ async fn gets_logs_by_schema_name_and_version() {
 
     let response = ctx
         .client
-        .get(&format!(
+        .get(format!(
             "{}/logs/schema/{}/{}",
             ctx.base_url, "logs-by-name-version", "1.0.0"
         ))
@@ -152,7 +208,7 @@ async fn gets_logs_by_schema_name_and_version() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let data: Value = response.json().await.unwrap();
-    let logs = data["logs"].as_array().unwrap();
+    let logs = data["items"].as_array().unwrap();
     assert_eq!(logs.len(), 1);
 }
 
@@ -162,7 +218,7 @@ async fn filters_logs_with_query_parameters() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&json!({
             "name": "filter-test",
             "version": "1.0.0",
@@ -191,7 +247,7 @@ async fn filters_logs_with_query_parameters() {
         });
 
         ctx.client
-            .post(&format!("{}/logs", ctx.base_url))
+            .post(format!("{}/logs", ctx.base_url))
             .json(&log_payload)
             .send()
             .await
@@ -200,7 +256,7 @@ async fn filters_logs_with_query_parameters() {
 
     let response = ctx
         .client
-        .get(&format!(
+        .get(format!(
             "{}/logs/schema/filter-test?level=ERROR",
             ctx.base_url
         ))
@@ -211,18 +267,252 @@ async fn filters_logs_with_query_parameters() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let data: Value = response.json().await.unwrap();
-    let logs = data["logs"].as_array().unwrap();
+    let logs = data["items"].as_array().unwrap();
     assert_eq!(logs.len(), 1);
     assert_eq!(logs[0]["log_data"]["level"], "ERROR");
 }
 
+#[tokio::test]
+async fn counts_only_filtered_logs_in_total() {
+    let ctx = TestContext::new().await;
+
+    let mut schema_payload = valid_schema_payload("filtered-count-test");
+    schema_payload["schema_definition"]["properties"]["level"] = json!({ "type": "string" });
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_payload)
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for level in ["INFO", "ERROR", "INFO", "INFO"] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["log_data"]["level"] = json!(level);
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/filtered-count-test?level=INFO&limit=2",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get filtered logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "3");
+
+    let data: Value = response.json().await.unwrap();
+    assert_eq!(data["items"].as_array().unwrap().len(), 2);
+    assert_eq!(data["total"], 3);
+}
+
+#[tokio::test]
+async fn sorts_logs_by_log_data_field() {
+    let ctx = TestContext::new().await;
+
+    let mut schema_payload = valid_schema_payload("sort-field-test");
+    schema_payload["schema_definition"]["properties"]["level"] = json!({ "type": "string" });
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_payload)
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for level in ["WARN", "ERROR", "INFO"] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["log_data"]["level"] = json!(level);
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    for (order, expected) in [
+        ("", ["ERROR", "INFO", "WARN"]),
+        ("&sort_order=desc", ["WARN", "INFO", "ERROR"]),
+    ] {
+        let data: Value = ctx
+            .client
+            .get(format!(
+                "{}/logs/schema/sort-field-test?sort_field=level{}",
+                ctx.base_url, order
+            ))
+            .send()
+            .await
+            .expect("Failed to get sorted logs")
+            .json()
+            .await
+            .unwrap();
+
+        let levels: Vec<&str> = data["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|log| log["log_data"]["level"].as_str().unwrap())
+            .collect();
+        assert_eq!(levels, expected);
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/sort-field-test?sort_field=level;DROP",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn filters_logs_by_severity_range() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("severity-filter-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for severity in [json!(0), json!(2), json!(3), json!(5), Value::Null] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["severity"] = severity;
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/severity-filter-test?min_severity=2&max_severity=4",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs");
+
+    assert_eq!(response.headers()["x-total-count"], "2");
+    let data: Value = response.json().await.unwrap();
+    let mut severities: Vec<i64> = data["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|log| log["severity"].as_i64().unwrap())
+        .collect();
+    severities.sort();
+    assert_eq!(severities, [2, 3]);
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/severity-filter-test?min_severity=4&max_severity=1",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let mut log_payload = valid_log_payload(schema.id);
+    log_payload["severity"] = json!(6);
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_payload)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn filters_logs_by_source() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("source-filter-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    for source in ["api-gateway", "worker", "api-gateway"] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["source"] = json!(source);
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/source-filter-test?source=worker",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get filtered logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data: Value = response.json().await.unwrap();
+    let logs = data["items"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0]["source"], "worker");
+}
+
 #[tokio::test]
 async fn returns_404_for_nonexistent_schema_name() {
     let ctx = TestContext::new().await;
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/schema/nonexistent-schema", ctx.base_url))
+        .get(format!("{}/logs/schema/nonexistent-schema", ctx.base_url))
         .send()
         .await
         .expect("Failed to send request");
@@ -231,6 +521,8 @@ async fn returns_404_for_nonexistent_schema_name() {
 
     let error: ErrorResponse = response.json().await.unwrap();
     assert_eq!(error.error, "NOT_FOUND");
+    assert_eq!(error.resource_type.as_deref(), Some("schema"));
+    assert!(error.message.contains("nonexistent-schema"));
 }
 
 #[tokio::test]
@@ -239,10 +531,536 @@ async fn rejects_empty_schema_name() {
 
     let response = ctx
         .client
-        .get(&format!("{}/logs/schema/", ctx.base_url))
+        .get(format!("{}/logs/schema/", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn compresses_log_listing_when_requested() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("logs-compression-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    ctx.client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/logs-compression-test/1.0.0",
+            ctx.base_url
+        ))
+        .header("Accept-Encoding", "gzip")
+        .send()
+        .await
+        .expect("Failed to get logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response
+            .headers()
+            .get("Content-Encoding")
+            .expect("Content-Encoding header should be present"),
+        "gzip"
+    );
+}
+
+#[tokio::test]
+async fn paginates_logs_with_limit_and_offset() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("pagination-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/pagination-test?limit=2&offset=0",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs");
+    assert_eq!(response.headers()["x-total-count"], "3");
+
+    let data: Value = response.json().await.unwrap();
+
+    assert_eq!(data["items"].as_array().unwrap().len(), 2);
+    assert_eq!(data["total"], 3);
+    assert_eq!(data["limit"], 2);
+    assert_eq!(data["offset"], 0);
+    assert_eq!(data["has_more"], true);
+
+    let data: Value = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/pagination-test?limit=2&offset=2",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(data["items"].as_array().unwrap().len(), 1);
+    assert_eq!(data["has_more"], false);
+
+    let data: Value = ctx
+        .client
+        .get(format!("{}/logs/schema/pagination-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(data["limit"], 100);
+    assert_eq!(data["offset"], 0);
+    assert!(data.get("total").is_none());
+    assert!(data.get("has_more").is_none());
+}
+
+#[tokio::test]
+async fn rejects_invalid_pagination_params() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .get(format!("{}/logs/schema/any-schema?limit=0", ctx.base_url))
         .send()
         .await
         .expect("Failed to send request");
 
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn includes_schema_name_and_version_when_requested() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("include-schema-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    ctx.client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    let data: Value = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/include-schema-test?include_schema=true",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    let logs = data["items"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0]["schema_name"], "include-schema-test");
+    assert_eq!(logs[0]["schema_version"], "1.0.0");
+
+    let data: Value = ctx
+        .client
+        .get(format!("{}/logs/schema/include-schema-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert!(data["items"][0].get("schema_name").is_none());
+}
+
+#[tokio::test]
+async fn groups_log_counts_by_time_bucket() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("grouped-by-time-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/grouped-by-time-test/1.0.0/grouped-by-time?bucket=day",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get grouped logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data: Value = response.json().await.unwrap();
+    let buckets = data["buckets"].as_array().unwrap();
+    let total: i64 = buckets
+        .iter()
+        .map(|bucket| bucket["count"].as_i64().unwrap())
+        .sum();
+    assert_eq!(total, 3);
+    assert!(buckets.iter().all(|bucket| bucket["time"].is_string()));
+
+    let data: Value = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/grouped-by-time-test/1.0.0/grouped-by-time?from=2020-01-01T00:00:00Z&to=2020-01-02T00:00:00Z",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get grouped logs")
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(data["buckets"], json!([]));
+}
+
+#[tokio::test]
+async fn rejects_invalid_time_bucket() {
+    let ctx = TestContext::new().await;
+
+    ctx.client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("grouped-by-time-invalid-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    for query in [
+        "bucket=month",
+        "from=yesterday",
+        "from=2025-01-02T00:00:00Z&to=2025-01-01T00:00:00Z",
+    ] {
+        let response = ctx
+            .client
+            .get(format!(
+                "{}/logs/schema/grouped-by-time-invalid-test/1.0.0/grouped-by-time?{}",
+                ctx.base_url, query
+            ))
+            .send()
+            .await
+            .expect("Failed to send request");
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+
+        let error: ErrorResponse = response.json().await.unwrap();
+        assert_eq!(error.error, "INVALID_INPUT");
+    }
+}
+
+#[tokio::test]
+async fn unversioned_listing_uses_highest_schema_version() {
+    let ctx = TestContext::new().await;
+
+    // Created out of order; 1.10.0 must win over 1.9.0 despite sorting lower as a string
+    let mut schemas = Vec::new();
+    for version in ["1.10.0", "1.9.0"] {
+        let mut payload = valid_schema_payload("latest-version-test");
+        payload["version"] = json!(version);
+
+        let schema: Schema = ctx
+            .client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .expect("Failed to create schema")
+            .json()
+            .await
+            .unwrap();
+        schemas.push(schema);
+    }
+
+    for schema in &schemas {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let data: Value = ctx
+        .client
+        .get(format!("{}/logs/schema/latest-version-test", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get logs")
+        .json()
+        .await
+        .unwrap();
+
+    let logs = data["items"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0]["schema_id"], json!(schemas[0].id));
+}
+
+#[tokio::test]
+async fn lists_logs_across_schema_versions() {
+    let ctx = TestContext::new().await;
+
+    let mut schemas = Vec::new();
+    for version in ["2.0.0", "1.0.0"] {
+        let mut payload = valid_schema_payload("across-versions-test");
+        payload["version"] = json!(version);
+
+        let schema: Schema = ctx
+            .client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .expect("Failed to create schema")
+            .json()
+            .await
+            .unwrap();
+        schemas.push(schema);
+    }
+
+    for schema in &schemas {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/across-versions-test/across-versions?limit=1",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "2");
+
+    let data: Value = response.json().await.unwrap();
+    assert_eq!(data["schema_versions_queried"], json!(["1.0.0", "2.0.0"]));
+
+    let logs = data["logs"].as_array().unwrap();
+    assert_eq!(logs.len(), 1);
+    // Newest first: the 1.0.0 log was created last
+    assert_eq!(logs[0]["schema_id"], json!(schemas[1].id));
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/logs/schema/nonexistent-schema/across-versions",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to get logs");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn lists_logs_by_correlation_id_across_schemas() {
+    let ctx = TestContext::new().await;
+
+    let mut schemas = Vec::new();
+    for name in ["correlation-gateway", "correlation-worker"] {
+        let schema: Schema = ctx
+            .client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&valid_schema_payload(name))
+            .send()
+            .await
+            .expect("Failed to create schema")
+            .json()
+            .await
+            .unwrap();
+        schemas.push(schema);
+    }
+
+    for (schema, correlation_id) in [
+        (&schemas[0], "req-42"),
+        (&schemas[1], "req-42"),
+        (&schemas[1], "req-43"),
+    ] {
+        let mut log_payload = valid_log_payload(schema.id);
+        log_payload["correlation_id"] = json!(correlation_id);
+
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&log_payload)
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!("{}/logs/correlation/req-42", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to get correlated logs");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "2");
+
+    let data: Value = response.json().await.unwrap();
+    let logs = data["items"].as_array().unwrap();
+    assert_eq!(logs.len(), 2);
+    // Oldest first, each with the schema it was logged against
+    assert_eq!(logs[0]["schema_name"], "correlation-gateway");
+    assert_eq!(logs[1]["schema_name"], "correlation-worker");
+    assert_eq!(logs[1]["schema_version"], "1.0.0");
+    assert!(logs.iter().all(|log| log["correlation_id"] == "req-42"));
+}
+
+#[tokio::test]
+async fn head_matches_get_without_body() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("log-head-test").await;
+    let log = ctx.create_log(schema.id).await;
+
+    for path in [format!("/logs/{}", log.id), "/logs/2147483647".to_string()] {
+        let url = format!("{}{}", ctx.base_url, path);
+        let get = ctx.client.get(&url).send().await.unwrap();
+        let head = ctx.client.head(&url).send().await.unwrap();
+
+        assert_eq!(head.status(), get.status(), "{}", path);
+        assert_eq!(
+            head.headers().get("content-type"),
+            get.headers().get("content-type")
+        );
+        assert!(head.bytes().await.unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn lists_attachments_of_a_log() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("attachments-read-test").await;
+
+    let attachments = json!([
+        { "url": "https://example.com/shot.png", "name": "shot.png", "content_type": "image/png" },
+        { "url": "https://example.com/trace.json", "name": "trace.json", "content_type": "application/json" }
+    ]);
+    let mut payload = valid_log_payload(schema.id);
+    payload["attachments"] = attachments.clone();
+
+    let log: Log = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to create log")
+        .json()
+        .await
+        .unwrap();
+
+    let body: Value = ctx
+        .client
+        .get(format!("{}/logs/{}/attachments", ctx.base_url, log.id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        body,
+        json!({ "log_id": log.id, "attachments": attachments })
+    );
+
+    let without = ctx.create_log(schema.id).await;
+    let body: Value = ctx
+        .client
+        .get(format!("{}/logs/{}/attachments", ctx.base_url, without.id))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["attachments"], json!([]));
+
+    let response = ctx
+        .client
+        .get(format!("{}/logs/999999999/attachments", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
@@ -1,6 +1,9 @@
+use log_server::middleware::body_limit::SCHEMA_MAX_REQUEST_BODY_BYTES;
 use log_server::{ErrorResponse, Schema};
 use reqwest::StatusCode;
 use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
 use uuid::Uuid;
 
 use crate::common::{valid_schema_payload, TestContext, TEST_SCHEMA_NAME, TEST_SCHEMA_VERSION};
@@ -11,7 +14,7 @@ async fn creates_schema_with_valid_data() {
 
     let response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload(TEST_SCHEMA_NAME))
         .send()
         .await
@@ -36,7 +39,7 @@ async fn returns_201_with_location_header() {
 
     let response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("location-test"))
         .send()
         .await
@@ -57,7 +60,7 @@ async fn rejects_duplicate_schema_name() {
     let ctx = TestContext::new().await;
 
     ctx.client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("duplicate"))
         .send()
         .await
@@ -65,7 +68,7 @@ async fn rejects_duplicate_schema_name() {
 
     let response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("duplicate"))
         .send()
         .await
@@ -77,6 +80,28 @@ async fn rejects_duplicate_schema_name() {
     assert!(error.message.contains("already exists"));
 }
 
+#[tokio::test]
+async fn rejects_non_semver_version() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("semver-test");
+    payload["version"] = json!("foo");
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+    assert!(error.message.contains("valid semver"));
+}
+
 #[tokio::test]
 async fn rejects_missing_required_fields() {
     let ctx = TestContext::new().await;
@@ -87,7 +112,7 @@ async fn rejects_missing_required_fields() {
 
     let response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&invalid_payload)
         .send()
         .await
@@ -98,3 +123,82 @@ async fn rejects_missing_required_fields() {
     let error_text = response.text().await.unwrap();
     assert!(error_text.contains("missing field") || error_text.contains("name"));
 }
+
+#[tokio::test]
+async fn rejects_required_field_missing_from_properties() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("required-not-in-properties-test");
+    payload["schema_definition"] = json!({
+        "type": "object",
+        "properties": { "message": { "type": "string" } },
+        "required": ["message", "level"]
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_SCHEMA");
+    assert!(error.message.contains("'level'"), "{}", error.message);
+}
+
+#[tokio::test]
+async fn accepts_schema_above_default_body_limit() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("large-description-test");
+    payload["description"] = json!("x".repeat(2 * 1024 * 1024));
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn rejects_schema_exceeding_body_limit() {
+    let ctx = TestContext::new().await;
+
+    // With `Expect: 100-continue` the body is only sent once the server asks for it, so
+    // the 413 (decided from Content-Length) cannot race a half-written upload.
+    let address = ctx.base_url.trim_start_matches("http://");
+    let mut stream = TcpStream::connect(address)
+        .await
+        .expect("Failed to connect");
+    let request = format!(
+        "POST /schemas HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nExpect: 100-continue\r\nConnection: close\r\n\r\n",
+        address,
+        SCHEMA_MAX_REQUEST_BODY_BYTES + 1
+    );
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .expect("Failed to send request");
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .await
+        .expect("Failed to read response");
+    let response = String::from_utf8(response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+
+    assert!(head.starts_with("HTTP/1.1 413"), "{}", head);
+
+    let error: ErrorResponse = serde_json::from_str(body).expect("Failed to parse JSON");
+    assert_eq!(error.error, "PAYLOAD_TOO_LARGE");
+}
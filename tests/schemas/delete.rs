@@ -23,7 +23,7 @@ async fn deletes_existing_schema_successfully() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&schema_payload)
         .send()
         .await
@@ -33,7 +33,7 @@ async fn deletes_existing_schema_successfully() {
 
     let delete_response = ctx
         .client
-        .delete(&format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .delete(format!("{}/schemas/{}", ctx.base_url, schema.id))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -50,7 +50,7 @@ async fn returns_404_for_nonexistent_schema() {
 
     let response = ctx
         .client
-        .delete(&format!("{}/schemas/{}", ctx.base_url, non_existent_id))
+        .delete(format!("{}/schemas/{}", ctx.base_url, non_existent_id))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -68,7 +68,7 @@ async fn rejects_invalid_uuid_format() {
 
     let response = ctx
         .client
-        .delete(&format!("{}/schemas/invalid-uuid", ctx.base_url))
+        .delete(format!("{}/schemas/invalid-uuid", ctx.base_url))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -84,7 +84,7 @@ async fn rejects_nil_uuid() {
 
     let response = ctx
         .client
-        .delete(&format!("{}/schemas/{}", ctx.base_url, nil_uuid))
+        .delete(format!("{}/schemas/{}", ctx.base_url, nil_uuid))
         .send()
         .await
         .expect("Failed to send delete request");
@@ -116,7 +116,7 @@ async fn schema_not_accessible_after_deletion() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&schema_payload)
         .send()
         .await
@@ -126,7 +126,7 @@ async fn schema_not_accessible_after_deletion() {
 
     let get_response = ctx
         .client
-        .get(&format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
         .send()
         .await
         .unwrap();
@@ -134,7 +134,7 @@ async fn schema_not_accessible_after_deletion() {
 
     let delete_response = ctx
         .client
-        .delete(&format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .delete(format!("{}/schemas/{}", ctx.base_url, schema.id))
         .send()
         .await
         .unwrap();
@@ -142,7 +142,7 @@ async fn schema_not_accessible_after_deletion() {
 
     let get_after_delete_response = ctx
         .client
-        .get(&format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
         .send()
         .await
         .unwrap();
@@ -0,0 +1,78 @@
+use log_server::Schema;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+use crate::common::{valid_schema_payload, TestContext};
+
+async fn create_schema_with_examples(ctx: &TestContext, name: &str) -> Schema {
+    let mut payload = valid_schema_payload(name);
+    payload["examples"] = json!([{ "message": "hello" }, { "message": 42 }]);
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .expect("Failed to create schema");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn stores_examples_in_schema_definition() {
+    let ctx = TestContext::new().await;
+
+    let schema = create_schema_with_examples(&ctx, "examples-stored").await;
+
+    assert_eq!(
+        schema.schema_definition["examples"],
+        json!([{ "message": "hello" }, { "message": 42 }])
+    );
+}
+
+#[tokio::test]
+async fn validates_stored_examples_by_index() {
+    let ctx = TestContext::new().await;
+
+    let schema = create_schema_with_examples(&ctx, "examples-validate").await;
+    let url = format!("{}/schemas/{}/validate-example", ctx.base_url, schema.id);
+
+    let response = ctx.client.post(&url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["index"], 0);
+    assert_eq!(body["valid"], true);
+    assert!(body.get("field_errors").is_none());
+
+    let response = ctx
+        .client
+        .post(format!("{}?index=1", url))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["valid"], false);
+    assert!(body["field_errors"]["message"].is_array());
+}
+
+#[tokio::test]
+async fn returns_404_for_missing_example() {
+    let ctx = TestContext::new().await;
+
+    let schema = create_schema_with_examples(&ctx, "examples-missing").await;
+
+    let response = ctx
+        .client
+        .post(format!(
+            "{}/schemas/{}/validate-example?index=5",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
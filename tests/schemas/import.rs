@@ -0,0 +1,121 @@
+use log_server::dto::{BulkCreateSchemaResponse, ValidationReport};
+use log_server::ErrorResponse;
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::common::{valid_schema_payload, TestContext};
+
+#[tokio::test]
+async fn imports_openapi_components_with_inlined_refs() {
+    let ctx = TestContext::new().await;
+
+    let document = json!({
+        "openapi": "3.0.3",
+        "info": { "title": "Orders", "version": "1.0.0" },
+        "paths": {},
+        "components": {
+            "schemas": {
+                "import-address": {
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": [ "city" ]
+                },
+                "import-order": {
+                    "type": "object",
+                    "description": "An order event",
+                    "properties": {
+                        "shipping": { "$ref": "#/components/schemas/import-address" }
+                    }
+                },
+                "import-external": {
+                    "$ref": "https://example.com/schemas/external.json"
+                }
+            }
+        }
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas/import-openapi", ctx.base_url))
+        .json(&document)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let result: BulkCreateSchemaResponse = response.json().await.unwrap();
+    assert_eq!(result.created.len(), 2);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].name, "import-external");
+    assert!(result.failed[0].error.contains("External $ref"));
+
+    let order = result
+        .created
+        .iter()
+        .find(|schema| schema.name == "import-order")
+        .expect("import-order should be created");
+
+    assert_eq!(order.version, "1.0.0");
+    assert_eq!(order.description.as_deref(), Some("An order event"));
+    assert_eq!(
+        order.schema_definition["properties"]["shipping"]["required"],
+        json!(["city"])
+    );
+}
+
+#[tokio::test]
+async fn rejects_body_without_schemas() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas/import-openapi", ctx.base_url))
+        .json(&json!({ "openapi": "3.0.3", "paths": {} }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn validates_schemas_without_creating_them() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas/validate", ctx.base_url))
+        .json(&json!([
+            valid_schema_payload("dry-run-valid"),
+            {
+                "name": "dry-run-invalid",
+                "version": "1.0.0",
+                "schema_definition": { "type": "no-such-type" }
+            },
+            valid_schema_payload("dry-run-valid"),
+        ]))
+        .send()
+        .await
+        .expect("Failed to validate schemas");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let report: ValidationReport = response.json().await.unwrap();
+    assert!(!report.valid);
+    let indexes: Vec<usize> = report.errors.iter().map(|error| error.index).collect();
+    assert_eq!(indexes, [1, 2]);
+    assert_eq!(report.errors[1].name, "dry-run-valid");
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/dry-run-valid/1.0.0", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -1,3 +1,4 @@
+use log_server::{dto::PaginatedResponse, SchemaResponse};
 use reqwest::StatusCode;
 
 use crate::common::{valid_schema_payload, TestContext};
@@ -8,22 +9,22 @@ async fn lists_all_schemas() {
 
     let initial_response = ctx
         .client
-        .get(&format!("{}/schemas", ctx.base_url))
+        .get(format!("{}/schemas", ctx.base_url))
         .send()
         .await
         .unwrap();
     let initial_data: serde_json::Value = initial_response.json().await.unwrap();
-    let initial_count = initial_data["schemas"].as_array().unwrap().len();
+    let initial_count = initial_data["items"].as_array().unwrap().len();
 
     ctx.client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("list-test-1"))
         .send()
         .await
         .unwrap();
 
     ctx.client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("list-test-2"))
         .send()
         .await
@@ -31,7 +32,7 @@ async fn lists_all_schemas() {
 
     let response = ctx
         .client
-        .get(&format!("{}/schemas", ctx.base_url))
+        .get(format!("{}/schemas", ctx.base_url))
         .send()
         .await
         .unwrap();
@@ -39,7 +40,7 @@ async fn lists_all_schemas() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let data: serde_json::Value = response.json().await.unwrap();
-    let schemas = data["schemas"].as_array().unwrap();
+    let schemas = data["items"].as_array().unwrap();
     assert_eq!(
         schemas.len(),
         initial_count + 2,
@@ -53,3 +54,282 @@ async fn lists_all_schemas() {
     assert!(schema_names.contains(&"list-test-1"));
     assert!(schema_names.contains(&"list-test-2"));
 }
+
+#[tokio::test]
+async fn paginates_schemas_filtered_by_name() {
+    let ctx = TestContext::new().await;
+
+    for version in ["1.0.0", "1.1.0", "2.0.0"] {
+        let mut payload = valid_schema_payload("list-pagination-test");
+        payload["version"] = serde_json::json!(version);
+
+        ctx.client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas?name=list-pagination-test&limit=2",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "3");
+
+    let data: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(data["items"].as_array().unwrap().len(), 2);
+    assert_eq!(data["total"], 3);
+    assert_eq!(data["has_more"], true);
+}
+
+#[tokio::test]
+async fn sets_total_count_header_without_pagination() {
+    let ctx = TestContext::new().await;
+
+    ctx.client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("total-count-header-test"))
+        .send()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas?name=total-count-header-test",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "1");
+
+    let data: serde_json::Value = response.json().await.unwrap();
+    assert!(data.get("total").is_none());
+}
+
+#[tokio::test]
+async fn streams_schemas_as_json_array() {
+    let ctx = TestContext::new().await;
+
+    for version in ["1.0.0", "1.1.0"] {
+        let mut payload = valid_schema_payload("stream-test");
+        payload["version"] = serde_json::json!(version);
+
+        ctx.client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas?name=stream-test&stream=true",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/json");
+
+    let schemas: Vec<serde_json::Value> = response.json().await.unwrap();
+    assert_eq!(schemas.len(), 2);
+    assert!(schemas.iter().all(|schema| schema["name"] == "stream-test"));
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas?stream=true&include_stats=true",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn searches_schemas_by_name_and_description() {
+    let ctx = TestContext::new().await;
+
+    let mut payload = valid_schema_payload("search-payments");
+    payload["description"] = serde_json::json!("Audit events emitted by the billing service");
+    ctx.client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&payload)
+        .send()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/search?q=billing+audit", ctx.base_url))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let data: serde_json::Value = response.json().await.unwrap();
+    let items = data["items"].as_array().unwrap();
+    assert!(items
+        .iter()
+        .any(|schema| schema["name"] == "search-payments"));
+
+    let data: serde_json::Value = ctx
+        .client
+        .get(format!(
+            "{}/schemas/search?q=nonexistentkeyword",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(data["items"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn rejects_empty_search_query() {
+    let ctx = TestContext::new().await;
+
+    for query in ["", "?q=", "?q=%20"] {
+        let response = ctx
+            .client
+            .get(format!("{}/schemas/search{}", ctx.base_url, query))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{}", query);
+    }
+}
+
+#[tokio::test]
+async fn pages_by_seq_after_cursor() {
+    let ctx = TestContext::new().await;
+    let first = ctx.create_schema("seq-page-first").await;
+    let second = ctx.create_schema("seq-page-second").await;
+    assert!(second.seq > first.seq);
+
+    let page: PaginatedResponse<SchemaResponse> = ctx
+        .client
+        .get(format!(
+            "{}/schemas?after_seq={}&limit=1",
+            ctx.base_url,
+            first.seq - 1
+        ))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, first.id);
+
+    let page: PaginatedResponse<SchemaResponse> = ctx
+        .client
+        .get(format!(
+            "{}/schemas?after_seq={}&limit=1000",
+            ctx.base_url, first.seq
+        ))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert!(page.items.iter().all(|schema| schema.seq > first.seq));
+    assert!(page.items.iter().any(|schema| schema.id == second.id));
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas?after_seq={}", ctx.base_url, second.seq))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let total: i64 = response
+        .headers()
+        .get("X-Total-Count")
+        .expect("missing X-Total-Count")
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(total >= 2);
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas?after_seq=0&offset=10", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn filters_by_name_substring_ignoring_case() {
+    let ctx = TestContext::new().await;
+    let matching = ctx.create_schema("billing-AUDIT-events").await;
+    let other = ctx.create_schema("billing-metrics").await;
+    let underscore = ctx.create_schema("contains_under").await;
+    let lookalike = ctx.create_schema("containsXunder").await;
+
+    let page: PaginatedResponse<SchemaResponse> = ctx
+        .client
+        .get(format!("{}/schemas?name_contains=audit-ev", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    let ids: Vec<_> = page.items.iter().map(|schema| schema.id).collect();
+    assert!(ids.contains(&matching.id));
+    assert!(!ids.contains(&other.id));
+    assert!(page
+        .items
+        .iter()
+        .all(|schema| schema.name.to_lowercase().contains("audit-ev")));
+
+    // `_` is not a wildcard
+    let page: PaginatedResponse<SchemaResponse> = ctx
+        .client
+        .get(format!("{}/schemas?name_contains=s_u", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    let ids: Vec<_> = page.items.iter().map(|schema| schema.id).collect();
+    assert!(ids.contains(&underscore.id));
+    assert!(!ids.contains(&lookalike.id));
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas?name_contains=_&name=x", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
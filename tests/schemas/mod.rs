@@ -1,5 +1,7 @@
 pub mod create;
 pub mod delete;
+pub mod examples;
+pub mod import;
 pub mod list;
 pub mod read;
 pub mod update;
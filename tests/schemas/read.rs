@@ -1,14 +1,16 @@
 use log_server::Schema;
 use reqwest::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
 
-use crate::common::{valid_schema_payload, TestContext};
+use crate::common::{valid_log_payload, valid_schema_payload, TestContext};
 
 #[tokio::test]
 async fn retrieves_existing_schema_by_id() {
     let ctx = TestContext::new().await;
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("get-test"))
         .send()
         .await
@@ -18,7 +20,7 @@ async fn retrieves_existing_schema_by_id() {
 
     let response = ctx
         .client
-        .get(&format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
         .send()
         .await
         .unwrap();
@@ -35,7 +37,7 @@ async fn retrieves_existing_schema_by_name_and_version() {
     let ctx = TestContext::new().await;
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("get-test-2"))
         .send()
         .await
@@ -45,7 +47,7 @@ async fn retrieves_existing_schema_by_name_and_version() {
 
     let response = ctx
         .client
-        .get(&format!(
+        .get(format!(
             "{}/schemas/{}/{}",
             ctx.base_url, schema.name, schema.version
         ))
@@ -66,7 +68,7 @@ async fn returns_404_for_nonexistent_schema() {
 
     let response = ctx
         .client
-        .get(&format!(
+        .get(format!(
             "{}/schemas/{}",
             ctx.base_url, "7182c4cb-24dc-4142-890c-3c7755ba673e"
         ))
@@ -83,10 +85,501 @@ async fn rejects_invalid_uuid_format() {
 
     let response = ctx
         .client
-        .get(&format!("{}/schemas/{}", ctx.base_url, "not-a-uuid"))
+        .get(format!("{}/schemas/{}", ctx.base_url, "not-a-uuid"))
         .send()
         .await
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
+
+#[tokio::test]
+async fn returns_etag_header() {
+    let ctx = TestContext::new().await;
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("etag-test"))
+        .send()
+        .await
+        .unwrap();
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let by_id = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .send()
+        .await
+        .unwrap();
+
+    let by_name = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/{}",
+            ctx.base_url, schema.name, schema.version
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    let etag = by_id
+        .headers()
+        .get("ETag")
+        .expect("ETag header should be present");
+
+    assert!(etag.to_str().unwrap().starts_with('"'));
+    assert_eq!(by_name.headers().get("ETag"), Some(etag));
+}
+
+#[tokio::test]
+async fn returns_304_when_etag_matches() {
+    let ctx = TestContext::new().await;
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("etag-not-modified-test"))
+        .send()
+        .await
+        .unwrap();
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .send()
+        .await
+        .unwrap();
+
+    let etag = response.headers().get("ETag").unwrap().clone();
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get("ETag"), Some(&etag));
+    assert!(response.text().await.unwrap().is_empty());
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .header("If-None-Match", "\"stale\"")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn etag_changes_when_schema_is_deprecated_or_tagged() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("etag-metadata-test").await;
+    let schema_url = format!("{}/schemas/{}", ctx.base_url, schema.id);
+
+    let response = ctx.client.get(&schema_url).send().await.unwrap();
+    let original_etag = response.headers().get("ETag").unwrap().clone();
+
+    ctx.client
+        .post(format!("{}/deprecate", schema_url))
+        .send()
+        .await
+        .expect("Failed to deprecate schema");
+
+    let response = ctx
+        .client
+        .get(&schema_url)
+        .header("If-None-Match", original_etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let deprecated_etag = response.headers().get("ETag").unwrap().clone();
+    assert_ne!(deprecated_etag, original_etag);
+    let body: Schema = response.json().await.unwrap();
+    assert!(body.deprecated);
+
+    ctx.client
+        .patch(format!("{}/tags", schema_url))
+        .json(&json!({ "add": ["prod"] }))
+        .send()
+        .await
+        .expect("Failed to patch tags");
+
+    let response = ctx
+        .client
+        .get(&schema_url)
+        .header("If-None-Match", deprecated_etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers().get("ETag"), Some(&deprecated_etag));
+    let body: Schema = response.json().await.unwrap();
+    assert_eq!(body.tags, vec!["prod"]);
+}
+
+#[tokio::test]
+async fn includes_log_count_when_stats_requested() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("stats-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        ctx.client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log");
+    }
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}?include_stats=true",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to get schema");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("etag").is_none());
+
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["log_count"], 2);
+
+    let body: serde_json::Value = ctx
+        .client
+        .get(format!(
+            "{}/schemas?name=stats-test&include_stats=true",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to list schemas")
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(body["items"][0]["log_count"], 2);
+
+    let body: serde_json::Value = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to get schema")
+        .json()
+        .await
+        .unwrap();
+
+    assert!(body.get("log_count").is_none());
+}
+
+#[tokio::test]
+async fn lists_schema_fields_with_nested_properties() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&json!({
+            "name": "fields-test",
+            "version": "1.0.0",
+            "schema_definition": {
+                "type": "object",
+                "properties": {
+                    "message": { "type": "string", "description": "Log text" },
+                    "address": {
+                        "type": "object",
+                        "properties": { "city": { "type": ["string", "null"] } },
+                        "required": ["city"]
+                    }
+                },
+                "required": ["message"]
+            }
+        }))
+        .send()
+        .await
+        .unwrap();
+    let schema: Schema = response.json().await.unwrap();
+
+    let response = ctx
+        .client
+        .get(format!("{}/schemas/{}/fields", ctx.base_url, schema.id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["schema_id"], json!(schema.id));
+
+    let fields = body["fields"].as_array().unwrap();
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|field| field["name"] == name)
+            .unwrap_or_else(|| panic!("missing field {}", name))
+    };
+
+    assert_eq!(fields.len(), 3);
+    assert_eq!(field("message")["type"], "string");
+    assert_eq!(field("message")["required"], true);
+    assert_eq!(field("message")["description"], "Log text");
+    assert_eq!(field("address")["type"], "object");
+    assert_eq!(field("address")["required"], false);
+    assert_eq!(field("address.city")["type"], "string|null");
+    assert_eq!(field("address.city")["required"], true);
+}
+
+#[tokio::test]
+async fn returns_404_for_fields_of_nonexistent_schema() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/fields",
+            ctx.base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn exports_self_contained_json_schema() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&json!({
+            "name": "export-test",
+            "version": "1.0.0",
+            "schema_definition": {
+                "type": "object",
+                "properties": {
+                    "address": { "$ref": "#/$defs/address" }
+                },
+                "$defs": {
+                    "address": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } }
+                    }
+                }
+            }
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/export.json",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/schema+json"
+    );
+
+    let document: Value = response.json().await.unwrap();
+    assert_eq!(
+        document["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(document["title"], "export-test");
+    assert_eq!(
+        document["properties"]["address"]["properties"]["city"]["type"],
+        "string"
+    );
+
+    let document: Value = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/export.json?draft=7",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        document["$schema"],
+        "http://json-schema.org/draft-07/schema#"
+    );
+}
+
+#[tokio::test]
+async fn export_rejects_unknown_draft_and_missing_schema() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/export.json",
+            ctx.base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/export.json?draft=3",
+            ctx.base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn head_matches_get_without_body() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("head-test").await;
+
+    for path in [
+        format!("/schemas/{}", schema.id),
+        format!("/schemas/{}/{}", schema.name, schema.version),
+        format!("/schemas/{}", Uuid::new_v4()),
+    ] {
+        let url = format!("{}{}", ctx.base_url, path);
+        let get = ctx.client.get(&url).send().await.unwrap();
+        let head = ctx.client.head(&url).send().await.unwrap();
+
+        assert_eq!(head.status(), get.status(), "{}", path);
+        for header in ["content-type", "etag", "content-length"] {
+            assert_eq!(
+                head.headers().get(header),
+                get.headers().get(header),
+                "{} {}",
+                path,
+                header
+            );
+        }
+        assert!(head.bytes().await.unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn lists_versions_by_creation_or_semver_order() {
+    let ctx = TestContext::new().await;
+    let name = "versions-sort-test";
+
+    for version in ["1.9.0", "1.10.0", "2.0.0-beta.1", "1.2.0", "2.0.0"] {
+        let mut payload = valid_schema_payload(name);
+        payload["version"] = json!(version);
+        let response = ctx
+            .client
+            .post(format!("{}/schemas", ctx.base_url))
+            .json(&payload)
+            .send()
+            .await
+            .expect("Failed to create schema");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let versions = |body: Value| -> Vec<String> {
+        assert_eq!(body["schema_name"], name);
+        body["versions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|schema| schema["version"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let body: Value = ctx
+        .client
+        .get(format!("{}/schemas/{}/versions", ctx.base_url, name))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        versions(body),
+        vec!["2.0.0", "1.2.0", "2.0.0-beta.1", "1.10.0", "1.9.0"]
+    );
+
+    let body: Value = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/versions?sort_by=version",
+            ctx.base_url, name
+        ))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        versions(body),
+        vec!["2.0.0", "2.0.0-beta.1", "1.10.0", "1.9.0", "1.2.0"]
+    );
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/{}/versions?sort_by=size",
+            ctx.base_url, name
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = ctx
+        .client
+        .get(format!(
+            "{}/schemas/versions-sort-missing/versions",
+            ctx.base_url
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
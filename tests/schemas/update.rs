@@ -1,9 +1,12 @@
-use log_server::{ErrorResponse, Schema};
+use log_server::{ErrorResponse, Schema, ValidationMode};
 use reqwest::StatusCode;
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::common::{valid_schema_payload, TestContext};
+use crate::common::{
+    schema_with_enum_field, schema_with_nested_object, valid_log_payload, valid_schema_payload,
+    TestContext,
+};
 
 #[tokio::test]
 async fn updates_existing_schema_successfully() {
@@ -11,7 +14,7 @@ async fn updates_existing_schema_successfully() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-test"))
         .send()
         .await
@@ -37,7 +40,7 @@ async fn updates_existing_schema_successfully() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -81,7 +84,7 @@ async fn returns_404_for_nonexistent_schema() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, nonexistent_id))
+        .put(format!("{}/schemas/{}", ctx.base_url, nonexistent_id))
         .json(&update_payload)
         .send()
         .await
@@ -94,6 +97,34 @@ async fn returns_404_for_nonexistent_schema() {
     assert!(error.message.contains(&nonexistent_id.to_string()));
 }
 
+#[tokio::test]
+async fn rejects_required_field_missing_from_properties() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("update-required-not-in-properties").await;
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .json(&json!({
+            "name": schema.name,
+            "version": schema.version,
+            "schema_definition": {
+                "type": "object",
+                "properties": { "message": { "type": "string" } },
+                "required": ["message", "level"]
+            }
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_SCHEMA");
+    assert!(error.message.contains("'level'"), "{}", error.message);
+}
+
 #[tokio::test]
 async fn rejects_invalid_uuid_format() {
     let ctx = TestContext::new().await;
@@ -109,7 +140,7 @@ async fn rejects_invalid_uuid_format() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/invalid-uuid", ctx.base_url))
+        .put(format!("{}/schemas/invalid-uuid", ctx.base_url))
         .json(&update_payload)
         .send()
         .await
@@ -134,7 +165,7 @@ async fn rejects_nil_uuid() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, nil_uuid))
+        .put(format!("{}/schemas/{}", ctx.base_url, nil_uuid))
         .json(&update_payload)
         .send()
         .await
@@ -153,7 +184,7 @@ async fn rejects_empty_schema_name() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-empty-name-test"))
         .send()
         .await
@@ -172,7 +203,7 @@ async fn rejects_empty_schema_name() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -191,7 +222,7 @@ async fn rejects_whitespace_only_schema_name() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-whitespace-name-test"))
         .send()
         .await
@@ -210,7 +241,7 @@ async fn rejects_whitespace_only_schema_name() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -229,7 +260,7 @@ async fn rejects_missing_required_fields() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-missing-fields-test"))
         .send()
         .await
@@ -244,7 +275,7 @@ async fn rejects_missing_required_fields() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -254,12 +285,12 @@ async fn rejects_missing_required_fields() {
 }
 
 #[tokio::test]
-async fn handles_special_characters_in_updated_name() {
+async fn rejects_special_characters_in_updated_name() {
     let ctx = TestContext::new().await;
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-special-chars-test"))
         .send()
         .await
@@ -282,7 +313,42 @@ async fn handles_special_characters_in_updated_name() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .json(&update_payload)
+        .send()
+        .await
+        .expect("Failed to send update request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
+
+#[tokio::test]
+async fn accepts_dots_underscores_and_dashes_in_updated_name() {
+    let ctx = TestContext::new().await;
+
+    let created_schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("update-allowed-chars-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let update_payload = json!({
+        "name": "updated.schema_with-allowed.chars123",
+        "version": "2.0.0",
+        "schema_definition": { "type": "object" }
+    });
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -291,7 +357,7 @@ async fn handles_special_characters_in_updated_name() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let updated_schema: Schema = response.json().await.unwrap();
-    assert_eq!(updated_schema.name, special_name);
+    assert_eq!(updated_schema.name, "updated.schema_with-allowed.chars123");
 }
 
 #[tokio::test]
@@ -300,7 +366,7 @@ async fn allows_optional_description_to_be_none() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-no-description-test"))
         .send()
         .await
@@ -322,7 +388,7 @@ async fn allows_optional_description_to_be_none() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -340,7 +406,7 @@ async fn rejects_duplicate_name_when_updating() {
 
     let schema1_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("original-schema"))
         .send()
         .await
@@ -350,7 +416,7 @@ async fn rejects_duplicate_name_when_updating() {
 
     let schema2_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("schema-to-update"))
         .send()
         .await
@@ -372,7 +438,7 @@ async fn rejects_duplicate_name_when_updating() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, schema2.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, schema2.id))
         .json(&update_payload)
         .send()
         .await
@@ -392,7 +458,7 @@ async fn allows_updating_to_same_name() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("same-name-update-test"))
         .send()
         .await
@@ -414,7 +480,7 @@ async fn allows_updating_to_same_name() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -427,44 +493,44 @@ async fn allows_updating_to_same_name() {
     assert_eq!(updated_schema.version, "2.0.0");
 }
 
-// #[tokio::test]
-// async fn rejects_name_exceeding_max_length() {
-//     let ctx = TestContext::new().await;
-
-//     let create_response = ctx
-//         .client
-//         .post(&format!("{}/schemas", ctx.base_url))
-//         .json(&valid_schema_payload("update-long-name-test"))
-//         .send()
-//         .await
-//         .expect("Failed to create schema");
-
-//     let created_schema: Schema = create_response.json().await.unwrap();
-
-//     let long_name = "a".repeat(101);
-//     let update_payload = json!({
-//         "name": long_name,
-//         "version": "2.0.0",
-//         "description": "Testing long name",
-//         "schema_definition": {
-//             "type": "object"
-//         }
-//     });
-
-//     let response = ctx
-//         .client
-//         .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
-//         .json(&update_payload)
-//         .send()
-//         .await
-//         .expect("Failed to send update request");
-
-//     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-
-//     let error: ErrorResponse = response.json().await.unwrap();
-//     assert_eq!(error.error, "INVALID_INPUT");
-//     assert!(error.message.contains("name") && error.message.contains("length"));
-// }
+#[tokio::test]
+async fn rejects_name_exceeding_max_length() {
+    let ctx = TestContext::new().await;
+
+    let create_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("update-long-name-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let created_schema: Schema = create_response.json().await.unwrap();
+
+    let long_name = "a".repeat(256);
+    let update_payload = json!({
+        "name": long_name,
+        "version": "2.0.0",
+        "description": "Testing long name",
+        "schema_definition": {
+            "type": "object"
+        }
+    });
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .json(&update_payload)
+        .send()
+        .await
+        .expect("Failed to send update request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+    assert!(error.message.contains("name") && error.message.contains("length"));
+}
 
 #[tokio::test]
 async fn rejects_invalid_schema_definition() {
@@ -472,7 +538,7 @@ async fn rejects_invalid_schema_definition() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-invalid-def-test"))
         .send()
         .await
@@ -492,7 +558,7 @@ async fn rejects_invalid_schema_definition() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -510,7 +576,7 @@ async fn rejects_malformed_json_payload() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-malformed-test"))
         .send()
         .await
@@ -520,7 +586,7 @@ async fn rejects_malformed_json_payload() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .header("content-type", "application/json")
         .body(r#"{"name": "test", "version": "1.0.0", "invalid": json}"#)
         .send()
@@ -536,7 +602,7 @@ async fn rejects_wrong_content_type() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("update-content-type-test"))
         .send()
         .await
@@ -546,7 +612,7 @@ async fn rejects_wrong_content_type() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .header("content-type", "text/plain")
         .body("not json")
         .send()
@@ -562,7 +628,7 @@ async fn handles_concurrent_updates_gracefully() {
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("concurrent-update-test"))
         .send()
         .await
@@ -596,11 +662,11 @@ async fn handles_concurrent_updates_gracefully() {
 
     let (response1, response2) = tokio::join!(
         ctx.client
-            .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+            .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
             .json(&update_payload_1)
             .send(),
         ctx.client
-            .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+            .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
             .json(&update_payload_2)
             .send()
     );
@@ -617,13 +683,61 @@ async fn handles_concurrent_updates_gracefully() {
     );
 }
 
+#[tokio::test]
+async fn rejects_update_with_stale_expected_updated_at() {
+    let ctx = TestContext::new().await;
+    let created_schema = ctx.create_schema("optimistic-lock-test").await;
+
+    let update_payload = |description: &str| {
+        json!({
+            "name": "optimistic-lock-test",
+            "version": "1.0.0",
+            "description": description,
+            "schema_definition": created_schema.schema_definition,
+            "expected_updated_at": created_schema.updated_at,
+        })
+    };
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .json(&update_payload("First update"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .json(&update_payload("Second update"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "SCHEMA_MODIFIED");
+
+    let current: Schema = ctx
+        .client
+        .get(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(current.description.as_deref(), Some("First update"));
+}
+
 #[tokio::test]
 async fn preserves_id_and_created_at_fields() {
     let ctx = TestContext::new().await;
 
     let create_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("preserve-fields-test"))
         .send()
         .await
@@ -631,7 +745,7 @@ async fn preserves_id_and_created_at_fields() {
 
     let created_schema: Schema = create_response.json().await.unwrap();
     let original_id = created_schema.id;
-    let original_created_at = created_schema.created_at.clone();
+    let original_created_at = created_schema.created_at;
 
     tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
@@ -649,7 +763,7 @@ async fn preserves_id_and_created_at_fields() {
 
     let response = ctx
         .client
-        .put(&format!("{}/schemas/{}", ctx.base_url, created_schema.id))
+        .put(format!("{}/schemas/{}", ctx.base_url, created_schema.id))
         .json(&update_payload)
         .send()
         .await
@@ -665,3 +779,476 @@ async fn preserves_id_and_created_at_fields() {
     assert_eq!(updated_schema.name, "preserve-test-updated");
     assert_ne!(updated_schema.updated_at, created_schema.updated_at);
 }
+
+#[tokio::test]
+async fn strict_compatibility_rejects_definition_breaking_existing_logs() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("strict-compat-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    ctx.client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "message": "existing log" }
+        }))
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    let update_payload = json!({
+        "name": "strict-compat-test",
+        "version": "1.0.0",
+        "schema_definition": {
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" },
+                "level": { "type": "string" }
+            },
+            "required": ["message", "level"]
+        }
+    });
+
+    let response = ctx
+        .client
+        .put(format!(
+            "{}/schemas/{}?strict_compatibility=true",
+            ctx.base_url, schema.id
+        ))
+        .json(&update_payload)
+        .send()
+        .await
+        .expect("Failed to send update request");
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INCOMPATIBLE_SCHEMA");
+    assert!(error.message.contains("1 of 1"));
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .json(&update_payload)
+        .send()
+        .await
+        .expect("Failed to send update request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn strict_compatibility_rejects_removing_enum_value_in_use() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_enum_field(
+            "enum-compat-test",
+            "level",
+            &["INFO", "WARN"],
+        ))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    ctx.client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "level": "WARN" }
+        }))
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    let strict_update = |payload: serde_json::Value| {
+        ctx.client
+            .put(format!(
+                "{}/schemas/{}?strict_compatibility=true",
+                ctx.base_url, schema.id
+            ))
+            .json(&payload)
+            .send()
+    };
+
+    let response = strict_update(schema_with_enum_field(
+        "enum-compat-test",
+        "level",
+        &["INFO"],
+    ))
+    .await
+    .expect("Failed to send update request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INCOMPATIBLE_SCHEMA");
+
+    let response = strict_update(schema_with_enum_field(
+        "enum-compat-test",
+        "level",
+        &["INFO", "WARN", "ERROR"],
+    ))
+    .await
+    .expect("Failed to send update request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn strict_compatibility_rejects_replacing_structure_of_existing_logs() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&schema_with_nested_object("nested-compat-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    ctx.client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "log_data": { "user": { "id": 7 } }
+        }))
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    let response = ctx
+        .client
+        .put(format!(
+            "{}/schemas/{}?strict_compatibility=true",
+            ctx.base_url, schema.id
+        ))
+        .json(&valid_schema_payload("nested-compat-test"))
+        .send()
+        .await
+        .expect("Failed to send update request");
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn deprecates_and_undeprecates_schema() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("deprecate-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+    assert!(!schema.deprecated);
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas/{}/deprecate", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to deprecate schema");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let deprecated: Schema = response.json().await.unwrap();
+    assert!(deprecated.deprecated);
+    assert!(deprecated.deprecated_at.is_some());
+    assert!(deprecated.updated_at > schema.updated_at);
+
+    // Deprecated schemas still accept logs
+    let log_response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&crate::common::valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to create log");
+    assert_eq!(log_response.status(), StatusCode::CREATED);
+
+    let response = ctx
+        .client
+        .post(format!(
+            "{}/schemas/{}/undeprecate",
+            ctx.base_url, schema.id
+        ))
+        .send()
+        .await
+        .expect("Failed to undeprecate schema");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let undeprecated: Schema = response.json().await.unwrap();
+    assert!(!undeprecated.deprecated);
+    assert!(undeprecated.deprecated_at.is_none());
+    assert!(undeprecated.updated_at > deprecated.updated_at);
+
+    let response = ctx
+        .client
+        .post(format!(
+            "{}/schemas/{}/deprecate",
+            ctx.base_url,
+            Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .expect("Failed to deprecate schema");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn patches_schema_tags() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("tags-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+    assert!(schema.tags.is_empty());
+
+    let tags_url = format!("{}/schemas/{}/tags", ctx.base_url, schema.id);
+
+    let response = ctx
+        .client
+        .patch(&tags_url)
+        .json(&json!({ "add": ["staging", "billing", "eu"] }))
+        .send()
+        .await
+        .expect("Failed to patch tags");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .patch(&tags_url)
+        .json(&json!({ "add": ["production", "eu"], "remove": ["staging"] }))
+        .send()
+        .await
+        .expect("Failed to patch tags");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let patched: Schema = response.json().await.unwrap();
+    assert_eq!(patched.tags, vec!["billing", "eu", "production"]);
+    assert!(patched.updated_at > schema.updated_at);
+
+    let response = ctx
+        .client
+        .patch(&tags_url)
+        .json(&json!({ "add": ["not a tag"] }))
+        .send()
+        .await
+        .expect("Failed to patch tags");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = ctx
+        .client
+        .patch(format!("{}/schemas/{}/tags", ctx.base_url, Uuid::new_v4()))
+        .json(&json!({ "add": ["production"] }))
+        .send()
+        .await
+        .expect("Failed to patch tags");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn validates_logs_against_updated_definition() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("cache-update-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let log_payload = valid_log_payload(schema.id);
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_payload)
+        .send()
+        .await
+        .expect("Failed to create log");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let mut update_payload = valid_schema_payload("cache-update-test");
+    update_payload["schema_definition"]["properties"]["level"] = json!({ "type": "string" });
+    update_payload["schema_definition"]["required"] = json!(["message", "level"]);
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .json(&update_payload)
+        .send()
+        .await
+        .expect("Failed to update schema");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_payload)
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn validation_mode_controls_extra_top_level_fields() {
+    let ctx = TestContext::new().await;
+
+    let schema_definition = json!({
+        "type": "object",
+        "properties": { "message": { "type": "string" } },
+        "required": ["message"],
+        "additionalProperties": false
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&json!({
+            "name": "validation-mode-test",
+            "version": "1.0.0",
+            "schema_definition": schema_definition,
+            "validation_mode": "lenient"
+        }))
+        .send()
+        .await
+        .expect("Failed to create schema");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let schema: Schema = response.json().await.unwrap();
+    assert_eq!(schema.validation_mode, ValidationMode::Lenient);
+
+    let log_with_extra_field = json!({
+        "schema_id": schema.id,
+        "log_data": { "message": "hello", "legacy_field": 1 }
+    });
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_with_extra_field)
+        .send()
+        .await
+        .expect("Failed to create log");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = ctx
+        .client
+        .put(format!("{}/schemas/{}", ctx.base_url, schema.id))
+        .json(&json!({
+            "name": "validation-mode-test",
+            "version": "1.0.0",
+            "schema_definition": schema_definition,
+            "validation_mode": "strict"
+        }))
+        .send()
+        .await
+        .expect("Failed to update schema");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let updated: Schema = response.json().await.unwrap();
+    assert_eq!(updated.validation_mode, ValidationMode::Strict);
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&log_with_extra_field)
+        .send()
+        .await
+        .expect("Failed to create log");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn patches_only_the_schema_definition() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("patch-definition-test").await;
+    ctx.create_log(schema.id).await;
+
+    let required_level = json!({
+        "schema_definition": {
+            "type": "object",
+            "properties": { "level": { "type": "string" } },
+            "required": ["level"]
+        }
+    });
+
+    let response = ctx
+        .client
+        .patch(format!(
+            "{}/schemas/{}/schema_definition?strict_compatibility=true",
+            ctx.base_url, schema.id
+        ))
+        .json(&required_level)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = ctx
+        .client
+        .patch(format!(
+            "{}/schemas/{}/schema_definition",
+            ctx.base_url, schema.id
+        ))
+        .json(&required_level)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let updated: Schema = response.json().await.unwrap();
+    assert_eq!(updated.name, schema.name);
+    assert_eq!(updated.version, schema.version);
+    assert_eq!(updated.description, schema.description);
+    assert_eq!(updated.schema_definition["required"], json!(["level"]));
+    assert_ne!(updated.updated_at.to_rfc3339(), schema.updated_at);
+
+    let response = ctx
+        .client
+        .patch(format!(
+            "{}/schemas/{}/schema_definition",
+            ctx.base_url,
+            Uuid::new_v4()
+        ))
+        .json(&required_level)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
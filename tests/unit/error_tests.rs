@@ -0,0 +1,49 @@
+use log_server::{AppError, AppResult, ErrorResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+fn parse_log_id(id: &str) -> AppResult<i32> {
+    Ok(id.parse::<i32>()?)
+}
+
+fn parse_schema_id(id: &str) -> AppResult<Uuid> {
+    Ok(Uuid::parse_str(id)?)
+}
+
+#[test]
+fn parse_errors_become_bad_requests() {
+    assert_eq!(parse_log_id("42").unwrap(), 42);
+    assert!(matches!(
+        parse_log_id("forty-two"),
+        Err(AppError::BadRequest(message)) if message.starts_with("Invalid integer")
+    ));
+
+    assert!(parse_schema_id("0a9dadf1-fd1b-4727-88d5-98aad5ce70a3").is_ok());
+    assert!(matches!(
+        parse_schema_id("not-a-uuid"),
+        Err(AppError::BadRequest(message)) if message == "Invalid UUID format"
+    ));
+}
+
+#[test]
+fn problem_detail_keeps_error_fields_as_extensions() {
+    let error = ErrorResponse::new("NOT_FOUND", "Schema not found").with_resource_type("schema");
+
+    assert_eq!(
+        error.as_problem_detail(404, "/schemas/abc"),
+        json!({
+            "type": "about:blank",
+            "title": "Not Found",
+            "status": 404,
+            "detail": "Schema not found",
+            "instance": "/schemas/abc",
+            "error": "NOT_FOUND",
+            "message": "Schema not found",
+            "resource_type": "schema"
+        })
+    );
+
+    let problem = error.as_problem_detail(599, "");
+    assert_eq!(problem["title"], "Error");
+    assert!(problem.get("instance").is_none());
+}
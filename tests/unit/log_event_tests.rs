@@ -0,0 +1,114 @@
+use log_server::LogEvent;
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn created_event_round_trips_with_event_type_tag() {
+    let schema_id = Uuid::new_v4();
+    let event = LogEvent::Created {
+        id: 7,
+        schema_id,
+        schema_name: "orders".to_string(),
+        schema_version: "1.0.0".to_string(),
+        log_data: json!({ "message": "hello" }),
+        source: None,
+        severity: Some(3),
+        attachments: None,
+        created_at: "2024-01-01T00:00:00+00:00".to_string(),
+    };
+
+    let serialized = serde_json::to_value(&event).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "event_type": "created",
+            "id": 7,
+            "schema_id": schema_id,
+            "schema_name": "orders",
+            "schema_version": "1.0.0",
+            "log_data": { "message": "hello" },
+            "severity": 3,
+            "created_at": "2024-01-01T00:00:00+00:00"
+        })
+    );
+
+    match serde_json::from_value::<LogEvent>(serialized).unwrap() {
+        LogEvent::Created {
+            id,
+            schema_name,
+            source,
+            severity,
+            ..
+        } => {
+            assert_eq!(id, 7);
+            assert_eq!(schema_name, "orders");
+            assert_eq!(source, None);
+            assert_eq!(severity, Some(3));
+        }
+        other => panic!("Expected a created event, got {:?}", other),
+    }
+    assert_eq!(event.schema_id(), Some(schema_id));
+}
+
+#[test]
+fn deleted_event_round_trips_with_event_type_tag() {
+    let schema_id = Uuid::new_v4();
+    let event = LogEvent::Deleted { id: 1, schema_id };
+
+    let serialized = serde_json::to_string(&event).unwrap();
+    assert_eq!(
+        serialized,
+        format!(
+            r#"{{"event_type":"deleted","id":1,"schema_id":"{}"}}"#,
+            schema_id
+        )
+    );
+
+    match serde_json::from_str::<LogEvent>(&serialized).unwrap() {
+        LogEvent::Deleted {
+            id,
+            schema_id: id_of_schema,
+        } => {
+            assert_eq!(id, 1);
+            assert_eq!(id_of_schema, schema_id);
+        }
+        other => panic!("Expected a deleted event, got {:?}", other),
+    }
+    assert_eq!(event.schema_id(), Some(schema_id));
+}
+
+#[test]
+fn batch_event_nests_tagged_events() {
+    let schema_id = Uuid::new_v4();
+    let event = LogEvent::Batch {
+        events: vec![LogEvent::Deleted { id: 1, schema_id }],
+        batch_at: "2024-01-01T00:00:00+00:00".to_string(),
+    };
+
+    let serialized = serde_json::to_value(&event).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "event_type": "batch",
+            "events": [{ "event_type": "deleted", "id": 1, "schema_id": schema_id }],
+            "batch_at": "2024-01-01T00:00:00+00:00"
+        })
+    );
+
+    match serde_json::from_value::<LogEvent>(serialized).unwrap() {
+        LogEvent::Batch { events, .. } => assert_eq!(events.len(), 1),
+        other => panic!("Expected a batch event, got {:?}", other),
+    }
+    assert_eq!(event.schema_id(), None);
+}
+
+#[test]
+fn rejects_unknown_event_type() {
+    let result = serde_json::from_value::<LogEvent>(json!({
+        "event_type": "Created",
+        "id": 1,
+        "schema_id": Uuid::new_v4()
+    }));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,408 @@
+//! In-memory repositories for exercising services without a database. Queries the
+//! tested services never make are left unimplemented.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use log_server::{
+    repositories::{
+        audit_repository::AuditRepositoryTrait,
+        log_repository::{
+            DeletedLogs, LogDeleteFilter, LogRepositoryTrait, LogSort, SeverityRange, TimeBucket,
+            TimeBucketedCount,
+        },
+        schema_repository::{SchemaQueryParams, SchemaRepositoryTrait, SchemaWithLogCount},
+    },
+    AppResult, AuditEvent, AuditLog, Log, Schema,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct MockSchemaRepository {
+    schemas: Mutex<HashMap<Uuid, Schema>>,
+}
+
+impl MockSchemaRepository {
+    fn matching(&self, params: &SchemaQueryParams) -> Vec<Schema> {
+        let mut schemas: Vec<Schema> = self
+            .schemas
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|schema| params.name.as_ref().is_none_or(|name| &schema.name == name))
+            .filter(|schema| {
+                params
+                    .version
+                    .as_ref()
+                    .is_none_or(|version| &schema.version == version)
+            })
+            .cloned()
+            .collect();
+        schemas.sort_by_key(|schema| std::cmp::Reverse(schema.created_at));
+        schemas
+    }
+}
+
+#[async_trait]
+impl SchemaRepositoryTrait for MockSchemaRepository {
+    async fn get_all(&self, params: Option<SchemaQueryParams>) -> AppResult<Vec<Schema>> {
+        Ok(self.matching(&params.unwrap_or_default()))
+    }
+
+    fn stream_all(&self, _params: SchemaQueryParams) -> BoxStream<'static, AppResult<Schema>> {
+        unimplemented!()
+    }
+
+    async fn get_after_seq(&self, after_seq: i64, limit: i64) -> AppResult<Vec<Schema>> {
+        let mut schemas: Vec<Schema> = self
+            .schemas
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|schema| schema.seq > after_seq)
+            .cloned()
+            .collect();
+        schemas.sort_by_key(|schema| schema.seq);
+        schemas.truncate(limit as usize);
+        Ok(schemas)
+    }
+
+    async fn count(&self, params: &SchemaQueryParams) -> AppResult<i64> {
+        Ok(self.matching(params).len() as i64)
+    }
+
+    async fn get_all_with_counts(
+        &self,
+        _params: Option<SchemaQueryParams>,
+    ) -> AppResult<Vec<SchemaWithLogCount>> {
+        unimplemented!()
+    }
+
+    async fn search(&self, _query: &str, _limit: i64, _offset: i64) -> AppResult<Vec<Schema>> {
+        unimplemented!()
+    }
+
+    async fn count_search(&self, _query: &str) -> AppResult<i64> {
+        unimplemented!()
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> AppResult<Option<Schema>> {
+        Ok(self.schemas.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn get_by_ids(&self, ids: &[Uuid]) -> AppResult<HashMap<Uuid, Schema>> {
+        let schemas = self.schemas.lock().unwrap();
+        Ok(ids
+            .iter()
+            .filter_map(|id| schemas.get(id).map(|schema| (*id, schema.clone())))
+            .collect())
+    }
+
+    async fn get_by_name_and_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> AppResult<Option<Schema>> {
+        Ok(self
+            .schemas
+            .lock()
+            .unwrap()
+            .values()
+            .find(|schema| schema.name == name && schema.version == version)
+            .cloned())
+    }
+
+    async fn get_all_versions_by_name(&self, _name: &str) -> AppResult<Vec<Schema>> {
+        unimplemented!()
+    }
+
+    async fn get_versions_sorted_by_semver(&self, _name: &str) -> AppResult<Vec<Schema>> {
+        unimplemented!()
+    }
+
+    async fn get_by_name_containing(&self, _substring: &str) -> AppResult<Vec<Schema>> {
+        unimplemented!()
+    }
+
+    async fn get_latest_version_by_name(&self, _name: &str) -> AppResult<Option<Schema>> {
+        unimplemented!()
+    }
+
+    async fn create(&self, schema: &Schema) -> AppResult<Schema> {
+        let mut schemas = self.schemas.lock().unwrap();
+        let created = Schema {
+            seq: schemas.values().map(|schema| schema.seq).max().unwrap_or(0) + 1,
+            ..schema.clone()
+        };
+        schemas.insert(created.id, created.clone());
+        Ok(created)
+    }
+
+    async fn update(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        self.update_with_history(id, schema, "update", expected_updated_at)
+            .await
+    }
+
+    async fn update_with_history(
+        &self,
+        id: Uuid,
+        schema: &Schema,
+        _change_type: &str,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        let mut schemas = self.schemas.lock().unwrap();
+        Ok(schemas
+            .get_mut(&id)
+            .filter(|existing| {
+                expected_updated_at.is_none_or(|expected| existing.updated_at == expected)
+            })
+            .map(|existing| {
+                *existing = schema.clone();
+                existing.clone()
+            }))
+    }
+
+    async fn set_deprecated(
+        &self,
+        id: Uuid,
+        deprecated: bool,
+        deprecated_at: Option<DateTime<Utc>>,
+    ) -> AppResult<Option<Schema>> {
+        let mut schemas = self.schemas.lock().unwrap();
+        Ok(schemas.get_mut(&id).map(|schema| {
+            schema.deprecated = deprecated;
+            schema.deprecated_at = deprecated_at;
+            schema.updated_at = Utc::now();
+            schema.clone()
+        }))
+    }
+
+    async fn set_tags(&self, id: Uuid, tags: &[String]) -> AppResult<Option<Schema>> {
+        let mut schemas = self.schemas.lock().unwrap();
+        Ok(schemas.get_mut(&id).map(|schema| {
+            schema.tags = tags.to_vec();
+            schema.updated_at = Utc::now();
+            schema.clone()
+        }))
+    }
+
+    async fn set_definition(
+        &self,
+        id: Uuid,
+        schema_definition: &Value,
+        updated_at: DateTime<Utc>,
+    ) -> AppResult<Option<Schema>> {
+        let mut schemas = self.schemas.lock().unwrap();
+        Ok(schemas.get_mut(&id).map(|schema| {
+            schema.schema_definition = schema_definition.clone();
+            schema.updated_at = updated_at;
+            schema.clone()
+        }))
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<bool> {
+        self.delete_with_history(id, "delete").await
+    }
+
+    async fn delete_with_history(&self, id: Uuid, _change_type: &str) -> AppResult<bool> {
+        Ok(self.schemas.lock().unwrap().remove(&id).is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct MockLogRepository {
+    logs: Mutex<HashMap<i32, Log>>,
+}
+
+#[async_trait]
+impl LogRepositoryTrait for MockLogRepository {
+    async fn get_by_schema_id(
+        &self,
+        _schema_id: Uuid,
+        _filters: Option<Value>,
+        _source: Option<&str>,
+        _severity: &SeverityRange,
+        _sort: &LogSort,
+        _limit: Option<i64>,
+        _offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn count_by_schema_id_with_filter(
+        &self,
+        _schema_id: Uuid,
+        _filter: Option<&Value>,
+        _source: Option<&str>,
+        _severity: &SeverityRange,
+    ) -> AppResult<i64> {
+        unimplemented!()
+    }
+
+    async fn get_by_severity_range(
+        &self,
+        _schema_id: Uuid,
+        _severity: &SeverityRange,
+        _limit: i64,
+        _offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn get_by_schema_ids(
+        &self,
+        _schema_ids: &[Uuid],
+        _from: Option<DateTime<Utc>>,
+        _to: Option<DateTime<Utc>>,
+        _limit: i64,
+        _offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn count_by_schema_ids(
+        &self,
+        _schema_ids: &[Uuid],
+        _from: Option<DateTime<Utc>>,
+        _to: Option<DateTime<Utc>>,
+    ) -> AppResult<i64> {
+        unimplemented!()
+    }
+
+    async fn get_for_replay(
+        &self,
+        _schema_id: Uuid,
+        _from: Option<DateTime<Utc>>,
+        _limit: i64,
+    ) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn get_by_correlation_id(
+        &self,
+        _correlation_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn count_by_correlation_id(&self, _correlation_id: &str) -> AppResult<i64> {
+        unimplemented!()
+    }
+
+    async fn get_by_id(&self, id: i32) -> AppResult<Option<Log>> {
+        Ok(self.logs.lock().unwrap().get(&id).cloned())
+    }
+
+    async fn create(&self, log: &Log) -> AppResult<Log> {
+        let mut logs = self.logs.lock().unwrap();
+        let created = Log {
+            id: logs.len() as i32 + 1,
+            ..log.clone()
+        };
+        logs.insert(created.id, created.clone());
+        Ok(created)
+    }
+
+    async fn create_many(&self, _logs: &[Log]) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn delete(&self, id: i32) -> AppResult<bool> {
+        Ok(self.logs.lock().unwrap().remove(&id).is_some())
+    }
+
+    async fn count_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64> {
+        Ok(self
+            .logs
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|log| log.schema_id == schema_id)
+            .count() as i64)
+    }
+
+    async fn count_all(&self) -> AppResult<i64> {
+        Ok(self.logs.lock().unwrap().len() as i64)
+    }
+
+    async fn count_since(&self, _since: DateTime<Utc>) -> AppResult<i64> {
+        unimplemented!()
+    }
+
+    async fn created_at_range(&self) -> AppResult<(Option<DateTime<Utc>>, Option<DateTime<Utc>>)> {
+        unimplemented!()
+    }
+
+    async fn sample_by_schema_id(&self, _schema_id: Uuid, _limit: i64) -> AppResult<Vec<Log>> {
+        unimplemented!()
+    }
+
+    async fn delete_by_schema_id(&self, schema_id: Uuid) -> AppResult<i64> {
+        let mut logs = self.logs.lock().unwrap();
+        let before = logs.len();
+        logs.retain(|_, log| log.schema_id != schema_id);
+        Ok((before - logs.len()) as i64)
+    }
+
+    async fn delete_by_filter(
+        &self,
+        _schema_id: Uuid,
+        _filter: &LogDeleteFilter,
+        _id_limit: i64,
+    ) -> AppResult<DeletedLogs> {
+        unimplemented!()
+    }
+
+    async fn get_grouped_by_time(
+        &self,
+        _schema_id: Uuid,
+        _bucket: TimeBucket,
+        _from: DateTime<Utc>,
+        _to: DateTime<Utc>,
+    ) -> AppResult<Vec<TimeBucketedCount>> {
+        unimplemented!()
+    }
+
+    async fn get_or_create_idempotent(&self, _key: &str, _log: &Log) -> AppResult<(Log, bool)> {
+        unimplemented!()
+    }
+}
+
+/// Keeps every recorded entry so tests can assert on the audit trail.
+#[derive(Default)]
+pub struct MockAuditRepository {
+    pub entries: Mutex<Vec<AuditLog>>,
+    pub schema_events: Mutex<Vec<AuditEvent>>,
+}
+
+#[async_trait]
+impl AuditRepositoryTrait for MockAuditRepository {
+    async fn record(&self, entry: &AuditLog) -> AppResult<AuditLog> {
+        self.entries.lock().unwrap().push(entry.clone());
+        Ok(entry.clone())
+    }
+
+    async fn get_by_entity(
+        &self,
+        _entity_type: Option<&str>,
+        _entity_id: Option<&str>,
+        _limit: i64,
+    ) -> AppResult<Vec<AuditLog>> {
+        unimplemented!()
+    }
+
+    async fn record_schema_event(&self, event: &AuditEvent) -> AppResult<()> {
+        self.schema_events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod error_tests;
+mod log_event_tests;
+mod mocks;
+mod remote_refs_tests;
+mod schema_audit_tests;
+mod schema_handler_tests;
+mod schema_service_tests;
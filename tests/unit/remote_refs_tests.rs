@@ -0,0 +1,114 @@
+use axum::{response::Redirect, routing::get, Json, Router};
+use log_server::utils::remote_refs::{inline_remote_refs, is_private_ip, FetchOptions};
+use serde_json::json;
+use std::net::{IpAddr, SocketAddr};
+
+/// Serves `/address.json`, `/redirect` (to `/address.json`) and `/large` on loopback.
+async fn spawn_ref_server() -> SocketAddr {
+    let app = Router::new()
+        .route(
+            "/address.json",
+            get(|| async { Json(json!({ "type": "string" })) }),
+        )
+        .route(
+            "/redirect",
+            get(|| async { Redirect::temporary("/address.json") }),
+        )
+        .route(
+            "/large",
+            get(|| async { Json(json!({ "description": "x".repeat(4096) })) }),
+        );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    addr
+}
+
+/// Options reaching the loopback test server.
+fn loopback_options() -> FetchOptions {
+    FetchOptions {
+        allow_private_hosts: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn detects_private_addresses() {
+    for ip in [
+        "127.0.0.1",
+        "10.1.2.3",
+        "172.16.0.1",
+        "192.168.1.1",
+        "169.254.169.254",
+        "100.64.0.1",
+        "0.0.0.0",
+        "::1",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:192.168.1.1",
+    ] {
+        assert!(is_private_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+
+    for ip in ["8.8.8.8", "1.1.1.1", "2606:4700:4700::1111"] {
+        assert!(!is_private_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+}
+
+#[tokio::test]
+async fn keeps_local_and_private_refs() {
+    let definition = json!({
+        "type": "object",
+        "definitions": { "name": { "type": "string" } },
+        "properties": {
+            "name": { "$ref": "#/definitions/name" },
+            "address": { "$ref": "http://127.0.0.1:1/address.schema.json" },
+            "metadata": { "$ref": "http://169.254.169.254/latest/meta-data" }
+        }
+    });
+
+    let resolved = inline_remote_refs(&definition, &FetchOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(resolved, definition);
+}
+
+#[tokio::test]
+async fn inlines_fetched_document() {
+    let addr = spawn_ref_server().await;
+    let definition = json!({ "$ref": format!("http://{}/address.json", addr) });
+
+    let resolved = inline_remote_refs(&definition, &loopback_options())
+        .await
+        .unwrap();
+
+    assert_eq!(resolved, json!({ "type": "string" }));
+}
+
+#[tokio::test]
+async fn does_not_follow_redirects_to_loopback() {
+    let addr = spawn_ref_server().await;
+    let definition = json!({ "$ref": format!("http://{}/redirect", addr) });
+
+    let error = inline_remote_refs(&definition, &loopback_options())
+        .await
+        .unwrap_err();
+
+    assert!(error.contains("redirects are not followed"), "{}", error);
+}
+
+#[tokio::test]
+async fn rejects_documents_over_size_limit() {
+    let addr = spawn_ref_server().await;
+    let definition = json!({ "$ref": format!("http://{}/large", addr) });
+    let options = FetchOptions {
+        max_document_bytes: 1024,
+        ..loopback_options()
+    };
+
+    let error = inline_remote_refs(&definition, &options).await.unwrap_err();
+
+    assert!(error.contains("maximum document size"), "{}", error);
+}
@@ -0,0 +1,64 @@
+use axum::http::Method;
+use chrono::Utc;
+use log_server::{middleware::schema_audit::schema_operation, AuditEvent, AuditService};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use super::mocks::MockAuditRepository;
+
+#[test]
+fn maps_schema_mutations_to_operations() {
+    for (method, route, operation) in [
+        (Method::POST, "/schemas", Some("create")),
+        (Method::PUT, "/schemas/{id}", Some("update")),
+        (
+            Method::PATCH,
+            "/schemas/{id}/schema_definition",
+            Some("update_definition"),
+        ),
+        (Method::POST, "/schemas/{id}/deprecate", Some("deprecate")),
+        (Method::DELETE, "/schemas/{id}", Some("delete")),
+        (Method::GET, "/schemas/{id}", None),
+        (Method::POST, "/schemas/validate", None),
+        (Method::POST, "/schemas/{id}/validate-example", None),
+        (Method::DELETE, "/logs/{id}", None),
+    ] {
+        assert_eq!(
+            schema_operation(&method, route),
+            operation,
+            "{} {}",
+            method,
+            route
+        );
+    }
+}
+
+#[tokio::test]
+async fn writes_schema_events_until_senders_are_dropped() {
+    let repository = Arc::new(MockAuditRepository::default());
+    let service = Arc::new(AuditService::new(repository.clone()));
+    let (sender, receiver) = mpsc::channel(8);
+
+    let schema_id = Uuid::new_v4();
+    for operation in ["create", "delete"] {
+        sender
+            .send(AuditEvent {
+                operation: operation.to_string(),
+                schema_id: Some(schema_id),
+                actor: None,
+                request_id: Some("req-1".to_string()),
+                timestamp: Utc::now(),
+            })
+            .await
+            .unwrap();
+    }
+    drop(sender);
+
+    service.write_schema_events(receiver).await;
+
+    let events = repository.schema_events.lock().unwrap();
+    let operations: Vec<&str> = events.iter().map(|e| e.operation.as_str()).collect();
+    assert_eq!(operations, vec!["create", "delete"]);
+    assert!(events.iter().all(|e| e.schema_id == Some(schema_id)));
+}
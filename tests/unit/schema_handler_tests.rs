@@ -0,0 +1,172 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use log_server::{
+    config::{AppConfig, LogFormat},
+    dto::{UpdateSchemaQuery, UpdateSchemaRequest},
+    handlers::schema_handlers::update_schema,
+    repositories::{
+        ApiKeyRepository, IngestWebhookRepository, SchemaRepository, WebhookRepository,
+    },
+    services::{
+        log_service::DEFAULT_MAX_LOG_DATA_BYTES, schema_service::VersionFormat, AuditService,
+        IngestWebhookService, LogService, MigrationService, SchemaCache, SchemaService,
+        WebhookService,
+    },
+    AppState, AuditContext, ValidationMode,
+};
+use metrics_exporter_prometheus::PrometheusBuilder;
+use serde_json::json;
+use sqlx::postgres::PgPoolOptions;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
+use uuid::Uuid;
+
+use super::mocks::{MockAuditRepository, MockLogRepository, MockSchemaRepository};
+
+/// State backed by the in-memory repositories. Routes that need the database (API keys,
+/// webhooks, migrations) get a pool that never connects.
+fn app_state(version_format: VersionFormat) -> AppState {
+    let schemas = Arc::new(MockSchemaRepository::default());
+    let logs = Arc::new(MockLogRepository::default());
+    let schema_cache = Arc::new(SchemaCache::new(0));
+    let audit_service = Arc::new(AuditService::new(Arc::new(MockAuditRepository::default())));
+    let (log_broadcast, _) = broadcast::channel(16);
+    let (schema_audit_events, _) = mpsc::channel(16);
+    let pool = PgPoolOptions::new()
+        .connect_lazy("postgresql://localhost/unused")
+        .unwrap();
+
+    let config = AppConfig {
+        database_url: String::new(),
+        port: 8080,
+        bind_addr: "127.0.0.1".parse().unwrap(),
+        broadcast_capacity: 16,
+        max_ws_connections: 0,
+        max_request_body_bytes: 1024 * 1024,
+        cors_allowed_origins: Vec::new(),
+        log_format: LogFormat::Text,
+        request_timeout_ms: 30_000,
+        rate_limit_per_minute: 0,
+        rate_limit_burst: 0,
+        log_ingest_rate_limit_per_minute: 0,
+        ingest_webhook_rate_limit_per_minute: 0,
+        slow_query_threshold_ms: 1000,
+        require_api_key: false,
+        disable_compression: false,
+        max_log_data_bytes: DEFAULT_MAX_LOG_DATA_BYTES,
+        enrich_logs: false,
+        server_version: None,
+        otel_exporter_otlp_endpoint: None,
+        version_format,
+        schema_cache_size: 0,
+    };
+
+    let log_service = Arc::new(LogService::new(
+        logs.clone(),
+        schemas.clone(),
+        schema_cache.clone(),
+        audit_service.clone(),
+        log_broadcast.clone(),
+        false,
+        None,
+        DEFAULT_MAX_LOG_DATA_BYTES,
+    ));
+    let schema_service = Arc::new(SchemaService::new(
+        schemas,
+        logs,
+        log_service.clone(),
+        audit_service.clone(),
+        schema_cache,
+        version_format,
+    ));
+
+    AppState::builder()
+        .config(Arc::new(config))
+        .schema_service(schema_service)
+        .log_service(log_service)
+        .audit_service(audit_service)
+        .webhook_service(Arc::new(WebhookService::new(Arc::new(
+            WebhookRepository::new(pool.clone()),
+        ))))
+        .ingest_webhook_service(Arc::new(IngestWebhookService::new(
+            Arc::new(IngestWebhookRepository::new(pool.clone())),
+            Arc::new(SchemaRepository::new(pool.clone())),
+        )))
+        .migration_service(Arc::new(MigrationService::new(pool.clone())))
+        .log_broadcast(log_broadcast)
+        .api_key_repository(Arc::new(ApiKeyRepository::new(pool)))
+        .metrics(PrometheusBuilder::new().build_recorder().handle())
+        .schema_audit_events(schema_audit_events)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn update_rejects_free_form_version_shadowed_by_route() {
+    let state = app_state(VersionFormat::Free);
+    let schema = state
+        .schema_service
+        .create_schema(
+            "free-versions".to_string(),
+            "2024-q1".to_string(),
+            None,
+            json!({ "type": "object" }),
+            ValidationMode::Strict,
+            &AuditContext::default(),
+        )
+        .await
+        .unwrap();
+
+    let result = update_schema(
+        State(state),
+        Path(schema.id),
+        Query(UpdateSchemaQuery {
+            strict_compatibility: None,
+        }),
+        AuditContext::default(),
+        Json(UpdateSchemaRequest {
+            name: schema.name,
+            version: "versions".to_string(),
+            description: None,
+            schema_definition: json!({ "type": "object" }),
+            validation_mode: None,
+            expected_updated_at: None,
+        }),
+    )
+    .await;
+
+    let (status, Json(error)) = result.unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.error, "INVALID_INPUT");
+    assert!(error.message.contains("reserved"), "{}", error.message);
+}
+
+#[tokio::test]
+async fn update_of_unknown_schema_validates_version_first() {
+    let state = app_state(VersionFormat::Free);
+
+    let result = update_schema(
+        State(state),
+        Path(Uuid::new_v4()),
+        Query(UpdateSchemaQuery {
+            strict_compatibility: None,
+        }),
+        AuditContext::default(),
+        Json(UpdateSchemaRequest {
+            name: "free-versions".to_string(),
+            version: "-rc1".to_string(),
+            description: None,
+            schema_definition: json!({ "type": "object" }),
+            validation_mode: None,
+            expected_updated_at: None,
+        }),
+    )
+    .await;
+
+    let (status, Json(error)) = result.unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.error, "INVALID_INPUT");
+}
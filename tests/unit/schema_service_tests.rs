@@ -0,0 +1,329 @@
+use chrono::Utc;
+use log_server::{
+    repositories::{log_repository::LogRepositoryTrait, schema_repository::SchemaQueryParams},
+    services::{
+        log_service::DEFAULT_MAX_LOG_DATA_BYTES, schema_service::VersionFormat, AuditService,
+        LogService, SchemaCache, SchemaService,
+    },
+    AppError, AuditContext, Log, ValidationMode,
+};
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use super::mocks::{MockAuditRepository, MockLogRepository, MockSchemaRepository};
+
+struct Fixture {
+    service: SchemaService,
+    logs: Arc<MockLogRepository>,
+    audit: Arc<MockAuditRepository>,
+}
+
+fn fixture() -> Fixture {
+    fixture_with_version_format(VersionFormat::Semver)
+}
+
+fn fixture_with_version_format(version_format: VersionFormat) -> Fixture {
+    let schemas = Arc::new(MockSchemaRepository::default());
+    let logs = Arc::new(MockLogRepository::default());
+    let audit = Arc::new(MockAuditRepository::default());
+    let schema_cache = Arc::new(SchemaCache::new(0));
+    let audit_service = Arc::new(AuditService::new(audit.clone()));
+    let (log_broadcast, _) = broadcast::channel(16);
+
+    let log_service = Arc::new(LogService::new(
+        logs.clone(),
+        schemas.clone(),
+        schema_cache.clone(),
+        audit_service.clone(),
+        log_broadcast,
+        false,
+        None,
+        DEFAULT_MAX_LOG_DATA_BYTES,
+    ));
+    let service = SchemaService::new(
+        schemas,
+        logs.clone(),
+        log_service,
+        audit_service,
+        schema_cache,
+        version_format,
+    );
+
+    Fixture {
+        service,
+        logs,
+        audit,
+    }
+}
+
+fn definition() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": { "message": { "type": "string" } },
+        "required": ["message"]
+    })
+}
+
+async fn create(fixture: &Fixture, name: &str, version: &str) -> log_server::Schema {
+    fixture
+        .service
+        .create_schema(
+            name.to_string(),
+            version.to_string(),
+            None,
+            definition(),
+            ValidationMode::Strict,
+            &AuditContext::default(),
+        )
+        .await
+        .expect("Failed to create schema")
+}
+
+#[tokio::test]
+async fn create_schema_rejects_duplicate_name_and_version() {
+    let fixture = fixture();
+    create(&fixture, "duplicate", "1.0.0").await;
+
+    let result = fixture
+        .service
+        .create_schema(
+            "duplicate".to_string(),
+            "1.0.0".to_string(),
+            None,
+            definition(),
+            ValidationMode::Strict,
+            &AuditContext::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert_eq!(fixture.audit.entries.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn update_schema_rejects_name_and_version_of_another_schema() {
+    let fixture = fixture();
+    create(&fixture, "taken", "1.0.0").await;
+    let schema = create(&fixture, "taken", "2.0.0").await;
+
+    let result = fixture
+        .service
+        .update_schema(
+            schema.id,
+            "taken".to_string(),
+            "1.0.0".to_string(),
+            None,
+            definition(),
+            None,
+            None,
+            false,
+            &AuditContext::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn delete_schema_with_logs_requires_force() {
+    let fixture = fixture();
+    let schema = create(&fixture, "with-logs", "1.0.0").await;
+    fixture
+        .logs
+        .create(&Log {
+            id: 0,
+            schema_id: schema.id,
+            log_data: json!({ "message": "hello" }),
+            source: None,
+            correlation_id: None,
+            severity: None,
+            schema_version_at_creation: None,
+            attachments: None,
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+    let result = fixture
+        .service
+        .delete_schema(schema.id, false, &AuditContext::default())
+        .await;
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+
+    let deleted = fixture
+        .service
+        .delete_schema(schema.id, true, &AuditContext::default())
+        .await
+        .unwrap();
+    assert!(deleted);
+    assert_eq!(fixture.logs.count_by_schema_id(schema.id).await.unwrap(), 0);
+    assert!(fixture
+        .service
+        .get_schema_by_id(schema.id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn delete_schema_reports_missing_schema() {
+    let fixture = fixture();
+
+    let deleted = fixture
+        .service
+        .delete_schema(Uuid::new_v4(), true, &AuditContext::default())
+        .await
+        .unwrap();
+
+    assert!(!deleted);
+}
+
+async fn create_with_definition(
+    fixture: &Fixture,
+    definition: serde_json::Value,
+) -> Result<log_server::Schema, AppError> {
+    fixture
+        .service
+        .create_schema(
+            "definition".to_string(),
+            "1.0.0".to_string(),
+            None,
+            definition,
+            ValidationMode::Strict,
+            &AuditContext::default(),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn create_schema_rejects_array_definition() {
+    let result = create_with_definition(&fixture(), json!([{ "type": "object" }])).await;
+    assert!(
+        matches!(result, Err(AppError::ValidationError(_))),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn create_schema_rejects_null_definition() {
+    let result = create_with_definition(&fixture(), json!(null)).await;
+    assert!(
+        matches!(result, Err(AppError::ValidationError(_))),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn create_schema_rejects_string_definition() {
+    let result = create_with_definition(&fixture(), json!("not an object")).await;
+    assert!(
+        matches!(result, Err(AppError::ValidationError(_))),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn create_schema_rejects_invalid_type_value() {
+    let result = create_with_definition(&fixture(), json!({ "type": "not-a-type" })).await;
+    assert!(
+        matches!(result, Err(AppError::SchemaValidationError(_))),
+        "{:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn create_schema_rejects_required_field_missing_from_properties() {
+    let definition = json!({
+        "type": "object",
+        "properties": { "message": { "type": "string" } },
+        "required": ["message", "mesage"]
+    });
+
+    match create_with_definition(&fixture(), definition).await {
+        Err(AppError::ValidationError(message)) => assert_eq!(
+            message,
+            "Required field 'mesage' is not defined in properties"
+        ),
+        other => panic!("Expected a validation error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn create_schema_accepts_required_without_properties() {
+    let definition = json!({ "type": "object", "required": ["message"] });
+
+    create_with_definition(&fixture(), definition)
+        .await
+        .expect("Failed to create schema");
+}
+
+#[tokio::test]
+async fn create_schema_accepts_minimal_object_definition() {
+    let schema = create_with_definition(&fixture(), json!({}))
+        .await
+        .expect("Failed to create schema");
+    assert_eq!(schema.schema_definition, json!({}));
+}
+
+#[tokio::test]
+async fn get_all_schemas_pages_by_seq_after_cursor() {
+    let fixture = fixture();
+    let first = create(&fixture, "paged", "1.0.0").await;
+    let second = create(&fixture, "paged", "2.0.0").await;
+    let third = create(&fixture, "paged", "3.0.0").await;
+
+    let page = |after_seq| SchemaQueryParams {
+        limit: Some(2),
+        after_seq: Some(after_seq),
+        ..Default::default()
+    };
+
+    let schemas = fixture
+        .service
+        .get_all_schemas(Some(page(0)))
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = schemas.iter().map(|schema| schema.id).collect();
+    assert_eq!(ids, vec![first.id, second.id]);
+
+    let schemas = fixture
+        .service
+        .get_all_schemas(Some(page(schemas[1].seq)))
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = schemas.iter().map(|schema| schema.id).collect();
+    assert_eq!(ids, vec![third.id]);
+}
+
+#[tokio::test]
+async fn free_version_format_rejects_route_segments() {
+    let fixture = fixture_with_version_format(VersionFormat::Free);
+
+    assert!(fixture.service.validate_version("2024-q1-final").is_ok());
+
+    for version in [
+        "versions",
+        "fields",
+        "logs",
+        "tags",
+        "export.json",
+        "-rc1",
+        ".hidden",
+        "a/b",
+    ] {
+        assert!(
+            matches!(
+                fixture.service.validate_version(version),
+                Err(AppError::ValidationError(_))
+            ),
+            "'{}' should be rejected",
+            version
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use log_server::ErrorResponse;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+use crate::common::TestContext;
+
+#[tokio::test]
+async fn creates_lists_and_deletes_webhook() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/webhooks", ctx.base_url))
+        .json(&json!({
+            "url": "http://127.0.0.1:9/hook",
+            "events": ["log.created", "schema.deleted"],
+            "secret": "s3cret"
+        }))
+        .send()
+        .await
+        .expect("Failed to create webhook");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let webhook: Value = response.json().await.unwrap();
+    let id = webhook["id"].as_str().unwrap().to_string();
+    assert_eq!(webhook["active"], true);
+    assert_eq!(webhook["events"], json!(["log.created", "schema.deleted"]));
+    assert!(webhook.get("secret").is_none());
+
+    let response = ctx
+        .client
+        .get(format!("{}/webhooks", ctx.base_url))
+        .send()
+        .await
+        .expect("Failed to list webhooks");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let data: Value = response.json().await.unwrap();
+    assert!(data["webhooks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|w| w["id"] == id.as_str()));
+
+    let response = ctx
+        .client
+        .delete(format!("{}/webhooks/{}", ctx.base_url, id))
+        .send()
+        .await
+        .expect("Failed to delete webhook");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = ctx
+        .client
+        .get(format!("{}/webhooks/{}", ctx.base_url, id))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rejects_unknown_event() {
+    let ctx = TestContext::new().await;
+
+    let response = ctx
+        .client
+        .post(format!("{}/webhooks", ctx.base_url))
+        .json(&json!({
+            "url": "http://127.0.0.1:9/hook",
+            "events": ["log.exploded"],
+            "secret": "s3cret"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let error: ErrorResponse = response.json().await.unwrap();
+    assert_eq!(error.error, "INVALID_INPUT");
+}
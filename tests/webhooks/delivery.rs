@@ -0,0 +1,79 @@
+use axum::{body::Bytes, http::HeaderMap, routing::post, Router};
+use log_server::services::webhook_service::{sign_payload, WEBHOOK_SIGNATURE_HEADER};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use crate::common::{valid_schema_payload, TestContext};
+
+#[tokio::test]
+async fn delivers_signed_schema_created_event() {
+    let ctx = TestContext::new().await;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<(HeaderMap, Bytes)>();
+    let receiver = Router::new().route(
+        "/hook",
+        post(move |headers: HeaderMap, body: Bytes| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send((headers, body));
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await });
+
+    let webhook: Value = ctx
+        .client
+        .post(format!("{}/webhooks", ctx.base_url))
+        .json(&json!({
+            "url": format!("http://{}/hook", addr),
+            "events": ["schema.created"],
+            "secret": "delivery-secret"
+        }))
+        .send()
+        .await
+        .expect("Failed to create webhook")
+        .json()
+        .await
+        .unwrap();
+
+    ctx.client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("webhook-delivery-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    // Other tests create schemas concurrently, so wait for ours specifically
+    let (headers, body) = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let (headers, body) = rx.recv().await.expect("Receiver closed");
+            let payload: Value = serde_json::from_slice(&body).unwrap();
+            if payload["data"]["name"] == "webhook-delivery-test" {
+                return (headers, body);
+            }
+        }
+    })
+    .await
+    .expect("Webhook was not delivered");
+
+    let payload: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(payload["event"], "schema.created");
+    assert_eq!(
+        headers[WEBHOOK_SIGNATURE_HEADER].to_str().unwrap(),
+        sign_payload("delivery-secret", &body)
+    );
+
+    ctx.client
+        .delete(format!(
+            "{}/webhooks/{}",
+            ctx.base_url,
+            webhook["id"].as_str().unwrap()
+        ))
+        .send()
+        .await
+        .expect("Failed to delete webhook");
+}
@@ -0,0 +1,73 @@
+use log_server::Schema;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+use crate::common::{valid_schema_payload, TestContext};
+
+#[tokio::test]
+async fn ingests_transformed_payload_by_token() {
+    let ctx = TestContext::new().await;
+
+    let schema: Schema = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("ingest-webhook-test"))
+        .send()
+        .await
+        .expect("Failed to create schema")
+        .json()
+        .await
+        .unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/admin/webhooks", ctx.base_url))
+        .json(&json!({
+            "schema_id": schema.id,
+            "transform": { "message": "/alert/title" }
+        }))
+        .send()
+        .await
+        .expect("Failed to create ingest webhook");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let webhook: Value = response.json().await.unwrap();
+    let ingest_url = webhook["ingest_url"].as_str().unwrap();
+    assert_eq!(webhook["token"].as_str().unwrap().len(), 64);
+
+    let response = ctx
+        .client
+        .post(format!("{}{}", ctx.base_url, ingest_url))
+        .json(&json!({ "alert": { "title": "Disk full", "severity": "high" } }))
+        .send()
+        .await
+        .expect("Failed to ingest payload");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let log: Value = response.json().await.unwrap();
+    assert_eq!(log["schema_id"], json!(schema.id));
+    assert_eq!(log["log_data"], json!({ "message": "Disk full" }));
+
+    let response = ctx
+        .client
+        .delete(format!(
+            "{}/admin/webhooks/{}",
+            ctx.base_url,
+            webhook["id"].as_str().unwrap()
+        ))
+        .send()
+        .await
+        .expect("Failed to delete ingest webhook");
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = ctx
+        .client
+        .post(format!("{}{}", ctx.base_url, ingest_url))
+        .json(&json!({ "alert": { "title": "Disk full" } }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,3 @@
+pub mod crud;
+pub mod delivery;
+pub mod ingest;
@@ -1,7 +1,6 @@
 use crate::common::{valid_schema_payload, TestContext};
 use futures_util::{SinkExt, StreamExt};
 use log_server::Schema;
-use reqwest::StatusCode;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
 #[tokio::test]
@@ -17,7 +16,14 @@ async fn successfully_connects_to_websocket_endpoint() {
         "Should successfully connect to WebSocket endpoint"
     );
 
-    let (mut ws_stream, _) = result.unwrap();
+    let (mut ws_stream, response) = result.unwrap();
+    assert_eq!(
+        response
+            .headers()
+            .get("X-Log-Events-Version")
+            .and_then(|v| v.to_str().ok()),
+        Some("2")
+    );
 
     ws_stream.close(None).await.unwrap();
 }
@@ -28,7 +34,7 @@ async fn successfully_connects_with_valid_schema_id() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-connection-test"))
         .send()
         .await
@@ -74,6 +80,69 @@ async fn rejects_connection_with_nonexistent_schema_id() {
     );
 }
 
+#[tokio::test]
+async fn connects_with_schema_name_and_optional_version() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("ws-name-connection-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+
+    for url in [
+        format!("{}/ws/logs?schema_name={}", ws_url, schema.name),
+        format!(
+            "{}/ws/logs?schema_name={}&schema_version={}",
+            ws_url, schema.name, schema.version
+        ),
+    ] {
+        let (mut ws_stream, _) = connect_async(&url)
+            .await
+            .unwrap_or_else(|e| panic!("Should connect to {}: {}", url, e));
+        ws_stream.close(None).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn rejects_connection_with_nonexistent_schema_name() {
+    let ctx = TestContext::new().await;
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let url = format!("{}/ws/logs?schema_name=ws-no-such-schema", ws_url);
+
+    let err_msg = connect_async(&url).await.unwrap_err().to_string();
+    assert!(
+        err_msg.contains("404"),
+        "Error should indicate 404 Not Found, got: {}",
+        err_msg
+    );
+}
+
+#[tokio::test]
+async fn rejects_connection_combining_schema_id_and_name() {
+    let ctx = TestContext::new().await;
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let url = format!(
+        "{}/ws/logs?schema_id={}&schema_name=anything",
+        ws_url,
+        uuid::Uuid::new_v4()
+    );
+
+    let err_msg = connect_async(&url).await.unwrap_err().to_string();
+    assert!(
+        err_msg.contains("400"),
+        "Error should indicate 400 Bad Request, got: {}",
+        err_msg
+    );
+}
+
 #[tokio::test]
 async fn rejects_connection_with_invalid_schema_id_format() {
     let ctx = TestContext::new().await;
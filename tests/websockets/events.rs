@@ -1,5 +1,5 @@
-use crate::common::{valid_log_payload, valid_schema_payload, TestContext};
-use futures_util::{SinkExt, StreamExt};
+use crate::common::{valid_log_payload, valid_schema_payload, TestContext, WebSocketTestClient};
+use futures_util::StreamExt;
 use log_server::{Log, LogEvent, Schema};
 use serde_json::json;
 use tokio::time::{timeout, Duration};
@@ -11,7 +11,7 @@ async fn receives_created_event_when_log_is_created() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-create-event-test"))
         .send()
         .await
@@ -25,7 +25,7 @@ async fn receives_created_event_when_log_is_created() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -46,11 +46,15 @@ async fn receives_created_event_when_log_is_created() {
             LogEvent::Created {
                 id,
                 schema_id,
+                schema_name,
+                schema_version,
                 log_data,
                 ..
             } => {
                 assert_eq!(id, created_log.id);
                 assert_eq!(schema_id, schema.id);
+                assert_eq!(schema_name, schema.name);
+                assert_eq!(schema_version, schema.version);
                 assert_eq!(log_data["message"], "Test log message");
             }
             _ => panic!("Expected Created event, got Deleted"),
@@ -68,7 +72,7 @@ async fn receives_deleted_event_when_log_is_deleted() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-delete-event-test"))
         .send()
         .await
@@ -78,7 +82,7 @@ async fn receives_deleted_event_when_log_is_deleted() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -91,7 +95,7 @@ async fn receives_deleted_event_when_log_is_deleted() {
     let (mut ws_stream, _) = connect_async(&url).await.unwrap();
 
     ctx.client
-        .delete(&format!("{}/logs/{}", ctx.base_url, created_log.id))
+        .delete(format!("{}/logs/{}", ctx.base_url, created_log.id))
         .send()
         .await
         .expect("Failed to delete log");
@@ -119,13 +123,45 @@ async fn receives_deleted_event_when_log_is_deleted() {
     ws_stream.close(None).await.unwrap();
 }
 
+#[tokio::test]
+async fn receives_deleted_events_when_schema_logs_are_emptied() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("ws-empty-schema-logs-test").await;
+    let first = ctx.create_log(schema.id).await;
+    let second = ctx.create_log(schema.id).await;
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let url = format!("{}/ws/logs?schema_id={}", ws_url, schema.id);
+    let mut client = WebSocketTestClient::connect(&url).await;
+
+    let response = ctx
+        .client
+        .delete(format!("{}/schemas/{}/logs", ctx.base_url, schema.id))
+        .send()
+        .await
+        .expect("Failed to delete schema logs");
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let events = client.collect_events(2, Duration::from_secs(5)).await;
+    let ids: Vec<_> = events
+        .iter()
+        .map(|event| {
+            assert_eq!(event["event_type"], "deleted");
+            event["id"].as_i64().unwrap()
+        })
+        .collect();
+    assert_eq!(ids, vec![first.id as i64, second.id as i64]);
+
+    client.disconnect().await;
+}
+
 #[tokio::test]
 async fn filters_events_by_schema_id() {
     let ctx = TestContext::new().await;
 
     let schema1_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-filter-test-1"))
         .send()
         .await
@@ -135,7 +171,7 @@ async fn filters_events_by_schema_id() {
 
     let schema2_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-filter-test-2"))
         .send()
         .await
@@ -148,7 +184,7 @@ async fn filters_events_by_schema_id() {
     let (mut ws_stream, _) = connect_async(&url).await.unwrap();
 
     ctx.client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema2.id))
         .send()
         .await
@@ -162,7 +198,7 @@ async fn filters_events_by_schema_id() {
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema1.id))
         .send()
         .await
@@ -197,7 +233,7 @@ async fn receives_all_events_without_schema_filter() {
 
     let schema1_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-no-filter-test-1"))
         .send()
         .await
@@ -207,7 +243,7 @@ async fn receives_all_events_without_schema_filter() {
 
     let schema2_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-no-filter-test-2"))
         .send()
         .await
@@ -220,14 +256,14 @@ async fn receives_all_events_without_schema_filter() {
     let (mut ws_stream, _) = connect_async(&url).await.unwrap();
 
     ctx.client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema1.id))
         .send()
         .await
         .expect("Failed to create log for schema 1");
 
     ctx.client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema2.id))
         .send()
         .await
@@ -272,7 +308,7 @@ async fn multiple_clients_receive_same_events() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-multi-client-test"))
         .send()
         .await
@@ -283,13 +319,13 @@ async fn multiple_clients_receive_same_events() {
     let ws_url = ctx.base_url.replace("http", "ws");
     let url = format!("{}/ws/logs", ws_url);
 
-    let (mut ws_stream1, _) = connect_async(&url).await.unwrap();
-    let (mut ws_stream2, _) = connect_async(&url).await.unwrap();
-    let (mut ws_stream3, _) = connect_async(&url).await.unwrap();
+    let (ws_stream1, _) = connect_async(&url).await.unwrap();
+    let (ws_stream2, _) = connect_async(&url).await.unwrap();
+    let (ws_stream3, _) = connect_async(&url).await.unwrap();
 
     let log_response = ctx
         .client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&valid_log_payload(schema.id))
         .send()
         .await
@@ -297,12 +333,12 @@ async fn multiple_clients_receive_same_events() {
 
     let created_log: Log = log_response.json().await.unwrap();
 
-    let mut clients = vec![ws_stream1, ws_stream2, ws_stream3];
+    let mut clients = [ws_stream1, ws_stream2, ws_stream3];
 
     for (i, ws_stream) in clients.iter_mut().enumerate() {
         let ws_message = timeout(Duration::from_secs(5), ws_stream.next())
             .await
-            .expect(&format!("Timeout for client {}", i + 1))
+            .unwrap_or_else(|_| panic!("Timeout for client {}", i + 1))
             .expect("WebSocket stream ended")
             .expect("Failed to receive message");
 
@@ -330,7 +366,7 @@ async fn event_contains_correct_data_structure() {
 
     let schema_response = ctx
         .client
-        .post(&format!("{}/schemas", ctx.base_url))
+        .post(format!("{}/schemas", ctx.base_url))
         .json(&valid_schema_payload("ws-data-structure-test"))
         .send()
         .await
@@ -352,7 +388,7 @@ async fn event_contains_correct_data_structure() {
     });
 
     ctx.client
-        .post(&format!("{}/logs", ctx.base_url))
+        .post(format!("{}/logs", ctx.base_url))
         .json(&custom_log_data)
         .send()
         .await
@@ -380,3 +416,123 @@ async fn event_contains_correct_data_structure() {
 
     ws_stream.close(None).await.unwrap();
 }
+
+#[tokio::test]
+async fn batch_mode_groups_events_into_batch_messages() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("ws-batch-mode-test").await;
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let url = format!("{}/ws/logs?schema_id={}&batch_mode=true", ws_url, schema.id);
+    let (mut ws_stream, _) = connect_async(&url).await.unwrap();
+
+    let response = ctx
+        .client
+        .post(format!("{}/logs/batch", ctx.base_url))
+        .json(&json!({
+            "logs": [
+                valid_log_payload(schema.id),
+                valid_log_payload(schema.id),
+                valid_log_payload(schema.id)
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send batch");
+    assert!(response.status().is_success());
+
+    let mut received = Vec::new();
+    while received.len() < 3 {
+        let message = next_json(&mut ws_stream).await;
+        assert_eq!(message["event_type"], "batch", "{}", message);
+        assert!(message["batch_at"].is_string());
+
+        for event in message["events"].as_array().unwrap() {
+            assert_eq!(event["event_type"], "created");
+            assert_eq!(event["schema_id"], json!(schema.id));
+            received.push(event["id"].as_i64().unwrap());
+        }
+    }
+    assert_eq!(received.len(), 3);
+
+    ws_stream.close(None).await.unwrap();
+}
+
+async fn next_json<S>(ws_stream: &mut S) -> serde_json::Value
+where
+    S: futures_util::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    match timeout(Duration::from_secs(5), ws_stream.next())
+        .await
+        .expect("Timeout waiting for WebSocket message")
+        .expect("WebSocket stream ended")
+        .expect("Failed to receive message")
+    {
+        Message::Text(text) => serde_json::from_str(&text).expect("Failed to parse message"),
+        other => panic!("Expected text message, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn replays_past_logs_before_live_events() {
+    let ctx = TestContext::new().await;
+
+    let schema_response = ctx
+        .client
+        .post(format!("{}/schemas", ctx.base_url))
+        .json(&valid_schema_payload("ws-replay-test"))
+        .send()
+        .await
+        .expect("Failed to create schema");
+
+    let schema: Schema = schema_response.json().await.unwrap();
+
+    let mut past_logs = Vec::new();
+    for _ in 0..3 {
+        let log: Log = ctx
+            .client
+            .post(format!("{}/logs", ctx.base_url))
+            .json(&valid_log_payload(schema.id))
+            .send()
+            .await
+            .expect("Failed to create log")
+            .json()
+            .await
+            .unwrap();
+        past_logs.push(log);
+    }
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let url = format!("{}/ws/logs/replay?schema_id={}&limit=2", ws_url, schema.id);
+    let (mut ws_stream, _) = connect_async(&url).await.unwrap();
+
+    // The two most recent logs, oldest first
+    for expected in &past_logs[1..] {
+        let message = next_json(&mut ws_stream).await;
+        assert_eq!(message["event_type"], "created");
+        assert_eq!(message["id"], expected.id);
+    }
+
+    let complete = next_json(&mut ws_stream).await;
+    assert_eq!(
+        complete,
+        json!({ "event_type": "replay_complete", "count": 2 })
+    );
+
+    let live_log: Log = ctx
+        .client
+        .post(format!("{}/logs", ctx.base_url))
+        .json(&valid_log_payload(schema.id))
+        .send()
+        .await
+        .expect("Failed to create log")
+        .json()
+        .await
+        .unwrap();
+
+    let live = next_json(&mut ws_stream).await;
+    assert_eq!(live["event_type"], "created");
+    assert_eq!(live["id"], live_log.id);
+
+    ws_stream.close(None).await.unwrap();
+}
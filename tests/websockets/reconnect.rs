@@ -0,0 +1,80 @@
+use crate::common::{TestContext, WebSocketTestClient};
+use serde_json::json;
+use tokio::time::Duration;
+
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn client_misses_events_during_disconnect() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("ws-disconnect-test").await;
+
+    let url = format!(
+        "{}/ws/logs?schema_id={}",
+        ctx.base_url.replace("http", "ws"),
+        schema.id
+    );
+    let mut client = WebSocketTestClient::connect(&url).await;
+
+    let first = ctx.create_log(schema.id).await;
+    let events = client.collect_events(1, EVENT_TIMEOUT).await;
+    assert_eq!(events[0]["id"], first.id);
+
+    client.disconnect().await;
+    let missed = ctx.create_log(schema.id).await;
+    client
+        .reconnect_after(Duration::from_millis(100), &url)
+        .await;
+
+    let after = ctx.create_log(schema.id).await;
+    let events = client.collect_events(2, Duration::from_secs(1)).await;
+
+    let ids: Vec<_> = events.iter().map(|event| event["id"].clone()).collect();
+    assert_eq!(ids, vec![json!(after.id)], "missed log {}", missed.id);
+}
+
+#[tokio::test]
+async fn client_receives_missed_events_after_reconnect_with_replay() {
+    let ctx = TestContext::new().await;
+    let schema = ctx.create_schema("ws-reconnect-replay-test").await;
+
+    let ws_url = ctx.base_url.replace("http", "ws");
+    let mut client =
+        WebSocketTestClient::connect(&format!("{}/ws/logs?schema_id={}", ws_url, schema.id)).await;
+
+    let seen = ctx.create_log(schema.id).await;
+    let events = client.collect_events(1, EVENT_TIMEOUT).await;
+    assert_eq!(events[0]["id"], seen.id);
+    let last_seen_at = events[0]["created_at"].as_str().unwrap().to_string();
+
+    client.disconnect().await;
+    let missed = [
+        ctx.create_log(schema.id).await,
+        ctx.create_log(schema.id).await,
+    ];
+
+    // `from` is inclusive, so the last seen log is replayed again
+    let replay_url = format!(
+        "{}/ws/logs/replay?schema_id={}&from={}",
+        ws_url,
+        schema.id,
+        last_seen_at.replace('+', "%2B")
+    );
+    client
+        .reconnect_after(Duration::from_millis(100), &replay_url)
+        .await;
+
+    let events = client.collect_events(4, EVENT_TIMEOUT).await;
+    let ids: Vec<_> = events[..3]
+        .iter()
+        .map(|event| event["id"].clone())
+        .collect();
+    assert_eq!(
+        ids,
+        vec![json!(seen.id), json!(missed[0].id), json!(missed[1].id)]
+    );
+    assert_eq!(
+        events[3],
+        json!({ "event_type": "replay_complete", "count": 3 })
+    );
+}